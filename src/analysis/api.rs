@@ -7,12 +7,16 @@ use crate::{
             f64_to_money_with_currency, i64_to_datetime, i64_to_money_with_currency,
             string_to_period, string_to_recommendation_action, string_to_recommendation_grade,
         },
-        wire::{from_raw, from_raw_u32_round},
+        wire::{from_raw, from_raw_u32_round, from_yahoo_number},
     },
 };
 
 use super::fetch::fetch_modules;
-use super::model::{PriceTarget, RecommendationRow, RecommendationSummary, UpgradeDowngradeRow};
+use super::model::{
+    EpsTrendRow, PriceTarget, PriceTargetConsensus, RecommendationRow, RecommendationSummary,
+    UpgradeDowngradeRow,
+};
+use super::wire::FinancialDataNode;
 use chrono::DateTime;
 use paft::fundamentals::analysis::{
     EarningsEstimate, EpsRevisions, EpsTrend, RevenueEstimate, RevisionPoint, TrendPoint,
@@ -92,7 +96,10 @@ pub(super) async fn recommendation_summary(
         });
 
     let (mean, _mean_key) = root.financial_data.map_or((None, None), |fd| {
-        (from_raw(fd.recommendation_mean), fd.recommendation_key)
+        (
+            from_yahoo_number(fd.recommendation_mean),
+            fd.recommendation_key,
+        )
     });
 
     Ok(RecommendationSummary {
@@ -161,14 +168,39 @@ pub(super) async fn analyst_price_target(
         .financial_data
         .ok_or_else(|| YfError::MissingData("financialData missing".into()))?;
 
-    Ok(PriceTarget {
-        mean: from_raw(fd.target_mean_price)
+    Ok(price_target_from(&fd, &currency))
+}
+
+fn price_target_from(fd: &FinancialDataNode, currency: &Currency) -> PriceTarget {
+    PriceTarget {
+        mean: from_yahoo_number(fd.target_mean_price)
             .map(|v| f64_to_money_with_currency(v, currency.clone())),
-        high: from_raw(fd.target_high_price)
+        high: from_yahoo_number(fd.target_high_price)
+            .map(|v| f64_to_money_with_currency(v, currency.clone())),
+        low: from_yahoo_number(fd.target_low_price)
             .map(|v| f64_to_money_with_currency(v, currency.clone())),
-        low: from_raw(fd.target_low_price).map(|v| f64_to_money_with_currency(v, currency.clone())),
         number_of_analysts: from_raw_u32_round(fd.number_of_analyst_opinions),
-    })
+    }
+}
+
+pub(super) async fn price_targets_consensus(
+    client: &YfClient,
+    symbol: &str,
+    currency: Currency,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<PriceTargetConsensus, YfError> {
+    let root = fetch_modules(client, symbol, "financialData", cache_mode, retry_override).await?;
+    let fd = root
+        .financial_data
+        .ok_or_else(|| YfError::MissingData("financialData missing".into()))?;
+
+    let current_price = from_yahoo_number(fd.current_price)
+        .map(|v| f64_to_money_with_currency(v, currency.clone()));
+    Ok(PriceTargetConsensus::new(
+        price_target_from(&fd, &currency),
+        current_price,
+    ))
 }
 
 #[allow(clippy::too_many_lines)]
@@ -200,12 +232,12 @@ pub(super) async fn earnings_trend(
                 .earnings_estimate
                 .map(|e| {
                     (
-                        from_raw(e.avg),
-                        from_raw(e.low),
-                        from_raw(e.high),
-                        from_raw(e.year_ago_eps),
+                        from_yahoo_number(e.avg),
+                        from_yahoo_number(e.low),
+                        from_yahoo_number(e.high),
+                        from_yahoo_number(e.year_ago_eps),
                         from_raw_u32_round(e.num_analysts),
-                        from_raw(e.growth),
+                        from_yahoo_number(e.growth),
                     )
                 })
                 .unwrap_or_default();
@@ -226,7 +258,7 @@ pub(super) async fn earnings_trend(
                         from_raw(e.high),
                         from_raw(e.year_ago_revenue),
                         from_raw_u32_round(e.num_analysts),
-                        from_raw(e.growth),
+                        from_yahoo_number(e.growth),
                     )
                 })
                 .unwrap_or_default();
@@ -241,11 +273,11 @@ pub(super) async fn earnings_trend(
                 .eps_trend
                 .map(|e| {
                     (
-                        from_raw(e.current),
-                        from_raw(e.seven_days_ago),
-                        from_raw(e.thirty_days_ago),
-                        from_raw(e.sixty_days_ago),
-                        from_raw(e.ninety_days_ago),
+                        from_yahoo_number(e.current),
+                        from_yahoo_number(e.seven_days_ago),
+                        from_yahoo_number(e.thirty_days_ago),
+                        from_yahoo_number(e.sixty_days_ago),
+                        from_yahoo_number(e.ninety_days_ago),
                     )
                 })
                 .unwrap_or_default();
@@ -269,7 +301,7 @@ pub(super) async fn earnings_trend(
 
             EarningsTrendRow {
                 period: string_to_period(&n.period.unwrap_or_default()),
-                growth: from_raw(n.growth),
+                growth: from_yahoo_number(n.growth),
                 earnings_estimate: EarningsEstimate {
                     avg: earnings_estimate_avg
                         .map(|v| f64_to_money_with_currency(v, currency.clone())),
@@ -359,3 +391,35 @@ pub(super) async fn earnings_trend(
 
     Ok(rows)
 }
+
+pub(super) async fn eps_trend(
+    client: &YfClient,
+    symbol: &str,
+    currency: Currency,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Vec<EpsTrendRow>, YfError> {
+    let root = fetch_modules(client, symbol, "earningsTrend", cache_mode, retry_override).await?;
+
+    let trend = root
+        .earnings_trend
+        .and_then(|x| x.trend)
+        .unwrap_or_default();
+
+    let money = |v: Option<f64>| v.map(|v| f64_to_money_with_currency(v, currency.clone()));
+
+    Ok(trend
+        .into_iter()
+        .filter_map(|n| {
+            let e = n.eps_trend?;
+            Some(EpsTrendRow {
+                period: string_to_period(&n.period.unwrap_or_default()),
+                current: money(from_yahoo_number(e.current)),
+                seven_days_ago: money(from_yahoo_number(e.seven_days_ago)),
+                thirty_days_ago: money(from_yahoo_number(e.thirty_days_ago)),
+                sixty_days_ago: money(from_yahoo_number(e.sixty_days_ago)),
+                ninety_days_ago: money(from_yahoo_number(e.ninety_days_ago)),
+            })
+        })
+        .collect())
+}
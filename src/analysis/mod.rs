@@ -5,7 +5,8 @@ mod fetch;
 mod wire;
 
 pub use model::{
-    EarningsTrendRow, PriceTarget, RecommendationRow, RecommendationSummary, UpgradeDowngradeRow,
+    ConsensusTrend, EarningsTrendRow, EpsTrendRow, PriceTarget, PriceTargetConsensus,
+    RecommendationConsensus, RecommendationRow, RecommendationSummary, UpgradeDowngradeRow,
 };
 
 use crate::core::{
@@ -77,6 +78,19 @@ impl AnalysisBuilder {
         .await
     }
 
+    /// Fetches the recommendation trend and collapses it into a weighted consensus score with
+    /// its direction versus the prior period. See [`RecommendationConsensus`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::MissingData` if Yahoo reports no recommendation periods, or an error
+    /// if the request fails or the data is malformed.
+    pub async fn recommendation_consensus(self) -> Result<RecommendationConsensus, YfError> {
+        let rows = self.recommendations().await?;
+        RecommendationConsensus::from_rows(&rows)
+            .ok_or_else(|| YfError::MissingData("recommendationTrend has no periods".into()))
+    }
+
     /// Fetches the history of analyst upgrades and downgrades for the symbol.
     ///
     /// # Errors
@@ -119,6 +133,34 @@ impl AnalysisBuilder {
         .await
     }
 
+    /// Fetches the analyst price targets with the current price and the implied upside to the
+    /// mean target. See [`PriceTargetConsensus`].
+    ///
+    /// Provide `Some(currency)` to override the inferred reporting currency; pass `None`
+    /// to use the cached profile-based heuristic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the data is malformed.
+    pub async fn price_targets_consensus(
+        self,
+        override_currency: Option<Currency>,
+    ) -> Result<PriceTargetConsensus, YfError> {
+        let currency = self
+            .client
+            .reporting_currency(&self.symbol, override_currency)
+            .await;
+
+        api::price_targets_consensus(
+            &self.client,
+            &self.symbol,
+            currency,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
     /// Fetches earnings trend data.
     ///
     /// This includes earnings estimates, revenue estimates, EPS trends, and EPS revisions.
@@ -146,4 +188,32 @@ impl AnalysisBuilder {
         )
         .await
     }
+
+    /// Fetches how the consensus EPS estimate has been revised over the last 7/30/60/90 days
+    /// for each estimate period.
+    ///
+    /// Provide `Some(currency)` to override the inferred reporting currency; pass `None`
+    /// to use the cached profile-based heuristic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the data is malformed.
+    pub async fn eps_trend(
+        self,
+        override_currency: Option<Currency>,
+    ) -> Result<Vec<EpsTrendRow>, YfError> {
+        let currency = self
+            .client
+            .reporting_currency(&self.symbol, override_currency)
+            .await;
+
+        api::eps_trend(
+            &self.client,
+            &self.symbol,
+            currency,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
 }
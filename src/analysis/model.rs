@@ -2,3 +2,138 @@
 pub use paft::fundamentals::analysis::{
     EarningsTrendRow, PriceTarget, RecommendationRow, RecommendationSummary, UpgradeDowngradeRow,
 };
+
+use paft::domain::Period;
+use paft::money::Money;
+use serde::{Deserialize, Serialize};
+
+use crate::core::conversions::money_to_f64;
+
+/// How the consensus EPS estimate for one period has been revised over time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EpsTrendRow {
+    /// The estimate period (e.g. current quarter `0q`, next year `+1y`).
+    pub period: Period,
+    /// The current consensus EPS estimate.
+    pub current: Option<Money>,
+    /// The consensus estimate 7 days ago.
+    pub seven_days_ago: Option<Money>,
+    /// The consensus estimate 30 days ago.
+    pub thirty_days_ago: Option<Money>,
+    /// The consensus estimate 60 days ago.
+    pub sixty_days_ago: Option<Money>,
+    /// The consensus estimate 90 days ago.
+    pub ninety_days_ago: Option<Money>,
+}
+
+/// The analyst price targets together with the current price and the implied upside.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceTargetConsensus {
+    /// The analyst price targets.
+    pub targets: PriceTarget,
+    /// The current price reported alongside the targets.
+    pub current_price: Option<Money>,
+    /// Percent from the current price to the mean target, e.g. `12.5` for +12.5%.
+    ///
+    /// `None` if either price is missing or the current price is not positive.
+    pub upside_percent: Option<f64>,
+}
+
+impl PriceTargetConsensus {
+    /// Pairs `targets` with `current_price` and computes the implied upside.
+    #[must_use]
+    pub fn new(targets: PriceTarget, current_price: Option<Money>) -> Self {
+        let upside_percent = match (targets.mean.as_ref(), current_price.as_ref()) {
+            (Some(mean), Some(price)) => {
+                let (mean, price) = (money_to_f64(mean), money_to_f64(price));
+                (price > 0.0 && mean.is_finite()).then(|| (mean - price) / price * 100.0)
+            }
+            _ => None,
+        };
+        Self {
+            targets,
+            current_price,
+            upside_percent,
+        }
+    }
+}
+
+/// Direction of the analyst consensus between two recommendation periods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsensusTrend {
+    /// The score fell (more bullish) by at least [`RecommendationConsensus::TREND_THRESHOLD`].
+    Improving,
+    /// The score rose (more bearish) by at least the threshold.
+    Worsening,
+    /// The score moved by less than the threshold.
+    Unchanged,
+    /// One of the periods has no score, so no direction can be given.
+    Unknown,
+}
+
+/// The recommendation trend collapsed into a single weighted score.
+///
+/// Each rating is weighted `strongBuy = 1`, `buy = 2`, `hold = 3`, `sell = 4`,
+/// `strongSell = 5` and averaged over all analysts, so lower is more bullish, matching
+/// Yahoo's `recommendationMean`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecommendationConsensus {
+    /// The current period (Yahoo's first trend row, usually `0m`).
+    pub period: Period,
+    /// Weighted score for the current period, `None` if no analyst rated it.
+    pub score: Option<f64>,
+    /// Number of analysts behind `score`.
+    pub analysts: u32,
+    /// Weighted score for the prior period (usually `-1m`), if reported.
+    pub previous_score: Option<f64>,
+    /// Direction from `previous_score` to `score`.
+    pub trend: ConsensusTrend,
+}
+
+impl RecommendationConsensus {
+    /// Smallest score change reported as `Improving` or `Worsening`.
+    pub const TREND_THRESHOLD: f64 = 0.01;
+
+    /// Builds the consensus from trend rows ordered newest first, as Yahoo returns them.
+    ///
+    /// Returns `None` if `rows` is empty.
+    #[must_use]
+    pub fn from_rows(rows: &[RecommendationRow]) -> Option<Self> {
+        let current = rows.first()?;
+        let (score, analysts) = weighted_score(current);
+        let previous_score = rows.get(1).and_then(|r| weighted_score(r).0);
+
+        let trend = match (score, previous_score) {
+            (Some(now), Some(before)) if before - now >= Self::TREND_THRESHOLD => {
+                ConsensusTrend::Improving
+            }
+            (Some(now), Some(before)) if now - before >= Self::TREND_THRESHOLD => {
+                ConsensusTrend::Worsening
+            }
+            (Some(_), Some(_)) => ConsensusTrend::Unchanged,
+            _ => ConsensusTrend::Unknown,
+        };
+
+        Some(Self {
+            period: current.period.clone(),
+            score,
+            analysts,
+            previous_score,
+            trend,
+        })
+    }
+}
+
+fn weighted_score(row: &RecommendationRow) -> (Option<f64>, u32) {
+    let counts = [row.strong_buy, row.buy, row.hold, row.sell, row.strong_sell];
+    let total: u32 = counts.iter().map(|c| c.unwrap_or(0)).sum();
+    if total == 0 {
+        return (None, 0);
+    }
+    let weighted: u32 = counts
+        .iter()
+        .zip(1u32..)
+        .map(|(c, w)| c.unwrap_or(0) * w)
+        .sum();
+    (Some(f64::from(weighted) / f64::from(total)), total)
+}
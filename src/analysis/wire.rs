@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use crate::core::wire::RawNum;
+use crate::core::wire::{RawNum, YahooNumber};
 
 /* ---------------- Serde mapping (only what we need) ---------------- */
 
@@ -69,16 +69,18 @@ pub struct UpgradeNode {
 
 #[derive(Deserialize)]
 pub struct FinancialDataNode {
+    #[serde(rename = "currentPrice")]
+    pub(crate) current_price: Option<YahooNumber>,
     #[serde(rename = "targetMeanPrice")]
-    pub(crate) target_mean_price: Option<RawNum<f64>>,
+    pub(crate) target_mean_price: Option<YahooNumber>,
     #[serde(rename = "targetHighPrice")]
-    pub(crate) target_high_price: Option<RawNum<f64>>,
+    pub(crate) target_high_price: Option<YahooNumber>,
     #[serde(rename = "targetLowPrice")]
-    pub(crate) target_low_price: Option<RawNum<f64>>,
+    pub(crate) target_low_price: Option<YahooNumber>,
     #[serde(rename = "numberOfAnalystOpinions")]
-    pub(crate) number_of_analyst_opinions: Option<RawNum<f64>>,
+    pub(crate) number_of_analyst_opinions: Option<YahooNumber>,
     #[serde(rename = "recommendationMean")]
-    pub(crate) recommendation_mean: Option<RawNum<f64>>,
+    pub(crate) recommendation_mean: Option<YahooNumber>,
     #[serde(rename = "recommendationKey")]
     pub(crate) recommendation_key: Option<String>,
 }
@@ -91,7 +93,7 @@ pub struct EarningsTrendNode {
 #[derive(Deserialize)]
 pub struct EarningsTrendItemNode {
     pub(crate) period: Option<String>,
-    pub(crate) growth: Option<RawNum<f64>>,
+    pub(crate) growth: Option<YahooNumber>,
     #[serde(rename = "earningsEstimate")]
     pub(crate) earnings_estimate: Option<EarningsEstimateNode>,
     #[serde(rename = "revenueEstimate")]
@@ -104,14 +106,14 @@ pub struct EarningsTrendItemNode {
 
 #[derive(Deserialize)]
 pub struct EarningsEstimateNode {
-    pub(crate) avg: Option<RawNum<f64>>,
-    pub(crate) low: Option<RawNum<f64>>,
-    pub(crate) high: Option<RawNum<f64>>,
+    pub(crate) avg: Option<YahooNumber>,
+    pub(crate) low: Option<YahooNumber>,
+    pub(crate) high: Option<YahooNumber>,
     #[serde(rename = "yearAgoEps")]
-    pub(crate) year_ago_eps: Option<RawNum<f64>>,
+    pub(crate) year_ago_eps: Option<YahooNumber>,
     #[serde(rename = "numberOfAnalysts")]
-    pub(crate) num_analysts: Option<RawNum<f64>>,
-    pub(crate) growth: Option<RawNum<f64>>,
+    pub(crate) num_analysts: Option<YahooNumber>,
+    pub(crate) growth: Option<YahooNumber>,
 }
 
 #[derive(Deserialize)]
@@ -122,32 +124,32 @@ pub struct RevenueEstimateNode {
     #[serde(rename = "yearAgoRevenue")]
     pub(crate) year_ago_revenue: Option<RawNum<i64>>,
     #[serde(rename = "numberOfAnalysts")]
-    pub(crate) num_analysts: Option<RawNum<f64>>,
-    pub(crate) growth: Option<RawNum<f64>>,
+    pub(crate) num_analysts: Option<YahooNumber>,
+    pub(crate) growth: Option<YahooNumber>,
 }
 
 #[derive(Deserialize)]
 pub struct EpsTrendNode {
-    pub(crate) current: Option<RawNum<f64>>,
+    pub(crate) current: Option<YahooNumber>,
     #[serde(rename = "7daysAgo")]
-    pub(crate) seven_days_ago: Option<RawNum<f64>>,
+    pub(crate) seven_days_ago: Option<YahooNumber>,
     #[serde(rename = "30daysAgo")]
-    pub(crate) thirty_days_ago: Option<RawNum<f64>>,
+    pub(crate) thirty_days_ago: Option<YahooNumber>,
     #[serde(rename = "60daysAgo")]
-    pub(crate) sixty_days_ago: Option<RawNum<f64>>,
+    pub(crate) sixty_days_ago: Option<YahooNumber>,
     #[serde(rename = "90daysAgo")]
-    pub(crate) ninety_days_ago: Option<RawNum<f64>>,
+    pub(crate) ninety_days_ago: Option<YahooNumber>,
 }
 
 #[derive(Deserialize)]
 #[allow(clippy::struct_field_names)]
 pub struct EpsRevisionsNode {
     #[serde(rename = "upLast7days")]
-    pub(crate) up_last_7_days: Option<RawNum<f64>>,
+    pub(crate) up_last_7_days: Option<YahooNumber>,
     #[serde(rename = "upLast30days")]
-    pub(crate) up_last_30_days: Option<RawNum<f64>>,
+    pub(crate) up_last_30_days: Option<YahooNumber>,
     #[serde(rename = "downLast7days")]
-    pub(crate) down_last_7_days: Option<RawNum<f64>>,
+    pub(crate) down_last_7_days: Option<YahooNumber>,
     #[serde(rename = "downLast30days")]
-    pub(crate) down_last_30_days: Option<RawNum<f64>>,
+    pub(crate) down_last_30_days: Option<YahooNumber>,
 }
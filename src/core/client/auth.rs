@@ -2,6 +2,21 @@
 
 use crate::core::error::YfError;
 use reqwest::header::SET_COOKIE;
+use url::Url;
+
+const CONSENT_WALL: &str = "Yahoo returned a consent page instead of credentials. This happens \
+    for some regions (e.g. the EU); accept the consent prompt for this IP in a browser, or send \
+    requests from a region without the consent wall";
+
+/// Detects Yahoo's cookie-consent interstitial: either a redirect to its consent hosts, or a
+/// 200 HTML page mentioning consent where a cookie or plain-text crumb was expected.
+fn is_consent_page(url: &Url, body: &str) -> bool {
+    let host = url.host_str().unwrap_or_default();
+    if host.starts_with("consent.") || host.starts_with("guce.") {
+        return true;
+    }
+    body.trim_start().starts_with('<') && body.to_ascii_lowercase().contains("consent")
+}
 
 impl super::YfClient {
     pub(crate) async fn ensure_credentials(&self) -> Result<(), YfError> {
@@ -30,6 +45,10 @@ impl super::YfClient {
         state.crumb = None;
     }
 
+    /// Returns the raw (unencoded) crumb.
+    ///
+    /// Crumbs may contain `/`, `+` or `=`, so callers must attach them with
+    /// `query_pairs_mut().append_pair("crumb", ..)` rather than formatting them into a URL.
     pub(crate) async fn crumb(&self) -> Option<String> {
         let state = self.state.read().await;
         state.crumb.clone()
@@ -37,12 +56,20 @@ impl super::YfClient {
 
     async fn get_cookie(&self) -> Result<(), YfError> {
         let req = self.http.get(self.cookie_url.clone());
-        let resp = self.send_with_retry(req, None).await?;
+        let resp = self.send_with_retry(req, self.auth_retry.as_ref()).await?;
+        let final_url = resp.url().clone();
+        if is_consent_page(&final_url, "") {
+            return Err(YfError::Auth(CONSENT_WALL.into()));
+        }
 
-        let cookie = resp
-            .headers()
-            .get(SET_COOKIE)
-            .ok_or(YfError::Auth("No cookie received from fc.yahoo.com".into()))?
+        let Some(header) = resp.headers().get(SET_COOKIE) else {
+            let body = resp.text().await.unwrap_or_default();
+            if is_consent_page(&final_url, &body) {
+                return Err(YfError::Auth(CONSENT_WALL.into()));
+            }
+            return Err(YfError::Auth("No cookie received from fc.yahoo.com".into()));
+        };
+        let cookie = header
             .to_str()
             .map_err(|_| YfError::Auth("Invalid cookie header format".into()))?
             .to_string();
@@ -60,9 +87,16 @@ impl super::YfClient {
 
         let url = self.crumb_url.clone();
         let req = self.http.get(url);
-        let resp = self.send_with_retry(req, None).await?;
+        let resp = self.send_with_retry(req, self.auth_retry.as_ref()).await?;
+        let final_url = resp.url().clone();
+        if !resp.status().is_success() {
+            return Err(YfError::from_response(&resp, final_url.as_str()));
+        }
         let crumb = resp.text().await?;
 
+        if is_consent_page(&final_url, &crumb) {
+            return Err(YfError::Auth(CONSENT_WALL.into()));
+        }
         if crumb.is_empty() || crumb.contains('{') || crumb.contains('<') {
             return Err(YfError::Auth(format!("Received invalid crumb: {crumb}")));
         }
@@ -0,0 +1,23 @@
+//! Source of the current time.
+
+use chrono::{DateTime, Utc};
+
+/// Provides the current time to the client.
+///
+/// Cache expiry and the default windows of time-bounded requests read "now" through the
+/// client's clock, so a fixed or manually advanced clock makes that logic deterministic in
+/// tests. Install one with [`YfClientBuilder::clock`](crate::YfClientBuilder::clock).
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The wall clock, [`Utc::now`]. (Default)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -26,6 +26,12 @@ pub const DEFAULT_CRUMB_URL: &str = "https://query1.finance.yahoo.com/v1/test/ge
 /// Base URL for the Yahoo Finance v7 quote API.
 pub const DEFAULT_BASE_QUOTE_V7: &str = "https://query1.finance.yahoo.com/v7/finance/quote";
 
+/// Base URL for the Yahoo Finance v8 spark API (compact multi-symbol series).
+pub const DEFAULT_BASE_SPARK: &str = "https://query1.finance.yahoo.com/v8/finance/spark";
+
+/// Base URL for the Yahoo Finance v1 search API.
+pub const DEFAULT_BASE_SEARCH_V1: &str = "https://query2.finance.yahoo.com/v1/finance/search";
+
 /// Base URL for the Yahoo Finance v7 options API.
 pub const DEFAULT_BASE_OPTIONS_V7: &str = "https://query1.finance.yahoo.com/v7/finance/options/";
 
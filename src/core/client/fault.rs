@@ -0,0 +1,112 @@
+//! Deterministic fault injection for exercising retry and error handling without a mock server.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+struct FaultRule {
+    url_contains: String,
+    status: Option<u16>,
+    delay: Option<Duration>,
+    remaining: Option<u32>,
+}
+
+/// Describes faults to inject into outgoing requests.
+///
+/// Each rule matches requests whose URL contains a given substring. A matching request
+/// can be delayed, answered with a synthetic status code instead of hitting the network,
+/// or both. Rules are evaluated in insertion order and the first match wins.
+///
+/// Only available with the `fault-injection` feature.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use yfinance_rs::{FaultConfig, YfClient};
+///
+/// let faults = FaultConfig::new()
+///     .status("/v7/finance/quote", 429)
+///     .times(2)
+///     .delay("/v8/finance/chart", Duration::from_millis(50));
+///
+/// let client = YfClient::builder().fault_injection(faults).build().unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FaultConfig {
+    rules: Vec<FaultRule>,
+}
+
+impl FaultConfig {
+    /// Creates an empty fault configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers requests whose URL contains `url_contains` with `status` and an empty body.
+    #[must_use]
+    pub fn status(mut self, url_contains: impl Into<String>, status: u16) -> Self {
+        self.rules.push(FaultRule {
+            url_contains: url_contains.into(),
+            status: Some(status),
+            delay: None,
+            remaining: None,
+        });
+        self
+    }
+
+    /// Delays requests whose URL contains `url_contains` by `delay` before sending them.
+    #[must_use]
+    pub fn delay(mut self, url_contains: impl Into<String>, delay: Duration) -> Self {
+        self.rules.push(FaultRule {
+            url_contains: url_contains.into(),
+            status: None,
+            delay: Some(delay),
+            remaining: None,
+        });
+        self
+    }
+
+    /// Limits the most recently added rule to the first `n` matching requests.
+    #[must_use]
+    pub fn times(mut self, n: u32) -> Self {
+        if let Some(rule) = self.rules.last_mut() {
+            rule.remaining = Some(n);
+        }
+        self
+    }
+}
+
+/// What to do with a matched request.
+pub(super) struct Fault {
+    pub(super) status: Option<u16>,
+    pub(super) delay: Option<Duration>,
+}
+
+#[derive(Debug)]
+pub(super) struct FaultInjector {
+    rules: Mutex<Vec<FaultRule>>,
+}
+
+impl FaultInjector {
+    pub(super) fn new(cfg: FaultConfig) -> Self {
+        Self {
+            rules: Mutex::new(cfg.rules),
+        }
+    }
+
+    pub(super) fn take(&self, url: &str) -> Option<Fault> {
+        let mut rules = self.rules.lock().expect("fault rules lock poisoned");
+        let rule = rules
+            .iter_mut()
+            .find(|r| r.remaining != Some(0) && url.contains(&r.url_contains))?;
+        if let Some(n) = rule.remaining.as_mut() {
+            *n -= 1;
+        }
+        Some(Fault {
+            status: rule.status,
+            delay: rule.delay,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+//! Coalescing of identical requests that are in flight at the same time.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use tokio::sync::watch;
+
+use crate::core::YfError;
+
+/// `None` while the leader is still running, then the body it fetched or the error it got.
+type Outcome = Option<Result<String, YfError>>;
+
+/// Tracks requests currently being performed, keyed by what they fetch.
+#[derive(Debug, Default)]
+pub(crate) struct InFlight {
+    calls: Mutex<HashMap<String, watch::Receiver<Outcome>>>,
+}
+
+/// A caller's role for a request key.
+pub(crate) enum Flight<'a> {
+    /// No identical request is running: perform it and report through the guard.
+    Leader(FlightGuard<'a>),
+    /// An identical request is running: wait for its result.
+    Follower(watch::Receiver<Outcome>),
+}
+
+impl InFlight {
+    /// Registers interest in `key`, becoming the leader if nobody else is fetching it.
+    pub(crate) fn join(&self, key: &str) -> Flight<'_> {
+        let mut calls = self.calls.lock().expect("in-flight lock poisoned");
+        if let Some(rx) = calls.get(key) {
+            return Flight::Follower(rx.clone());
+        }
+        let (tx, rx) = watch::channel(None);
+        calls.insert(key.to_string(), rx);
+        Flight::Leader(FlightGuard {
+            in_flight: self,
+            key: key.to_string(),
+            tx,
+        })
+    }
+}
+
+/// Waits for the leader and returns its body or a copy of its error, so a failure is not
+/// repeated once per waiting caller. Returns `None` if the leader was cancelled, in which case
+/// the follower should perform the request itself.
+pub(crate) async fn follow(mut rx: watch::Receiver<Outcome>) -> Option<Result<String, YfError>> {
+    let outcome = rx.wait_for(Option::is_some).await.ok()?;
+    match outcome.as_ref()? {
+        Ok(body) => Some(Ok(body.clone())),
+        Err(e) => Some(Err(e.for_follower())),
+    }
+}
+
+/// Held by the leader; removes the entry when dropped so later calls start a new request.
+pub(crate) struct FlightGuard<'a> {
+    in_flight: &'a InFlight,
+    key: String,
+    tx: watch::Sender<Outcome>,
+}
+
+impl FlightGuard<'_> {
+    /// Hands the leader's body, or a copy of its error, to every follower.
+    pub(crate) fn complete(self, res: &Result<String, YfError>) {
+        let shared = match res {
+            Ok(body) => Ok(body.clone()),
+            Err(e) => Err(e.for_follower()),
+        };
+        self.tx.send_replace(Some(shared));
+    }
+}
+
+impl Drop for FlightGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut calls) = self.in_flight.calls.lock() {
+            calls.remove(&self.key);
+        }
+    }
+}
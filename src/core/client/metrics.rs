@@ -0,0 +1,75 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A point-in-time snapshot of a client's request counters.
+///
+/// Counters accumulate over the lifetime of a [`YfClient`](crate::YfClient) and are shared by
+/// all of its clones. Take a snapshot with [`YfClient::metrics`](crate::YfClient::metrics).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ClientMetrics {
+    /// HTTP requests sent, counting each retry attempt.
+    pub requests: u64,
+    /// Lookups answered from the in-memory cache.
+    pub cache_hits: u64,
+    /// Lookups that found no fresh entry in the in-memory cache.
+    pub cache_misses: u64,
+    /// Attempts repeated because of a retryable status or transport error.
+    pub retries: u64,
+    /// Responses with status `429 Too Many Requests`.
+    pub rate_limited: u64,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct MetricsCounters {
+    requests: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    retries: AtomicU64,
+    rate_limited: AtomicU64,
+}
+
+impl MetricsCounters {
+    pub(super) fn record_request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_cache(&self, hit: bool) {
+        let counter = if hit {
+            &self.cache_hits
+        } else {
+            &self.cache_misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_retry(&self) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn record_status(&self, status: u16) {
+        if status == 429 {
+            self.rate_limited.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(super) fn snapshot(&self) -> ClientMetrics {
+        ClientMetrics {
+            requests: self.requests.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            cache_misses: self.cache_misses.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            rate_limited: self.rate_limited.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(super) fn reset(&self) {
+        for c in [
+            &self.requests,
+            &self.cache_hits,
+            &self.cache_misses,
+            &self.retries,
+            &self.rate_limited,
+        ] {
+            c.store(0, Ordering::Relaxed);
+        }
+    }
+}
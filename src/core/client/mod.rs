@@ -2,23 +2,40 @@
 //! Internals are split into `auth` (cookie/crumb) and `constants` (UA + defaults).
 
 mod auth;
+mod clock;
 mod constants;
+#[cfg(feature = "fault-injection")]
+mod fault;
+pub(crate) mod in_flight;
+mod metrics;
+mod request_id;
+mod resolver;
 mod retry;
 
-use crate::core::YfError;
 use crate::core::client::constants::DEFAULT_BASE_INSIDER_SEARCH;
 use crate::core::currency::currency_for_country;
+use crate::core::{Interval, Range, YfError};
+pub use clock::{Clock, SystemClock};
+#[cfg(feature = "fault-injection")]
+pub use fault::FaultConfig;
+pub use metrics::ClientMetrics;
 use paft::money::{Currency, IsoCurrency};
+pub use request_id::DEFAULT_REQUEST_ID_HEADER;
+use request_id::RequestIdProvider;
+pub(crate) use request_id::SentRequestId;
+pub use resolver::{DefaultPathResolver, Endpoint, PathResolver};
 pub use retry::{Backoff, CacheMode, RetryConfig};
 
+use chrono::{DateTime, Utc};
 use constants::{
     DEFAULT_BASE_CHART, DEFAULT_BASE_QUOTE, DEFAULT_BASE_QUOTE_API, DEFAULT_COOKIE_URL,
     DEFAULT_CRUMB_URL, USER_AGENT,
 };
 use reqwest::Client;
-use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::sync::RwLock;
 use url::Url;
 
@@ -36,16 +53,169 @@ pub enum ApiPreference {
     ScrapeOnly,
 }
 
+/// Selects which Yahoo API host the default base URLs point at.
+///
+/// Yahoo serves the same JSON endpoints from `query1` and `query2`; switching
+/// hosts can help when one of them is throttled or temporarily unavailable.
+/// Only the `queryN.finance.yahoo.com` defaults are affected; URLs set with the
+/// `base_*` builder methods are used as given.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum YahooHost {
+    /// `query1.finance.yahoo.com`.
+    Query1,
+    /// `query2.finance.yahoo.com`.
+    Query2,
+}
+
+impl YahooHost {
+    /// Returns the host name, e.g. `query1.finance.yahoo.com`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Query1 => "query1.finance.yahoo.com",
+            Self::Query2 => "query2.finance.yahoo.com",
+        }
+    }
+
+    /// Returns the other query host.
+    const fn other(self) -> Self {
+        match self {
+            Self::Query1 => Self::Query2,
+            Self::Query2 => Self::Query1,
+        }
+    }
+
+    fn from_host(host: &str) -> Option<Self> {
+        [Self::Query1, Self::Query2]
+            .into_iter()
+            .find(|h| h.as_str() == host)
+    }
+}
+
+/// Parses a default endpoint URL, moving it onto `host` when it lives on a
+/// `queryN.finance.yahoo.com` host.
+fn default_url(raw: &str, host: Option<YahooHost>) -> Result<Url, YfError> {
+    let mut url = Url::parse(raw)?;
+    if let Some(host) = host {
+        let on_query_host = url
+            .host_str()
+            .is_some_and(|h| h.starts_with("query") && h.ends_with(".finance.yahoo.com"));
+        if on_query_host {
+            url.set_host(Some(host.as_str()))?;
+        }
+    }
+    Ok(url)
+}
+
 #[derive(Debug)]
 struct CacheEntry {
     body: String,
-    expires_at: Instant,
+    expires_at: DateTime<Utc>,
+    // HTTP validators used to revalidate the entry once it has expired.
+    etag: Option<String>,
+    last_modified: Option<String>,
+    // Logical clock value of the last read or write, for LRU eviction; 0 until stored.
+    last_used: AtomicU64,
+}
+
+/// `now + ttl`, saturating for TTLs too large to represent.
+fn expiry(now: DateTime<Utc>, ttl: Duration) -> DateTime<Utc> {
+    chrono::Duration::from_std(ttl)
+        .ok()
+        .and_then(|ttl| now.checked_add_signed(ttl))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+/// Display-currency FX rates by pair symbol, with the time each one expires.
+type DisplayRateCache = HashMap<String, (f64, DateTime<Utc>)>;
+
+/// Cache keys ordered by last use, oldest first.
+#[derive(Debug)]
+struct Recency {
+    next: u64,
+    order: BTreeMap<u64, String>,
+}
+
+impl Recency {
+    const fn new() -> Self {
+        // Tick 0 is reserved for entries that have not been stored yet.
+        Self {
+            next: 1,
+            order: BTreeMap::new(),
+        }
+    }
+
+    /// Moves `key` to the most-recently-used end.
+    fn mark(&mut self, key: &str, entry: &CacheEntry) {
+        let tick = self.next;
+        self.next += 1;
+        let prev = entry.last_used.swap(tick, Ordering::Relaxed);
+        self.order.remove(&prev);
+        self.order.insert(tick, key.to_string());
+    }
+
+    fn forget(&mut self, entry: &CacheEntry) {
+        self.order.remove(&entry.last_used.load(Ordering::Relaxed));
+    }
 }
 
 #[derive(Debug)]
 struct CacheStore {
     map: RwLock<HashMap<String, CacheEntry>>,
     default_ttl: Duration,
+    capacity: usize,
+    // Always locked after `map`, and never held across an await.
+    recency: Mutex<Recency>,
+}
+
+impl CacheStore {
+    fn recency(&self) -> std::sync::MutexGuard<'_, Recency> {
+        self.recency.lock().expect("cache recency lock poisoned")
+    }
+
+    /// Marks a stored entry as just used.
+    fn touch(&self, key: &str, entry: &CacheEntry) {
+        self.recency().mark(key, entry);
+    }
+
+    /// Inserts `entry`, first evicting the least-recently-used entries if a new key would
+    /// exceed the capacity.
+    fn insert(&self, map: &mut HashMap<String, CacheEntry>, key: String, entry: CacheEntry) {
+        let mut recency = self.recency();
+        if let Some(old) = map.get(&key) {
+            recency.forget(old);
+        } else {
+            while map.len() >= self.capacity {
+                let Some((_, oldest)) = recency.order.pop_first() else {
+                    break;
+                };
+                map.remove(&oldest);
+            }
+        }
+        recency.mark(&key, &entry);
+        map.insert(key, entry);
+    }
+
+    fn remove(&self, map: &mut HashMap<String, CacheEntry>, key: &str) {
+        if let Some(old) = map.remove(key) {
+            self.recency().forget(&old);
+        }
+    }
+
+    fn clear(&self, map: &mut HashMap<String, CacheEntry>) {
+        map.clear();
+        self.recency().order.clear();
+    }
+}
+
+/// Default maximum number of responses kept in the in-memory cache.
+const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// Application symbols mapped to Yahoo symbols, and back.
+#[derive(Debug, Default)]
+struct SymbolAliases {
+    to_yahoo: HashMap<String, String>,
+    from_yahoo: HashMap<String, String>,
 }
 
 #[derive(Debug, Default)]
@@ -73,19 +243,37 @@ pub struct YfClient {
     base_news: Url,
     base_insider_search: Url,
     base_timeseries: Url,
+    base_spark: Url,
+    base_search: Url,
     cookie_url: Url,
     crumb_url: Url,
     user_agent: String,
+    default_region: Option<String>,
+    default_history: Option<(Range, Interval)>,
+    normalize_minor_units: bool,
+    display_currency: Option<Currency>,
+    path_resolver: Arc<dyn PathResolver>,
+    clock: Arc<dyn Clock>,
+    request_ids: Option<RequestIdProvider>,
+    host_failover: bool,
+    auth_retry: Option<RetryConfig>,
+    symbol_aliases: Arc<SymbolAliases>,
 
     state: Arc<RwLock<ClientState>>,
     credential_fetch_lock: Arc<tokio::sync::Mutex<()>>,
+    in_flight: Arc<in_flight::InFlight>,
 
     #[cfg(feature = "test-mode")]
     api_preference: ApiPreference,
+    #[cfg(feature = "fault-injection")]
+    faults: Option<Arc<fault::FaultInjector>>,
 
     retry: RetryConfig,
     reporting_currency_cache: Arc<RwLock<HashMap<String, Currency>>>,
+    display_rate_cache: Arc<RwLock<DisplayRateCache>>,
     cache: Option<Arc<CacheStore>>,
+    limiter: Option<Arc<tokio::sync::Semaphore>>,
+    metrics: Arc<metrics::MetricsCounters>,
 }
 
 impl Default for YfClient {
@@ -103,31 +291,55 @@ impl YfClient {
 
     /* -------- internal getters used by other modules -------- */
 
-    pub(crate) const fn http(&self) -> &Client {
-        &self.http
+    /// The current time according to the configured [`Clock`].
+    pub(crate) fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
     }
 
-    pub(crate) fn user_agent(&self) -> &str {
-        &self.user_agent
+    /// Requests currently in flight, used to coalesce identical concurrent fetches.
+    pub(crate) fn in_flight(&self) -> &in_flight::InFlight {
+        &self.in_flight
+    }
+
+    /// Builds the URL for `symbol` on `endpoint` through the configured [`PathResolver`].
+    pub(crate) fn symbol_url(&self, endpoint: Endpoint, symbol: &str) -> Result<Url, YfError> {
+        let base = match endpoint {
+            Endpoint::Chart => &self.base_chart,
+            Endpoint::QuoteSummary => &self.base_quote_api,
+            Endpoint::Options => &self.base_options_v7,
+            Endpoint::Timeseries => &self.base_timeseries,
+            Endpoint::QuotePage => &self.base_quote,
+        };
+        self.path_resolver
+            .resolve(endpoint, base, self.yahoo_symbol(symbol))
     }
 
-    pub(crate) const fn base_chart(&self) -> &Url {
-        &self.base_chart
+    /// The symbol Yahoo knows `symbol` by, after applying
+    /// [`symbol_alias`](YfClientBuilder::symbol_alias) mappings.
+    pub(crate) fn yahoo_symbol<'a>(&'a self, symbol: &'a str) -> &'a str {
+        self.symbol_aliases
+            .to_yahoo
+            .get(symbol)
+            .map_or(symbol, String::as_str)
     }
 
-    pub(crate) const fn base_quote(&self) -> &Url {
-        &self.base_quote
+    /// Rewrites a symbol returned by Yahoo to the application's alias for it, if any.
+    pub(crate) fn unalias_symbol(&self, symbol: &mut String) {
+        if let Some(app) = self.symbol_aliases.from_yahoo.get(symbol.as_str()) {
+            symbol.clone_from(app);
+        }
     }
-    pub(crate) const fn base_quote_api(&self) -> &Url {
-        &self.base_quote_api
+
+    pub(crate) const fn http(&self) -> &Client {
+        &self.http
     }
 
-    pub(crate) const fn base_quote_v7(&self) -> &Url {
-        &self.base_quote_v7
+    pub(crate) fn user_agent(&self) -> &str {
+        &self.user_agent
     }
 
-    pub(crate) const fn base_options_v7(&self) -> &Url {
-        &self.base_options_v7
+    pub(crate) const fn base_quote_v7(&self) -> &Url {
+        &self.base_quote_v7
     }
 
     pub(crate) const fn base_stream(&self) -> &Url {
@@ -142,8 +354,12 @@ impl YfClient {
         &self.base_insider_search
     }
 
-    pub(crate) const fn base_timeseries(&self) -> &Url {
-        &self.base_timeseries
+    pub(crate) const fn base_spark(&self) -> &Url {
+        &self.base_spark
+    }
+
+    pub(crate) const fn base_search(&self) -> &Url {
+        &self.base_search
     }
 
     #[cfg(feature = "test-mode")]
@@ -151,6 +367,62 @@ impl YfClient {
         self.api_preference
     }
 
+    /// Returns the default `region` applied to quote and search requests, if configured.
+    #[must_use]
+    pub fn default_region(&self) -> Option<&str> {
+        self.default_region.as_deref()
+    }
+
+    /// Returns the default range and interval used by [`Ticker::history`](crate::Ticker::history)
+    /// when called without them, if configured.
+    #[must_use]
+    pub const fn default_history(&self) -> Option<(Range, Interval)> {
+        self.default_history
+    }
+
+    /// Returns whether prices quoted in minor units (`GBp`/`GBX` pence) are restated in the
+    /// major currency.
+    #[must_use]
+    pub const fn normalize_minor_units(&self) -> bool {
+        self.normalize_minor_units
+    }
+
+    /// Returns the currency `Money` outputs are restated in, if configured.
+    #[must_use]
+    pub const fn display_currency(&self) -> Option<&Currency> {
+        self.display_currency.as_ref()
+    }
+
+    /// A display-currency FX rate stored by [`store_display_rate`](Self::store_display_rate),
+    /// keyed by pair symbol (`USDEUR=X`), if it has not expired.
+    pub(crate) async fn cached_display_rate(&self, pair: &str) -> Option<f64> {
+        self.display_rate_cache
+            .read()
+            .await
+            .get(pair)
+            .filter(|(_, expires_at)| self.now() <= *expires_at)
+            .map(|(rate, _)| *rate)
+    }
+
+    /// Remembers a display-currency FX rate for the client's cache TTL. Does nothing when
+    /// caching is disabled.
+    pub(crate) async fn store_display_rate(&self, pair: String, rate: f64) {
+        let Some(store) = &self.cache else {
+            return;
+        };
+        let expires_at = expiry(self.now(), store.default_ttl);
+        self.display_rate_cache
+            .write()
+            .await
+            .insert(pair, (rate, expires_at));
+    }
+
+    /// Returns the `quoteSummary` modules this client can request and parse.
+    #[must_use]
+    pub const fn supported_modules(&self) -> &'static [crate::core::QuoteSummaryModule] {
+        crate::core::QuoteSummaryModule::all()
+    }
+
     /// Returns `true` if in-memory caching is enabled for this client.
     #[must_use]
     pub const fn cache_enabled(&self) -> bool {
@@ -160,12 +432,18 @@ impl YfClient {
     pub(crate) async fn cache_get(&self, url: &Url) -> Option<String> {
         let store = self.cache.as_ref()?;
         let key = url.as_str().to_string();
-        if let Some(entry) = store.map.read().await.get(&key)
-            && Instant::now() <= entry.expires_at
-        {
-            return Some(entry.body.clone());
-        }
-        None
+        let hit = store
+            .map
+            .read()
+            .await
+            .get(&key)
+            .filter(|entry| self.now() <= entry.expires_at)
+            .map(|entry| {
+                store.touch(&key, entry);
+                entry.body.clone()
+            });
+        self.metrics.record_cache(hit.is_some());
+        hit
     }
 
     pub(crate) async fn cache_put(&self, url: &Url, body: &str, ttl_override: Option<Duration>) {
@@ -175,13 +453,88 @@ impl YfClient {
         };
         let key = url.as_str().to_string();
         let ttl = ttl_override.unwrap_or(store.default_ttl);
-        let expires_at = Instant::now() + ttl;
+        let expires_at = expiry(self.now(), ttl);
         let entry = CacheEntry {
             body: body.to_string(),
             expires_at,
+            etag: None,
+            last_modified: None,
+            last_used: AtomicU64::new(0),
+        };
+        let mut guard = store.map.write().await;
+        store.insert(&mut guard, key, entry);
+    }
+
+    /// Like [`cache_put`](Self::cache_put), but also stores the response's `ETag` and
+    /// `Last-Modified` headers so the entry can be revalidated after it expires.
+    pub(crate) async fn cache_put_validated(
+        &self,
+        url: &Url,
+        body: &str,
+        headers: &reqwest::header::HeaderMap,
+    ) {
+        let Some(store) = &self.cache else {
+            return;
+        };
+        let header = |name: reqwest::header::HeaderName| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_owned)
+        };
+        let entry = CacheEntry {
+            body: body.to_string(),
+            expires_at: expiry(self.now(), store.default_ttl),
+            etag: header(reqwest::header::ETAG),
+            last_modified: header(reqwest::header::LAST_MODIFIED),
+            last_used: AtomicU64::new(0),
         };
         let mut guard = store.map.write().await;
-        guard.insert(key, entry);
+        store.insert(&mut guard, url.as_str().to_string(), entry);
+    }
+
+    /// Adds `If-None-Match` / `If-Modified-Since` for a stored entry of `url`, if it has
+    /// validators. Entries without validators fall back to plain TTL caching.
+    pub(crate) async fn with_cache_validators(
+        &self,
+        url: &Url,
+        mut req: reqwest::RequestBuilder,
+    ) -> reqwest::RequestBuilder {
+        let Some(store) = &self.cache else {
+            return req;
+        };
+        if let Some(entry) = store.map.read().await.get(url.as_str()) {
+            if let Some(etag) = &entry.etag {
+                req = req.header(reqwest::header::IF_NONE_MATCH, etag);
+            }
+            if let Some(lm) = &entry.last_modified {
+                req = req.header(reqwest::header::IF_MODIFIED_SINCE, lm);
+            }
+        }
+        req
+    }
+
+    /// Handles a `304 Not Modified` for `url`: renews the stored entry's TTL and returns
+    /// its body, or `None` if nothing is cached.
+    pub(crate) async fn cache_revalidated(&self, url: &Url) -> Option<String> {
+        let store = self.cache.as_ref()?;
+        let mut guard = store.map.write().await;
+        let entry = guard.get_mut(url.as_str())?;
+        entry.expires_at = expiry(self.now(), store.default_ttl);
+        store.touch(url.as_str(), entry);
+        Some(entry.body.clone())
+    }
+
+    /// Returns a snapshot of the request, cache, retry and rate-limit counters accumulated
+    /// since the client was built (or last reset). Clones share the same counters.
+    #[must_use]
+    pub fn metrics(&self) -> ClientMetrics {
+        self.metrics.snapshot()
+    }
+
+    /// Resets all counters reported by [`metrics`](Self::metrics) to zero.
+    pub fn metrics_reset(&self) {
+        self.metrics.reset();
     }
 
     /// Clears the entire in-memory cache.
@@ -191,8 +544,9 @@ impl YfClient {
     pub async fn clear_cache(&self) {
         if let Some(store) = &self.cache {
             let mut guard = store.map.write().await;
-            guard.clear();
+            store.clear(&mut guard);
         }
+        self.display_rate_cache.write().await.clear();
     }
 
     /// Removes a specific URL-based entry from the in-memory cache.
@@ -203,7 +557,7 @@ impl YfClient {
         if let Some(store) = &self.cache {
             let key = url.as_str().to_string();
             let mut guard = store.map.write().await;
-            guard.remove(&key);
+            store.remove(&mut guard, &key);
         }
     }
 
@@ -235,13 +589,10 @@ impl YfClient {
 
         let mut debug_reason: Option<String> = None;
         let currency = match crate::profile::load_profile(self, symbol).await {
-            Ok(profile) => extract_currency_from_profile(&profile).map_or_else(
-                || {
-                    debug_reason = Some("profile missing country or unsupported currency".into());
-                    Currency::Iso(IsoCurrency::USD)
-                },
-                |currency| currency,
-            ),
+            Ok(profile) => extract_currency_from_profile(&profile).unwrap_or_else(|| {
+                debug_reason = Some("profile missing country or unsupported currency".into());
+                Currency::Iso(IsoCurrency::USD)
+            }),
             Err(err) => {
                 debug_reason = Some(format!("failed to load profile: {err}"));
                 Currency::Iso(IsoCurrency::USD)
@@ -269,21 +620,75 @@ impl YfClient {
     ) -> Result<reqwest::Response, reqwest::Error> {
         // Always set User-Agent header explicitly
         req = req.header("User-Agent", &self.user_agent);
+        // One correlation ID per logical request, shared by its retries.
+        let sent = self.request_ids.as_ref().map(|ids| SentRequestId {
+            header: ids.header().to_string(),
+            id: ids.next_id(),
+        });
+        if let Some(sent) = &sent {
+            req = req.header(&sent.header, &sent.id);
+        }
 
         let cfg = override_retry.unwrap_or(&self.retry);
+        let spare = if self.host_failover {
+            req.try_clone()
+        } else {
+            None
+        };
+        let mut result = self.send_attempts(req, cfg).await;
+
+        let failed = match &result {
+            Ok(resp) => resp.status().is_server_error(),
+            Err(e) => e.is_connect(),
+        };
+        if failed && let Some(alt) = spare.and_then(|r| self.on_other_host(r)) {
+            result = self.send_attempts(alt, cfg).await;
+        }
+        if let (Ok(resp), Some(sent)) = (&mut result, sent) {
+            resp.extensions_mut().insert(sent);
+        }
+        result
+    }
+
+    /// Moves a request on `query1.finance.yahoo.com` to `query2` and vice versa.
+    ///
+    /// Returns `None` for requests to any other host. The cookie is scoped to `.yahoo.com`
+    /// and the crumb travels in the query string, so both remain valid on the other host.
+    fn on_other_host(&self, req: reqwest::RequestBuilder) -> Option<reqwest::RequestBuilder> {
+        let mut request = req.build().ok()?;
+        let other = YahooHost::from_host(request.url().host_str()?)?.other();
+        request.url_mut().set_host(Some(other.as_str())).ok()?;
+        Some(reqwest::RequestBuilder::from_parts(
+            self.http.clone(),
+            request,
+        ))
+    }
+
+    /// Sends `req`, retrying according to `cfg`.
+    async fn send_attempts(
+        &self,
+        req: reqwest::RequestBuilder,
+        cfg: &RetryConfig,
+    ) -> Result<reqwest::Response, reqwest::Error> {
         if !cfg.enabled {
-            return req.send().await;
+            let _permit = self.acquire_permit().await;
+            return self.dispatch(req).await;
         }
 
         let mut attempt = 0u32;
         loop {
-            let response = req.try_clone().expect("cloneable request").send().await;
+            let response = {
+                let _permit = self.acquire_permit().await;
+                self.dispatch(req.try_clone().expect("cloneable request"))
+                    .await
+            };
 
             match response {
                 Ok(resp) => {
                     let code = resp.status().as_u16();
                     if cfg.retry_on_status.contains(&code) && attempt < cfg.max_retries {
-                        sleep_backoff(&cfg.backoff, attempt).await;
+                        self.metrics.record_retry();
+                        sleep_backoff(cfg, attempt).await;
                         attempt += 1;
                         continue;
                     }
@@ -294,7 +699,8 @@ impl YfClient {
                         || (cfg.retry_on_connect && e.is_connect());
 
                     if should_retry && attempt < cfg.max_retries {
-                        sleep_backoff(&cfg.backoff, attempt).await;
+                        self.metrics.record_retry();
+                        sleep_backoff(cfg, attempt).await;
                         attempt += 1;
                         continue;
                     }
@@ -304,6 +710,69 @@ impl YfClient {
         }
     }
 
+    /// Waits for a slot under the client-wide concurrency limit, if one is configured.
+    ///
+    /// The permit is held only while a single attempt is in flight, so backoff sleeps
+    /// between retries do not occupy a slot.
+    async fn acquire_permit(&self) -> Option<tokio::sync::OwnedSemaphorePermit> {
+        match &self.limiter {
+            Some(sem) => Arc::clone(sem).acquire_owned().await.ok(),
+            None => None,
+        }
+    }
+
+    /// Sends one attempt and records it in the client metrics.
+    async fn dispatch(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        self.metrics.record_request();
+        let resp = self.dispatch_inner(req).await;
+        if let Ok(r) = &resp {
+            self.metrics.record_status(r.status().as_u16());
+        }
+        resp
+    }
+
+    #[cfg(not(feature = "fault-injection"))]
+    async fn dispatch_inner(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        req.send().await
+    }
+
+    #[cfg(feature = "fault-injection")]
+    async fn dispatch_inner(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let Some(faults) = &self.faults else {
+            return req.send().await;
+        };
+        let url = req
+            .try_clone()
+            .and_then(|r| r.build().ok())
+            .map(|r| r.url().to_string())
+            .unwrap_or_default();
+        let Some(fault) = faults.take(&url) else {
+            return req.send().await;
+        };
+        if let Some(d) = fault.delay {
+            tokio::time::sleep(d).await;
+        }
+        match fault.status {
+            Some(status) => {
+                let resp = http::Response::builder()
+                    .status(status)
+                    .body(Vec::<u8>::new())
+                    .expect("valid synthetic response");
+                Ok(reqwest::Response::from(resp))
+            }
+            None => req.send().await,
+        }
+    }
+
     /// Returns a reference to the default `RetryConfig` for this client.
     ///
     /// This config is used for all requests unless overridden on a per-call basis.
@@ -328,8 +797,22 @@ pub struct YfClientBuilder {
     base_news: Option<Url>,
     base_insider_search: Option<Url>,
     base_timeseries: Option<Url>,
+    base_spark: Option<Url>,
+    base_search: Option<Url>,
     cookie_url: Option<Url>,
     crumb_url: Option<Url>,
+    host: Option<YahooHost>,
+    default_region: Option<String>,
+    default_history: Option<(Range, Interval)>,
+    normalize_minor_units: bool,
+    display_currency: Option<Currency>,
+    path_resolver: Option<Arc<dyn PathResolver>>,
+    clock: Option<Arc<dyn Clock>>,
+    request_id_provider: Option<Arc<dyn Fn() -> String + Send + Sync>>,
+    request_id_header: Option<String>,
+    host_failover: bool,
+    auth_retry: Option<RetryConfig>,
+    symbol_aliases: SymbolAliases,
 
     #[allow(dead_code)]
     api_preference: Option<ApiPreference>,
@@ -342,6 +825,10 @@ pub struct YfClientBuilder {
     connect_timeout: Option<Duration>,
     retry: Option<RetryConfig>,
     cache_ttl: Option<Duration>,
+    cache_capacity: Option<usize>,
+    max_concurrent_requests: Option<usize>,
+    #[cfg(feature = "fault-injection")]
+    faults: Option<fault::FaultConfig>,
 
     // New fields for custom client and proxy configuration
     custom_client: Option<Client>,
@@ -448,7 +935,45 @@ impl YfClientBuilder {
         self
     }
 
+    /// Sets a custom base URL for the spark endpoint.
+    #[must_use]
+    pub fn base_spark(mut self, url: Url) -> Self {
+        self.base_spark = Some(url);
+        self
+    }
+
+    /// Sets a custom base URL for the search endpoint.
+    #[must_use]
+    pub fn base_search(mut self, url: Url) -> Self {
+        self.base_search = Some(url);
+        self
+    }
+
+    /// Chooses which Yahoo API host (`query1` or `query2`) the default base URLs use.
+    ///
+    /// Explicit `base_*` and [`crumb_url`](Self::crumb_url) overrides take precedence.
+    #[must_use]
+    pub const fn host(mut self, host: YahooHost) -> Self {
+        self.host = Some(host);
+        self
+    }
+
+    /// Sets whether a request to `query1.finance.yahoo.com` that still fails after retries
+    /// is sent once more to `query2` (and vice versa). (Default: `false`)
+    ///
+    /// A request fails over on a connection error or a 5xx status; the second host gets the
+    /// same retry policy. The cookie and crumb are shared by both hosts. Requests to any
+    /// other host, including custom `base_*` URLs, are unaffected.
+    #[must_use]
+    pub const fn host_failover(mut self, yes: bool) -> Self {
+        self.host_failover = yes;
+        self
+    }
+
     /// Overrides the URL used to acquire an initial cookie.
+    ///
+    /// Together with [`crumb_url`](Self::crumb_url) this lets the whole
+    /// 401 → cookie → crumb → retry flow run against a mock server.
     #[must_use]
     pub fn cookie_url(mut self, url: Url) -> Self {
         self.cookie_url = Some(url);
@@ -462,6 +987,61 @@ impl YfClientBuilder {
         self
     }
 
+    /// Sets a default `region` (e.g. `"US"`, `"GB"`, `"DE"`) sent with quote and search requests.
+    ///
+    /// Some tickers resolve to different listings depending on region. Per-call builders
+    /// that expose their own `region` setter take precedence over this default.
+    #[must_use]
+    pub fn default_region(mut self, code: impl Into<String>) -> Self {
+        self.default_region = Some(code.into());
+        self
+    }
+
+    /// Sets the range and interval [`Ticker::history`](crate::Ticker::history) uses when it
+    /// is called with `None` for them (otherwise `6mo` of daily candles).
+    ///
+    /// Explicit arguments and [`HistoryBuilder`](crate::HistoryBuilder) settings take
+    /// precedence over this default.
+    #[must_use]
+    pub const fn default_history(mut self, range: Range, interval: Interval) -> Self {
+        self.default_history = Some((range, interval));
+        self
+    }
+
+    /// Restates prices Yahoo quotes in minor units in the major currency. (Default: `false`)
+    ///
+    /// London listings are quoted in pence (`GBp`, sometimes `GBX`) while dividends and
+    /// fundamentals are usually in pounds, which is easy to mix up by a factor of 100. When
+    /// enabled, quote and chart prices in those currencies are divided by 100 and labelled
+    /// `GBP`. Corporate actions are left as reported. By default Yahoo's values are kept as is.
+    #[must_use]
+    pub const fn normalize_minor_units(mut self, yes: bool) -> Self {
+        self.normalize_minor_units = yes;
+        self
+    }
+
+    /// Restates `Money` outputs in a single currency, converting via the [`fx`](crate::fx)
+    /// module.
+    ///
+    /// Applies to quotes ([`Ticker::quote`](crate::Ticker::quote),
+    /// [`Ticker::fast_info`](crate::Ticker::fast_info) and the
+    /// [`QuotesBuilder`](crate::QuotesBuilder) `fetch`, `fetch_details`, `fetch_partial` and
+    /// `fetch_fast_info` methods), dividends from [`DividendsBuilder`](crate::DividendsBuilder)
+    /// without an explicit `convert_to`, and the income statement, balance sheet and cash flow
+    /// statements, so a mixed-currency portfolio renders uniformly. Amounts are converted at
+    /// the latest rate.
+    ///
+    /// Each source currency costs one extra chart request for its rate. With caching enabled
+    /// ([`cache_ttl`](Self::cache_ttl)) rates are kept for the cache TTL and shared between
+    /// calls, so the cost is paid once per currency pair and TTL; without a cache every call
+    /// looks its rates up again. Calls made with `CacheMode::Refresh` refetch the rate;
+    /// `CacheMode::Bypass` neither reads nor stores it.
+    #[must_use]
+    pub fn display_currency(mut self, currency: Currency) -> Self {
+        self.display_currency = Some(currency);
+        self
+    }
+
     /// Sets the entire retry configuration.
     ///
     /// Replaces the default retry settings.
@@ -480,6 +1060,41 @@ impl YfClientBuilder {
         self
     }
 
+    /// Maps an application symbol to the symbol Yahoo uses for the same instrument.
+    ///
+    /// Requests made for `internal` are sent for `yahoo`, and symbols in responses that
+    /// equal `yahoo` are reported back as `internal`, so application vocabulary (e.g.
+    /// `GOOG_MAIN`) never has to leak into calling code. Applies to every request that
+    /// names a symbol: quotes, history, `quoteSummary` modules, fundamentals timeseries,
+    /// options, profiles, ISIN lookups, spark series and WebSocket subscriptions (streamed
+    /// updates are reported under `internal`). Call once per alias; a later alias for the
+    /// same symbol replaces the earlier one.
+    #[must_use]
+    pub fn symbol_alias(mut self, internal: impl Into<String>, yahoo: impl Into<String>) -> Self {
+        let (internal, yahoo) = (internal.into(), yahoo.into());
+        if let Some(old) = self
+            .symbol_aliases
+            .to_yahoo
+            .insert(internal.clone(), yahoo.clone())
+        {
+            self.symbol_aliases.from_yahoo.remove(&old);
+        }
+        self.symbol_aliases.from_yahoo.insert(yahoo, internal);
+        self
+    }
+
+    /// Sets the retry policy for fetching the cookie and crumb, independently of data calls.
+    ///
+    /// The credential bootstrap can fail transiently (e.g. a `503` from the crumb endpoint);
+    /// a dedicated policy keeps authentication resilient even when data-call retries are
+    /// disabled or tuned for something else. Defaults to the client's
+    /// [`retry_config`](Self::retry_config).
+    #[must_use]
+    pub fn auth_retry_config(mut self, cfg: RetryConfig) -> Self {
+        self.auth_retry = Some(cfg);
+        self
+    }
+
     /// Disables in-memory caching for this client.
     #[must_use]
     pub const fn no_cache(mut self) -> Self {
@@ -487,6 +1102,17 @@ impl YfClientBuilder {
         self
     }
 
+    /// Injects synthetic failures or delays into matching requests.
+    ///
+    /// Intended for testing retry and error handling in downstream code without
+    /// standing up a mock server. Only available with the `fault-injection` feature.
+    #[cfg(feature = "fault-injection")]
+    #[must_use]
+    pub fn fault_injection(mut self, cfg: FaultConfig) -> Self {
+        self.faults = Some(cfg);
+        self
+    }
+
     /// (Internal testing only) Chooses which data source path to use for profile lookups.
     ///
     /// This setting only has effect when the `test-mode` feature is enabled.
@@ -536,6 +1162,19 @@ impl YfClientBuilder {
         self
     }
 
+    /// Caps the number of HTTP requests this client (and all of its clones) has in flight.
+    ///
+    /// The limit is shared by every operation: quotes, history, fundamentals, search and so on.
+    /// A slot is held from sending a request until its response headers arrive; retry backoff
+    /// does not hold a slot. A value of `0` is treated as `1`.
+    ///
+    /// Default: unlimited.
+    #[must_use]
+    pub const fn max_concurrent_requests(mut self, n: usize) -> Self {
+        self.max_concurrent_requests = Some(n);
+        self
+    }
+
     /// Enables in-memory caching with a default Time-To-Live (TTL) for all responses.
     ///
     /// If not set, caching is disabled by default.
@@ -545,6 +1184,65 @@ impl YfClientBuilder {
         self
     }
 
+    /// Sets the source of the current time. (Default: [`SystemClock`])
+    ///
+    /// Used for cache expiry and for the default time windows of requests such as
+    /// shares-outstanding history, e.g. to test TTL boundaries with a fixed clock.
+    #[must_use]
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Attaches a correlation ID from `provider` to every outgoing HTTP request, so crate
+    /// activity can be matched with the caller's own logs.
+    ///
+    /// The ID is sent in the [`DEFAULT_REQUEST_ID_HEADER`] (`X-Request-Id`) header unless
+    /// [`request_id_header`](Self::request_id_header) names another. `provider` is called
+    /// once per logical request; retries of that request reuse its ID.
+    ///
+    /// When Yahoo answers with an error status, the ID is appended to the `url` of the
+    /// resulting [`YfError::NotFound`], [`YfError::RateLimited`], [`YfError::ServerError`]
+    /// or [`YfError::Status`], e.g. `https://... (X-Request-Id: abc)`. Transport failures
+    /// ([`YfError::Http`]) never reached Yahoo and do not carry it.
+    #[must_use]
+    pub fn request_id_provider(
+        mut self,
+        provider: impl Fn() -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.request_id_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Sets the header the [`request_id_provider`](Self::request_id_provider) ID is sent in.
+    /// (Default: [`DEFAULT_REQUEST_ID_HEADER`])
+    #[must_use]
+    pub fn request_id_header(mut self, name: impl Into<String>) -> Self {
+        self.request_id_header = Some(name.into());
+        self
+    }
+
+    /// Sets how per-symbol endpoint URLs are built. (Default: [`DefaultPathResolver`])
+    ///
+    /// The resolver receives each endpoint's base URL (as configured with the `base_*`
+    /// methods) and the symbol, and returns the URL to request.
+    #[must_use]
+    pub fn path_resolver(mut self, resolver: impl PathResolver + 'static) -> Self {
+        self.path_resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Caps the in-memory cache at `n` responses. (Default: 1024)
+    ///
+    /// When a new response would exceed the cap, the least-recently-used entry is evicted.
+    /// Only has an effect when caching is enabled with [`cache_ttl`](Self::cache_ttl); a
+    /// capacity of 0 disables the cache.
+    #[must_use]
+    pub const fn cache_capacity(mut self, n: usize) -> Self {
+        self.cache_capacity = Some(n);
+        self
+    }
+
     /// Sets a custom reqwest client for full control over HTTP configuration.
     ///
     /// This allows you to configure advanced features like custom TLS settings,
@@ -709,17 +1407,24 @@ impl YfClientBuilder {
     ///
     /// Returns an error if the base URLs are invalid or the HTTP client fails to build.
     pub fn build(self) -> Result<YfClient, YfError> {
-        let base_chart = self.base_chart.unwrap_or(Url::parse(DEFAULT_BASE_CHART)?);
+        let host = self.host;
+        let base_chart = match self.base_chart {
+            Some(u) => u,
+            None => default_url(DEFAULT_BASE_CHART, host)?,
+        };
         let base_quote = self.base_quote.unwrap_or(Url::parse(DEFAULT_BASE_QUOTE)?);
-        let base_quote_api = self
-            .base_quote_api
-            .unwrap_or(Url::parse(DEFAULT_BASE_QUOTE_API)?);
-        let base_quote_v7 = self
-            .base_quote_v7
-            .unwrap_or(Url::parse(constants::DEFAULT_BASE_QUOTE_V7)?);
-        let base_options_v7 = self
-            .base_options_v7
-            .unwrap_or(Url::parse(constants::DEFAULT_BASE_OPTIONS_V7)?);
+        let base_quote_api = match self.base_quote_api {
+            Some(u) => u,
+            None => default_url(DEFAULT_BASE_QUOTE_API, host)?,
+        };
+        let base_quote_v7 = match self.base_quote_v7 {
+            Some(u) => u,
+            None => default_url(constants::DEFAULT_BASE_QUOTE_V7, host)?,
+        };
+        let base_options_v7 = match self.base_options_v7 {
+            Some(u) => u,
+            None => default_url(constants::DEFAULT_BASE_OPTIONS_V7, host)?,
+        };
         let base_stream = self
             .base_stream
             .unwrap_or(Url::parse(constants::DEFAULT_BASE_STREAM)?);
@@ -729,15 +1434,40 @@ impl YfClientBuilder {
         let base_insider_search = self
             .base_insider_search
             .unwrap_or(Url::parse(DEFAULT_BASE_INSIDER_SEARCH)?);
-        let base_timeseries = self
-            .base_timeseries
-            .unwrap_or(Url::parse(constants::DEFAULT_BASE_TIMESERIES)?);
+        let base_timeseries = match self.base_timeseries {
+            Some(u) => u,
+            None => default_url(constants::DEFAULT_BASE_TIMESERIES, host)?,
+        };
+        let base_spark = match self.base_spark {
+            Some(u) => u,
+            None => default_url(constants::DEFAULT_BASE_SPARK, host)?,
+        };
+        let base_search = match self.base_search {
+            Some(u) => u,
+            None => default_url(constants::DEFAULT_BASE_SEARCH_V1, host)?,
+        };
 
         let cookie_url = self.cookie_url.unwrap_or(Url::parse(DEFAULT_COOKIE_URL)?);
-        let crumb_url = self.crumb_url.unwrap_or(Url::parse(DEFAULT_CRUMB_URL)?);
+        let crumb_url = match self.crumb_url {
+            Some(u) => u,
+            None => default_url(DEFAULT_CRUMB_URL, host)?,
+        };
 
         let user_agent = self.user_agent.as_deref().unwrap_or(USER_AGENT).to_string();
 
+        let request_ids = match self.request_id_provider {
+            Some(make) => {
+                let header = self
+                    .request_id_header
+                    .unwrap_or_else(|| DEFAULT_REQUEST_ID_HEADER.to_string());
+                reqwest::header::HeaderName::from_bytes(header.as_bytes()).map_err(|_| {
+                    YfError::InvalidParams(format!("invalid request id header: {header}"))
+                })?;
+                Some(RequestIdProvider::new(header, make))
+            }
+            None => None,
+        };
+
         // Use custom client if provided, otherwise build a new one
         let http = if let Some(custom_client) = self.custom_client {
             custom_client
@@ -791,55 +1521,54 @@ impl YfClientBuilder {
             base_news,
             base_insider_search,
             base_timeseries,
+            base_spark,
+            base_search,
             cookie_url,
             crumb_url,
             user_agent,
+            default_region: self.default_region,
+            default_history: self.default_history,
+            normalize_minor_units: self.normalize_minor_units,
+            display_currency: self.display_currency,
+            path_resolver: self
+                .path_resolver
+                .unwrap_or_else(|| Arc::new(DefaultPathResolver)),
+            clock: self.clock.unwrap_or_else(|| Arc::new(SystemClock)),
+            request_ids,
+            host_failover: self.host_failover,
+            auth_retry: self.auth_retry,
+            symbol_aliases: Arc::new(self.symbol_aliases),
             state: Arc::new(RwLock::new(initial_state)),
             credential_fetch_lock: Arc::new(tokio::sync::Mutex::new(())),
+            in_flight: Arc::new(in_flight::InFlight::default()),
             #[cfg(feature = "test-mode")]
             api_preference: self.api_preference.unwrap_or(ApiPreference::ApiThenScrape),
+            #[cfg(feature = "fault-injection")]
+            faults: self
+                .faults
+                .map(|cfg| Arc::new(fault::FaultInjector::new(cfg))),
             retry: self.retry.unwrap_or_default(),
             reporting_currency_cache: Arc::new(RwLock::new(HashMap::new())),
-            cache: self.cache_ttl.map(|ttl| {
-                Arc::new(CacheStore {
-                    map: RwLock::new(HashMap::new()),
-                    default_ttl: ttl,
-                })
-            }),
+            display_rate_cache: Arc::new(RwLock::new(HashMap::new())),
+            cache: self
+                .cache_ttl
+                .filter(|_| self.cache_capacity != Some(0))
+                .map(|ttl| {
+                    Arc::new(CacheStore {
+                        map: RwLock::new(HashMap::new()),
+                        default_ttl: ttl,
+                        capacity: self.cache_capacity.unwrap_or(DEFAULT_CACHE_CAPACITY),
+                        recency: Mutex::new(Recency::new()),
+                    })
+                }),
+            limiter: self
+                .max_concurrent_requests
+                .map(|n| Arc::new(tokio::sync::Semaphore::new(n.max(1)))),
+            metrics: Arc::new(metrics::MetricsCounters::default()),
         })
     }
 }
 
-async fn sleep_backoff(b: &Backoff, attempt: u32) {
-    use std::time::Duration;
-    let dur = match *b {
-        Backoff::Fixed(d) => d,
-        Backoff::Exponential {
-            base,
-            factor,
-            max,
-            jitter,
-        } => {
-            let pow = factor.powi(i32::try_from(attempt).unwrap());
-            let mut d = Duration::from_secs_f64(base.as_secs_f64() * pow);
-            if d > max {
-                d = max;
-            }
-            if jitter {
-                // simple +/- 50% jitter without extra deps
-                let nanos = d.as_nanos();
-                let j = u64::try_from(nanos / 2).unwrap_or(0)
-                    * ((u64::from(attempt) % 5 + 1) * 13 % 100)
-                    / 100;
-                let add = attempt.is_multiple_of(2);
-                d = if add {
-                    d.saturating_add(Duration::from_nanos(j))
-                } else {
-                    d.saturating_sub(Duration::from_nanos(j))
-                };
-            }
-            d
-        }
-    };
-    tokio::time::sleep(dur).await;
+async fn sleep_backoff(cfg: &RetryConfig, attempt: u32) {
+    tokio::time::sleep(cfg.backoff_delay(attempt)).await;
 }
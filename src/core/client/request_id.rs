@@ -0,0 +1,44 @@
+//! Correlation IDs attached to outgoing requests.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Header the correlation ID is sent in unless another is configured.
+pub const DEFAULT_REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Generates one correlation ID per logical request and names the header it is sent in.
+#[derive(Clone)]
+pub(crate) struct RequestIdProvider {
+    header: String,
+    make: Arc<dyn Fn() -> String + Send + Sync>,
+}
+
+impl RequestIdProvider {
+    pub(crate) fn new(header: String, make: Arc<dyn Fn() -> String + Send + Sync>) -> Self {
+        Self { header, make }
+    }
+
+    pub(crate) fn header(&self) -> &str {
+        &self.header
+    }
+
+    pub(crate) fn next_id(&self) -> String {
+        (self.make)()
+    }
+}
+
+/// The correlation ID a response was requested with, kept in its extensions so status
+/// errors can name it.
+#[derive(Clone, Debug)]
+pub(crate) struct SentRequestId {
+    pub(crate) header: String,
+    pub(crate) id: String,
+}
+
+impl fmt::Debug for RequestIdProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestIdProvider")
+            .field("header", &self.header)
+            .finish_non_exhaustive()
+    }
+}
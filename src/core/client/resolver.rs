@@ -0,0 +1,49 @@
+//! Mapping of per-symbol endpoints to URLs.
+
+use url::Url;
+
+use crate::core::YfError;
+
+/// An endpoint whose URL embeds the symbol in its path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Endpoint {
+    /// The v8 chart API (`/v8/finance/chart/{symbol}`).
+    Chart,
+    /// The v10 `quoteSummary` API (`/v10/finance/quoteSummary/{symbol}`).
+    QuoteSummary,
+    /// The v7 options API (`/v7/finance/options/{symbol}`).
+    Options,
+    /// The fundamentals timeseries API (`.../timeseries/{symbol}`).
+    Timeseries,
+    /// The HTML quote page scraped for profiles (`/quote/{symbol}`).
+    QuotePage,
+}
+
+/// Maps an [`Endpoint`] and symbol to the URL that is requested.
+///
+/// The default, [`DefaultPathResolver`], appends the symbol to the endpoint's base URL,
+/// which is what Yahoo expects. A custom resolver lets the client talk to a mirror that
+/// lays out its paths differently, e.g. an internal host in an air-gapped deployment.
+/// Query parameters are added by the crate after the URL is resolved.
+///
+/// Install one with [`YfClientBuilder::path_resolver`](crate::YfClientBuilder::path_resolver).
+pub trait PathResolver: Send + Sync + std::fmt::Debug {
+    /// Returns the URL for `symbol` on `endpoint`. `base` is the client's configured base URL
+    /// for that endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no valid URL can be built.
+    fn resolve(&self, endpoint: Endpoint, base: &Url, symbol: &str) -> Result<Url, YfError>;
+}
+
+/// The standard resolver: `base` joined with `symbol`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultPathResolver;
+
+impl PathResolver for DefaultPathResolver {
+    fn resolve(&self, _endpoint: Endpoint, base: &Url, symbol: &str) -> Result<Url, YfError> {
+        Ok(base.join(symbol)?)
+    }
+}
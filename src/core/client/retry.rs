@@ -1,18 +1,20 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 /// Specifies the backoff strategy for retrying failed requests.
 #[derive(Clone, Debug)]
 pub enum Backoff {
     /// Uses a fixed delay between retries.
-    Fixed(std::time::Duration),
+    Fixed(Duration),
     /// Uses an exponential delay between retries.
     /// The delay is calculated as `base * (factor ^ attempt)`.
     Exponential {
         /// The initial backoff duration.
-        base: std::time::Duration,
+        base: Duration,
         /// The multiplicative factor for each subsequent retry.
         factor: f64,
         /// The maximum duration to wait between retries.
-        max: std::time::Duration,
-        /// Whether to apply random jitter (+/- 50%) to the delay.
+        max: Duration,
+        /// Whether to apply random jitter (+/- 50%) to the delay. See [`RetryConfig::jitter_seed`].
         jitter: bool,
     },
 }
@@ -32,6 +34,9 @@ pub struct RetryConfig {
     pub retry_on_timeout: bool,
     /// Whether to retry on connection errors.
     pub retry_on_connect: bool,
+    /// Seeds the backoff jitter so the delay sequence is reproducible (useful in tests).
+    /// Leave `None` in production to draw fresh jitter for every retry.
+    pub jitter_seed: Option<u64>,
 }
 
 impl Default for RetryConfig {
@@ -40,18 +45,62 @@ impl Default for RetryConfig {
             enabled: true,
             max_retries: 4,
             backoff: Backoff::Exponential {
-                base: std::time::Duration::from_millis(200),
+                base: Duration::from_millis(200),
                 factor: 2.0,
-                max: std::time::Duration::from_secs(3),
+                max: Duration::from_secs(3),
                 jitter: true,
             },
             retry_on_status: vec![408, 429, 500, 502, 503, 504],
             retry_on_timeout: true,
             retry_on_connect: true,
+            jitter_seed: None,
         }
     }
 }
 
+impl RetryConfig {
+    /// Returns the delay before retry number `attempt` (starting at 0).
+    ///
+    /// With jitter enabled the delay is scaled by a factor in `[0.5, 1.5)`; set
+    /// [`jitter_seed`](Self::jitter_seed) to make that factor deterministic.
+    #[must_use]
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        match self.backoff {
+            Backoff::Fixed(d) => d,
+            Backoff::Exponential {
+                base,
+                factor,
+                max,
+                jitter,
+            } => {
+                let pow = factor.powi(i32::try_from(attempt).unwrap_or(i32::MAX));
+                let d = Duration::from_secs_f64(base.as_secs_f64() * pow).min(max);
+                if !jitter {
+                    return d;
+                }
+                let seed = self.jitter_seed.unwrap_or_else(|| {
+                    SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map_or(0, |t| u64::from(t.subsec_nanos()))
+                });
+                d.mul_f64(0.5 + unit_interval(seed, attempt))
+            }
+        }
+    }
+}
+
+/// Maps `(seed, attempt)` to a uniformly distributed value in `[0, 1)` (`SplitMix64`).
+#[allow(clippy::cast_precision_loss)]
+fn unit_interval(seed: u64, attempt: u32) -> f64 {
+    let mut z = seed
+        .wrapping_add(u64::from(attempt).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        .wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
 /// Defines the behavior of the in-memory cache for an API call.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CacheMode {
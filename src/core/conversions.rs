@@ -56,6 +56,21 @@ pub fn f64_to_money_with_currency_str(value: f64, currency_str: Option<&str>) ->
     f64_to_money_with_currency(value, currency)
 }
 
+/// Minor-unit currency codes Yahoo reports for some listings, with the major currency each
+/// is one hundredth of.
+const MINOR_UNIT_CURRENCIES: [(&str, &str); 2] = [("GBp", "GBP"), ("GBX", "GBP")];
+
+/// Returns the major currency for a minor-unit code such as `GBp` (pence), or `None`.
+///
+/// The match is case-sensitive: `GBP` is pounds, `GBp` is pence.
+#[must_use]
+pub fn minor_unit_major(currency_str: &str) -> Option<&'static str> {
+    MINOR_UNIT_CURRENCIES
+        .iter()
+        .find(|(minor, _)| *minor == currency_str)
+        .map(|(_, major)| *major)
+}
+
 /// Convert Money to f64 (loses currency information)
 #[must_use]
 pub fn money_to_f64(money: &Money) -> f64 {
@@ -1,11 +1,13 @@
 use thiserror::Error;
 
+use crate::core::client::SentRequestId;
+
 /// The primary error type for the `yfinance-rs` crate.
 #[derive(Debug, Error)]
 pub enum YfError {
     /// An error originating from the underlying HTTP client (`reqwest`).
     #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(#[source] reqwest::Error),
 
     /// An error related to WebSocket communication.
     #[error("WebSocket error: {0}")]
@@ -23,6 +25,19 @@ pub enum YfError {
     #[error("Base64 decoding error: {0}")]
     Base64(#[from] base64::DecodeError),
 
+    /// A response body that did not match the expected JSON schema, with the endpoint and
+    /// symbol(s) it was fetched for.
+    #[error("failed to parse {endpoint} response for {symbol}: {source}")]
+    Parse {
+        /// The endpoint whose response failed to parse, e.g. `quote_v7`.
+        endpoint: String,
+        /// The requested symbol, or a comma-separated list for batch requests.
+        symbol: String,
+        /// The underlying deserialization error.
+        #[source]
+        source: serde_json::Error,
+    },
+
     /// An error that occurs when parsing a URL.
     #[error("Invalid URL: {0}")]
     Url(#[from] url::ParseError),
@@ -62,8 +77,22 @@ pub enum YfError {
     /// An error returned by the Yahoo Finance API within an otherwise successful response.
     ///
     /// For example, a `200 OK` response might contain a JSON body with an `error` field.
-    #[error("Yahoo API error: {0}")]
-    Api(String),
+    #[error("Yahoo API error: {message}")]
+    Api {
+        /// The failure kind, parsed from the `code` and `description` of Yahoo's error object.
+        code: YahooErrorCode,
+        /// A human-readable description of the error.
+        message: String,
+    },
+
+    /// Yahoo answered with a different symbol than the one requested, e.g. after a rename.
+    #[error("requested {requested} but Yahoo returned {returned}")]
+    SymbolMismatch {
+        /// The symbol that was requested.
+        requested: String,
+        /// The symbol in Yahoo's response.
+        returned: String,
+    },
 
     /// An error related to authentication, such as failing to retrieve a cookie or crumb.
     #[error("Authentication error: {0}")]
@@ -84,6 +113,182 @@ pub enum YfError {
     /// An error indicating that the provided date range is invalid (e.g., start date after end date).
     #[error("Invalid date range: start date must be before end date")]
     InvalidDates,
+
+    /// An identical request made concurrently by another call failed with this error, and
+    /// was not repeated. Only used for errors that cannot be copied as-is, such as transport
+    /// failures; the original error is the message.
+    #[error("Shared request failed: {0}")]
+    Shared(String),
+}
+
+/// A failure kind reported in the `error` object of a Yahoo response.
+///
+/// Yahoo pairs a broad `code` (e.g. `"Unauthorized"`) with a free-text `description`;
+/// both are considered, so an `"Invalid Crumb"` description maps to [`InvalidCrumb`]
+/// whatever its code.
+///
+/// [`InvalidCrumb`]: YahooErrorCode::InvalidCrumb
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum YahooErrorCode {
+    /// `"Not Found"`: the symbol or resource does not exist, or has no data.
+    NotFound,
+    /// `"Unauthorized"` for a reason other than the crumb.
+    Unauthorized,
+    /// The crumb sent with the request was rejected.
+    InvalidCrumb,
+    /// `"Bad Request"`: a parameter was missing or malformed.
+    BadRequest,
+    /// `"Internal Server Error"` or `"internal-error"`.
+    InternalError,
+    /// Any other code, as reported by Yahoo.
+    Other(String),
+}
+
+impl YahooErrorCode {
+    /// Maps Yahoo's `code` and `description` strings to a failure kind.
+    #[must_use]
+    pub fn from_yahoo(code: &str, description: &str) -> Self {
+        if description.to_ascii_lowercase().contains("invalid crumb") {
+            return Self::InvalidCrumb;
+        }
+        match code.trim().to_ascii_lowercase().replace('-', " ").as_str() {
+            "not found" => Self::NotFound,
+            "unauthorized" => Self::Unauthorized,
+            "invalid crumb" => Self::InvalidCrumb,
+            "bad request" => Self::BadRequest,
+            "internal error" | "internal server error" => Self::InternalError,
+            _ => Self::Other(code.to_string()),
+        }
+    }
+}
+
+impl YfError {
+    /// Maps a non-success HTTP status to the matching variant: `NotFound` (404),
+    /// `RateLimited` (429), `ServerError` (5xx) or `Status` (anything else).
+    ///
+    /// The stored URL has its `crumb` query value replaced with `***`, so errors can be
+    /// logged without leaking the session secret.
+    #[must_use]
+    pub fn from_status(status: u16, url: &str) -> Self {
+        Self::from_status_at(status, redact_url(url))
+    }
+
+    /// Like [`from_status`](Self::from_status) for a response from `send_with_retry`; the
+    /// correlation ID it was sent with, if any, is appended to the URL.
+    pub(crate) fn from_response(resp: &reqwest::Response, url: &str) -> Self {
+        Self::from_status_at(resp.status().as_u16(), response_url(resp, url))
+    }
+
+    fn from_status_at(status: u16, url: String) -> Self {
+        match status {
+            404 => Self::NotFound { url },
+            429 => Self::RateLimited { url },
+            500..=599 => Self::ServerError { status, url },
+            _ => Self::Status { status, url },
+        }
+    }
+
+    /// Builds an [`Api`](Self::Api) error from the `code` and `description` of a Yahoo
+    /// error object, prefixing the message with `context`.
+    pub(crate) fn api(context: &str, code: &str, description: &str) -> Self {
+        Self::Api {
+            code: YahooErrorCode::from_yahoo(code, description),
+            message: format!("{context}: {description}"),
+        }
+    }
+
+    /// Copies this error for callers that waited on the request that produced it.
+    ///
+    /// Variants made of plain data are reproduced exactly; the rest become
+    /// [`Shared`](Self::Shared) with the original message.
+    pub(crate) fn for_follower(&self) -> Self {
+        match self {
+            Self::NotFound { url } => Self::NotFound { url: url.clone() },
+            Self::RateLimited { url } => Self::RateLimited { url: url.clone() },
+            Self::ServerError { status, url } => Self::ServerError {
+                status: *status,
+                url: url.clone(),
+            },
+            Self::Status { status, url } => Self::Status {
+                status: *status,
+                url: url.clone(),
+            },
+            Self::Api { code, message } => Self::Api {
+                code: code.clone(),
+                message: message.clone(),
+            },
+            Self::SymbolMismatch {
+                requested,
+                returned,
+            } => Self::SymbolMismatch {
+                requested: requested.clone(),
+                returned: returned.clone(),
+            },
+            Self::Auth(m) => Self::Auth(m.clone()),
+            Self::Scrape(m) => Self::Scrape(m.clone()),
+            Self::MissingData(m) => Self::MissingData(m.clone()),
+            Self::InvalidParams(m) => Self::InvalidParams(m.clone()),
+            Self::InvalidDates => Self::InvalidDates,
+            Self::Shared(m) => Self::Shared(m.clone()),
+            other => Self::Shared(other.to_string()),
+        }
+    }
+
+    /// Wraps a deserialization failure with the endpoint and symbol(s) it happened for.
+    pub(crate) fn parse(endpoint: &str, symbols: &[&str], source: serde_json::Error) -> Self {
+        Self::Parse {
+            endpoint: endpoint.to_string(),
+            symbol: symbols.join(","),
+            source,
+        }
+    }
+}
+
+/// Returns `url` with the value of any `crumb` query parameter replaced by `***`.
+pub(crate) fn redact_url(url: &str) -> String {
+    match url::Url::parse(url) {
+        Ok(mut parsed) => {
+            redact_crumb(&mut parsed);
+            parsed.into()
+        }
+        Err(_) => url.to_string(),
+    }
+}
+
+/// Redacts `url` and appends the correlation ID `resp` was requested with, if any.
+pub(crate) fn response_url(resp: &reqwest::Response, url: &str) -> String {
+    let url = redact_url(url);
+    match resp.extensions().get::<SentRequestId>() {
+        Some(sent) => format!("{url} ({}: {})", sent.header, sent.id),
+        None => url,
+    }
+}
+
+fn redact_crumb(url: &mut url::Url) {
+    if !url.query_pairs().any(|(k, _)| k == "crumb") {
+        return;
+    }
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(k, v)| {
+            let v = if k == "crumb" {
+                "***".into()
+            } else {
+                v.into_owned()
+            };
+            (k.into_owned(), v)
+        })
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+}
+
+impl From<reqwest::Error> for YfError {
+    fn from(mut e: reqwest::Error) -> Self {
+        if let Some(url) = e.url_mut() {
+            redact_crumb(url);
+        }
+        Self::Http(e)
+    }
 }
 
 impl From<tokio_tungstenite::tungstenite::Error> for YfError {
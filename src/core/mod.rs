@@ -17,6 +17,8 @@ pub(crate) mod quotes;
 pub(crate) mod quotesummary;
 /// Service traits for abstracting functionality like history fetching.
 pub mod services;
+/// Compact multi-symbol price series from Yahoo's spark endpoint.
+pub mod spark;
 pub(crate) mod wire;
 
 #[cfg(feature = "test-mode")]
@@ -30,7 +32,14 @@ pub mod conversions;
 pub(crate) mod net;
 
 // convenient re-exports so most code can just `use crate::core::YfClient`
-pub use client::{CacheMode, RetryConfig, YfClient, YfClientBuilder};
-pub use error::YfError;
+#[cfg(feature = "fault-injection")]
+pub use client::FaultConfig;
+pub use client::{
+    CacheMode, ClientMetrics, Clock, DefaultPathResolver, Endpoint, PathResolver, RetryConfig,
+    SystemClock, YahooHost, YfClient, YfClientBuilder,
+};
+pub use error::{YahooErrorCode, YfError};
 pub use models::{Action, Candle, HistoryMeta, HistoryResponse, Interval, Quote, Range};
+pub use quotesummary::QuoteSummaryModule;
 pub use services::{HistoryRequest, HistoryService};
+pub use wire::{YahooDate, YahooNumber};
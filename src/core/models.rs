@@ -37,3 +37,51 @@ pub(crate) const fn interval_as_str(interval: Interval) -> &'static str {
         Interval::M3 => "3mo",
     }
 }
+
+const ALL_RANGES: [Range; 11] = [
+    Range::D1,
+    Range::D5,
+    Range::M1,
+    Range::M3,
+    Range::M6,
+    Range::Y1,
+    Range::Y2,
+    Range::Y5,
+    Range::Y10,
+    Range::Ytd,
+    Range::Max,
+];
+
+const ALL_INTERVALS: [Interval; 12] = [
+    Interval::I1m,
+    Interval::I2m,
+    Interval::I5m,
+    Interval::I15m,
+    Interval::I30m,
+    Interval::I90m,
+    Interval::I1h,
+    Interval::D1,
+    Interval::D5,
+    Interval::W1,
+    Interval::M1,
+    Interval::M3,
+];
+
+/// Parses a Yahoo/yfinance range string such as `"1mo"` or `"ytd"`.
+pub(crate) fn parse_range(s: &str) -> Option<Range> {
+    let s = s.trim();
+    ALL_RANGES
+        .into_iter()
+        .find(|r| range_as_str(*r).eq_ignore_ascii_case(s))
+}
+
+/// Parses a Yahoo/yfinance interval string such as `"1d"` or `"1wk"` (`"60m"` is an alias of `"1h"`).
+pub(crate) fn parse_interval(s: &str) -> Option<Interval> {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case("60m") {
+        return Some(Interval::I1h);
+    }
+    ALL_INTERVALS
+        .into_iter()
+        .find(|i| interval_as_str(*i).eq_ignore_ascii_case(s))
+}
@@ -23,3 +23,20 @@ pub async fn get_text(
 
     Ok(text)
 }
+
+/// Appends user-supplied query parameters to `url`.
+///
+/// Keys the crate already set on the URL are skipped so extra params can never
+/// override `symbols`, `crumb` and the like.
+pub fn append_extra_params(url: &mut url::Url, extra: &[(String, String)]) {
+    if extra.is_empty() {
+        return;
+    }
+    let existing: Vec<String> = url.query_pairs().map(|(k, _)| k.into_owned()).collect();
+    let mut qp = url.query_pairs_mut();
+    for (k, v) in extra {
+        if !existing.iter().any(|e| e == k) {
+            qp.append_pair(k, v);
+        }
+    }
+}
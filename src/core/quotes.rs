@@ -1,13 +1,17 @@
 // src/core/quotes.rs
+use std::collections::HashMap;
+
 use serde::Deserialize;
 use serde_json::Value;
-use url::Url;
 
 use crate::{
     YfClient, YfError,
     core::{
-        client::{CacheMode, RetryConfig},
-        conversions::f64_to_money_with_currency_str,
+        client::{
+            CacheMode, RetryConfig,
+            in_flight::{self, Flight},
+        },
+        conversions::{f64_to_money_with_currency_str, minor_unit_major},
         net,
     },
 };
@@ -27,16 +31,46 @@ pub struct V7QuoteResponse {
     pub(crate) error: Option<serde_json::Value>,
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Clone, Default)]
 pub struct V7QuoteNode {
     #[serde(default)]
     pub(crate) symbol: Option<String>,
     #[serde(rename = "shortName")]
     pub(crate) short_name: Option<String>,
+    #[serde(rename = "longName")]
+    pub(crate) long_name: Option<String>,
+    #[serde(rename = "displayName")]
+    pub(crate) display_name: Option<String>,
     #[serde(rename = "regularMarketPrice")]
     pub(crate) regular_market_price: Option<f64>,
     #[serde(rename = "regularMarketPreviousClose")]
     pub(crate) regular_market_previous_close: Option<f64>,
+    #[serde(rename = "regularMarketTime")]
+    pub(crate) regular_market_time: Option<i64>,
+    #[serde(rename = "postMarketPreviousClose")]
+    pub(crate) post_market_previous_close: Option<f64>,
+    #[serde(rename = "preMarketPreviousClose")]
+    pub(crate) pre_market_previous_close: Option<f64>,
+    #[serde(rename = "postMarketPrice")]
+    pub(crate) post_market_price: Option<f64>,
+    #[serde(rename = "preMarketPrice")]
+    pub(crate) pre_market_price: Option<f64>,
+    #[serde(rename = "regularMarketChange")]
+    pub(crate) regular_market_change: Option<f64>,
+    #[serde(rename = "regularMarketChangePercent")]
+    pub(crate) regular_market_change_percent: Option<f64>,
+    #[serde(rename = "preMarketChange")]
+    pub(crate) pre_market_change: Option<f64>,
+    #[serde(rename = "preMarketChangePercent")]
+    pub(crate) pre_market_change_percent: Option<f64>,
+    #[serde(rename = "preMarketTime")]
+    pub(crate) pre_market_time: Option<i64>,
+    #[serde(rename = "postMarketChange")]
+    pub(crate) post_market_change: Option<f64>,
+    #[serde(rename = "postMarketChangePercent")]
+    pub(crate) post_market_change_percent: Option<f64>,
+    #[serde(rename = "postMarketTime")]
+    pub(crate) post_market_time: Option<i64>,
     pub(crate) currency: Option<String>,
     #[serde(rename = "fullExchangeName")]
     pub(crate) full_exchange_name: Option<String>,
@@ -46,120 +80,204 @@ pub struct V7QuoteNode {
     pub(crate) market_cap_figure_exchange: Option<String>,
     #[serde(rename = "marketState")]
     pub(crate) market_state: Option<String>,
+    #[serde(rename = "quoteType")]
+    pub(crate) quote_type: Option<String>,
+    #[serde(rename = "typeDisp")]
+    pub(crate) type_disp: Option<String>,
+
+    /* futures */
+    // `contractSymbol` is a boolean flag on some payloads; only string values are kept.
+    #[serde(rename = "contractSymbol")]
+    pub(crate) contract_symbol: Option<Value>,
+    #[serde(rename = "underlyingSymbol")]
+    pub(crate) underlying_symbol: Option<String>,
+    #[serde(rename = "expireDate")]
+    pub(crate) expire_date: Option<i64>,
+    #[serde(rename = "openInterest")]
+    pub(crate) open_interest: Option<f64>,
+}
+
+/// A v7 quote node together with every field `V7QuoteNode` does not model.
+#[derive(Deserialize)]
+pub struct V7QuoteNodeWithExtras {
+    #[serde(flatten)]
+    pub(crate) node: V7QuoteNode,
+    #[serde(flatten)]
+    pub(crate) extras: HashMap<String, Value>,
 }
 
+/// Options shared by every v7 quote request.
+#[derive(Clone, Copy)]
+pub struct V7Request<'a> {
+    /// Restricts the response to these fields; `None` or empty returns Yahoo's default set.
+    pub(crate) fields: Option<&'a [&'a str]>,
+    /// Overrides the client's default region when set.
+    pub(crate) region: Option<&'a str>,
+    /// Extra query params, appended without overriding the crate's own.
+    pub(crate) extra: &'a [(String, String)],
+    /// Sends the symbols in a form body instead of the query string.
+    pub(crate) use_post: bool,
+    pub(crate) cache_mode: CacheMode,
+    pub(crate) retry_override: Option<&'a RetryConfig>,
+}
+
+impl<'a> V7Request<'a> {
+    /// A plain GET for Yahoo's default fields and the client's region.
+    pub(crate) const fn new(
+        cache_mode: CacheMode,
+        retry_override: Option<&'a RetryConfig>,
+    ) -> Self {
+        Self {
+            fields: None,
+            region: None,
+            extra: &[],
+            use_post: false,
+            cache_mode,
+            retry_override,
+        }
+    }
+
+    /// Restricts the response to `fields`.
+    pub(crate) const fn fields(mut self, fields: Option<&'a [&'a str]>) -> Self {
+        self.fields = fields;
+        self
+    }
+}
+
+/// Fetches a v7 quote body, sharing one request between concurrent identical calls.
+///
+/// The first caller performs the request; callers arriving while it runs wait for its body,
+/// or get a copy of its error rather than repeating a request that just failed. Only calls
+/// with the same cache mode, retry policy and transport are shared, so a `Refresh` caller
+/// never receives a body fetched under `Use`.
+///
+/// With `use_post` the symbols are sent as a form body instead of in the query string, so
+/// large batches stay clear of URL-length limits. Responses are cached under the same key
+/// either way.
 async fn fetch_v7_quote_body(
     client: &YfClient,
     symbols: &[&str],
-    fields: Option<&[&str]>,
-    cache_mode: CacheMode,
-    retry_override: Option<&RetryConfig>,
+    req: &V7Request<'_>,
+) -> Result<String, YfError> {
+    let key = format!(
+        "v7|{symbols:?}|{:?}|{:?}|{:?}|{}|{:?}|{:?}",
+        req.fields, req.region, req.extra, req.use_post, req.cache_mode, req.retry_override
+    );
+    let guard = match client.in_flight().join(&key) {
+        Flight::Leader(guard) => Some(guard),
+        Flight::Follower(rx) => {
+            if let Some(res) = in_flight::follow(rx).await {
+                return res;
+            }
+            None
+        }
+    };
+
+    let res = fetch_v7_quote_body_uncoalesced(client, symbols, req).await;
+    if let Some(guard) = guard {
+        guard.complete(&res);
+    }
+    res
+}
+
+async fn fetch_v7_quote_body_uncoalesced(
+    client: &YfClient,
+    symbols: &[&str],
+    req: &V7Request<'_>,
 ) -> Result<String, YfError> {
     // Inner function to attempt the fetch, allowing for an auth retry.
     async fn attempt_fetch(
         client: &YfClient,
         symbols: &[&str],
-        fields: Option<&[&str]>,
+        req: &V7Request<'_>,
         crumb: Option<&str>,
-        cache_mode: CacheMode,
-        retry_override: Option<&RetryConfig>,
-    ) -> Result<(String, Url, Option<u16>), YfError> {
+    ) -> Result<(String, Option<YfError>), YfError> {
         let mut url = client.base_quote_v7().clone();
         {
             let mut qp = url.query_pairs_mut();
             qp.append_pair("symbols", &symbols.join(","));
-            if let Some(list) = fields {
-                if !list.is_empty() {
-                    qp.append_pair("fields", &list.join(","));
-                }
+            if let Some(list) = req.fields
+                && !list.is_empty()
+            {
+                qp.append_pair("fields", &list.join(","));
+            }
+            if let Some(r) = req.region.or_else(|| client.default_region()) {
+                qp.append_pair("region", r);
             }
             if let Some(c) = crumb {
                 qp.append_pair("crumb", c);
             }
         }
+        crate::core::net::append_extra_params(&mut url, req.extra);
 
-        if cache_mode == CacheMode::Use
+        if req.cache_mode == CacheMode::Use
             && let Some(body) = client.cache_get(&url).await
         {
-            return Ok((body, url, None));
+            return Ok((body, None));
         }
 
+        // The symbols stay in `url` as the cache key; a POST carries them in the body instead.
+        let (http_req, request_url) = if req.use_post {
+            let mut post_url = url.clone();
+            post_url
+                .query_pairs_mut()
+                .clear()
+                .extend_pairs(url.query_pairs().filter(|(k, _)| k != "symbols"));
+            let form = url::form_urlencoded::Serializer::new(String::new())
+                .append_pair("symbols", &symbols.join(","))
+                .finish();
+            let http_req = client
+                .http()
+                .post(post_url.clone())
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(form);
+            (http_req, post_url)
+        } else {
+            (client.http().get(url.clone()), url.clone())
+        };
         let resp = client
             .send_with_retry(
-                client
-                    .http()
-                    .get(url.clone())
-                    .header("accept", "application/json"),
-                retry_override,
+                http_req.header("accept", "application/json"),
+                req.retry_override,
             )
             .await?;
 
-        let status = resp.status();
+        let failure = (!resp.status().is_success())
+            .then(|| YfError::from_response(&resp, request_url.as_str()));
         let body = net::get_text(resp, "quote_v7", &symbols.join("-"), "json").await?;
 
-        if status.is_success() {
-            if cache_mode != CacheMode::Bypass {
-                client.cache_put(&url, &body, None).await;
-            }
-            Ok((body, url, None))
-        } else {
-            Ok((body, url, Some(status.as_u16())))
+        if failure.is_none() && req.cache_mode != CacheMode::Bypass {
+            client.cache_put(&url, &body, None).await;
         }
+        Ok((body, failure))
     }
 
     // First attempt, without a crumb.
-    let (body, url, maybe_status) =
-        attempt_fetch(client, symbols, fields, None, cache_mode, retry_override).await?;
+    let (body, failure) = attempt_fetch(client, symbols, req, None).await?;
 
-    let body_to_parse = if let Some(status_code) = maybe_status {
+    let body_to_parse = if let Some(err) = failure {
         // If unauthorized, get a crumb and retry.
-        if status_code == 401 || status_code == 403 {
+        if matches!(
+            err,
+            YfError::Status {
+                status: 401 | 403,
+                ..
+            }
+        ) {
             client.ensure_credentials().await?;
             let crumb = client.crumb().await.ok_or_else(|| {
                 YfError::Auth("Crumb is not set after ensuring credentials".into())
             })?;
 
             // Second attempt, with a crumb.
-            let (body, url, maybe_status) = attempt_fetch(
-                client,
-                symbols,
-                fields,
-                Some(&crumb),
-                cache_mode,
-                retry_override,
-            )
-            .await?;
+            let (body, failure) = attempt_fetch(client, symbols, req, Some(&crumb)).await?;
 
-            if let Some(status_code) = maybe_status {
-                let url_s = url.to_string();
-                return Err(match status_code {
-                    404 => YfError::NotFound { url: url_s },
-                    429 => YfError::RateLimited { url: url_s },
-                    500..=599 => YfError::ServerError {
-                        status: status_code,
-                        url: url_s,
-                    },
-                    _ => YfError::Status {
-                        status: status_code,
-                        url: url_s,
-                    },
-                });
+            if let Some(err) = failure {
+                return Err(err);
             }
             body
         } else {
-            let url_s = url.to_string();
-            return Err(match status_code {
-                404 => YfError::NotFound { url: url_s },
-                429 => YfError::RateLimited { url: url_s },
-                500..=599 => YfError::ServerError {
-                    status: status_code,
-                    url: url_s,
-                },
-                _ => YfError::Status {
-                    status: status_code,
-                    url: url_s,
-                },
-            });
+            return Err(err);
         }
     } else {
         body
@@ -173,40 +291,175 @@ async fn fetch_v7_quote_body(
 pub async fn fetch_v7_quotes(
     client: &YfClient,
     symbols: &[&str],
-    fields: Option<&[&str]>,
-    cache_mode: CacheMode,
-    retry_override: Option<&RetryConfig>,
+    req: &V7Request<'_>,
 ) -> Result<Vec<V7QuoteNode>, YfError> {
-    let body = fetch_v7_quote_body(client, symbols, fields, cache_mode, retry_override).await?;
-    let env: V7Envelope = serde_json::from_str(&body)?;
+    let yahoo_symbols: Vec<&str> = symbols.iter().map(|s| client.yahoo_symbol(s)).collect();
+    let body = fetch_v7_quote_body(client, &yahoo_symbols, req).await?;
+    let env: V7Envelope =
+        serde_json::from_str(&body).map_err(|e| YfError::parse("quote_v7", symbols, e))?;
 
-    Ok(env
+    let mut nodes = env
         .quote_response
         .and_then(|qr| qr.result)
-        .unwrap_or_default())
+        .unwrap_or_default();
+    for node in &mut nodes {
+        if client.normalize_minor_units() {
+            node.normalize_minor_units();
+        }
+        if let Some(symbol) = &mut node.symbol {
+            client.unalias_symbol(symbol);
+        }
+    }
+    Ok(nodes)
 }
 
 /// Fetches raw quote nodes from the v7 API without mapping to strongly typed models.
 pub async fn fetch_v7_quotes_raw(
     client: &YfClient,
     symbols: &[&str],
-    fields: Option<&[&str]>,
-    cache_mode: CacheMode,
-    retry_override: Option<&RetryConfig>,
+    req: &V7Request<'_>,
 ) -> Result<Vec<Value>, YfError> {
-    let body = fetch_v7_quote_body(client, symbols, fields, cache_mode, retry_override).await?;
-    let value: Value = serde_json::from_str(&body)?;
+    let yahoo_symbols: Vec<&str> = symbols.iter().map(|s| client.yahoo_symbol(s)).collect();
+    let body = fetch_v7_quote_body(client, &yahoo_symbols, req).await?;
+    let value: Value =
+        serde_json::from_str(&body).map_err(|e| YfError::parse("quote_v7", symbols, e))?;
 
-    let nodes = value
+    let mut nodes = value
         .get("quoteResponse")
         .and_then(|qr| qr.get("result"))
         .and_then(|res| res.as_array())
-        .map(|arr| arr.clone())
+        .cloned()
         .unwrap_or_default();
+    for node in &mut nodes {
+        if let Some(Value::String(symbol)) = node.get_mut("symbol") {
+            client.unalias_symbol(symbol);
+        }
+    }
 
     Ok(nodes)
 }
 
+impl V7QuoteNode {
+    /// The close that the current session's change is measured against.
+    ///
+    /// During extended hours (`PRE`/`POST`) that is the last regular-session close, reported
+    /// as `regularMarketPrice`, rather than `regularMarketPreviousClose` (the session before).
+    ///
+    /// Returns the value together with the name of the v7 field it was read from.
+    pub(crate) fn session_previous_close(&self) -> Option<(f64, &'static str)> {
+        let regular = self
+            .regular_market_previous_close
+            .map(|v| (v, "regularMarketPreviousClose"));
+        let extended = match self.market_state.as_deref() {
+            Some("POST" | "POSTPOST") => self
+                .post_market_previous_close
+                .map(|v| (v, "postMarketPreviousClose")),
+            Some("PRE" | "PREPRE") => self
+                .pre_market_previous_close
+                .map(|v| (v, "preMarketPreviousClose")),
+            _ => return regular,
+        };
+        extended
+            .or_else(|| self.regular_market_price.map(|v| (v, "regularMarketPrice")))
+            .or(regular)
+    }
+
+    /// The v7 field the quote's exchange is read from, in the order `Quote::from` tries them.
+    pub(crate) fn exchange_source(&self) -> Option<&'static str> {
+        [
+            (&self.full_exchange_name, "fullExchangeName"),
+            (&self.exchange, "exchange"),
+            (&self.market, "market"),
+            (&self.market_cap_figure_exchange, "marketCapFigureExchange"),
+        ]
+        .into_iter()
+        .find_map(|(v, name)| v.is_some().then_some(name))
+    }
+
+    /// Restates prices quoted in a minor unit (e.g. `GBp` pence) in the major currency.
+    pub(crate) fn normalize_minor_units(&mut self) {
+        let Some(major) = self.currency.as_deref().and_then(minor_unit_major) else {
+            return;
+        };
+        for price in [
+            &mut self.regular_market_price,
+            &mut self.regular_market_previous_close,
+            &mut self.post_market_previous_close,
+            &mut self.pre_market_previous_close,
+            &mut self.post_market_price,
+            &mut self.pre_market_price,
+            &mut self.regular_market_change,
+            &mut self.pre_market_change,
+            &mut self.post_market_change,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *price /= 100.0;
+        }
+        self.currency = Some(major.to_string());
+    }
+
+    /// Rejects prices that are obviously wrong: a negative regular, previous-close or
+    /// pre/post-market price, or a pre/post-market price more than 50% away from the regular
+    /// price. Returns `YfError::MissingData` naming the offending field.
+    pub(crate) fn check_prices(&self) -> Result<(), YfError> {
+        let symbol = self.symbol.as_deref().unwrap_or_default();
+        let reject = |what: &str, v: f64| {
+            YfError::MissingData(format!("implausible {what} {v} for {symbol}"))
+        };
+
+        for (what, value) in [
+            ("regularMarketPrice", self.regular_market_price),
+            (
+                "regularMarketPreviousClose",
+                self.regular_market_previous_close,
+            ),
+            ("preMarketPrice", self.pre_market_price),
+            ("postMarketPrice", self.post_market_price),
+        ] {
+            if let Some(v) = value.filter(|v| *v < 0.0) {
+                return Err(reject(what, v));
+            }
+        }
+
+        let Some(regular) = self.regular_market_price.filter(|p| *p > 0.0) else {
+            return Ok(());
+        };
+        for (what, value) in [
+            ("preMarketPrice", self.pre_market_price),
+            ("postMarketPrice", self.post_market_price),
+        ] {
+            if let Some(v) = value.filter(|v| ((v - regular) / regular).abs() > 0.5) {
+                return Err(reject(what, v));
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces `regularMarketPrice` with the pre/post-market price when `pref` asks for it and
+    /// the market is in that session.
+    pub(crate) fn apply_price_preference(&mut self, pref: crate::quote::PricePreference) {
+        if pref == crate::quote::PricePreference::Regular {
+            return;
+        }
+        let extended = match self.market_state.as_deref() {
+            Some("POST" | "POSTPOST") => self.post_market_price,
+            Some("PRE" | "PREPRE") => self.pre_market_price,
+            _ => None,
+        };
+        if extended.is_some() {
+            self.regular_market_price = extended;
+        }
+    }
+}
+
+impl From<&V7QuoteNode> for Quote {
+    fn from(n: &V7QuoteNode) -> Self {
+        n.clone().into()
+    }
+}
+
 impl From<V7QuoteNode> for Quote {
     fn from(n: V7QuoteNode) -> Self {
         Self {
@@ -1,6 +1,6 @@
 use crate::core::{
-    YfClient, YfError,
-    client::{CacheMode, RetryConfig},
+    YahooErrorCode, YfClient, YfError,
+    client::{CacheMode, Endpoint, RetryConfig},
     net,
 };
 use serde::Deserialize;
@@ -8,6 +8,140 @@ use serde::Deserialize;
 #[cfg(feature = "debug-dumps")]
 use crate::profile::debug::debug_dump_api;
 
+/// A `quoteSummary` module that this crate knows how to request and parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QuoteSummaryModule {
+    /// Company profile (`assetProfile`).
+    AssetProfile,
+    /// Instrument type and naming (`quoteType`).
+    QuoteType,
+    /// Fund profile (`fundProfile`).
+    FundProfile,
+    /// ESG scores and involvement flags (`esgScores`).
+    EsgScores,
+    /// Analyst recommendation trend (`recommendationTrend`).
+    RecommendationTrend,
+    /// Financial data, including analyst price targets (`financialData`).
+    FinancialData,
+    /// Analyst upgrade/downgrade history (`upgradeDowngradeHistory`).
+    UpgradeDowngradeHistory,
+    /// Earnings and revenue estimates (`earningsTrend`).
+    EarningsTrend,
+    /// Historical earnings (`earnings`).
+    Earnings,
+    /// Reported versus estimated EPS for recent quarters (`earningsHistory`).
+    EarningsHistory,
+    /// Upcoming earnings and dividend dates (`calendarEvents`).
+    CalendarEvents,
+    /// Annual income statements (`incomeStatementHistory`).
+    IncomeStatementHistory,
+    /// Quarterly income statements (`incomeStatementHistoryQuarterly`).
+    IncomeStatementHistoryQuarterly,
+    /// Annual balance sheets (`balanceSheetHistory`).
+    BalanceSheetHistory,
+    /// Quarterly balance sheets (`balanceSheetHistoryQuarterly`).
+    BalanceSheetHistoryQuarterly,
+    /// Annual cash flow statements (`cashflowStatementHistory`).
+    CashflowStatementHistory,
+    /// Quarterly cash flow statements (`cashflowStatementHistoryQuarterly`).
+    CashflowStatementHistoryQuarterly,
+    /// Institutional holders (`institutionOwnership`).
+    InstitutionOwnership,
+    /// Mutual fund holders (`fundOwnership`).
+    FundOwnership,
+    /// Major holders breakdown (`majorHoldersBreakdown`).
+    MajorHoldersBreakdown,
+    /// Insider transactions (`insiderTransactions`).
+    InsiderTransactions,
+    /// Insider roster (`insiderHolders`).
+    InsiderHolders,
+    /// Net share purchase activity (`netSharePurchaseActivity`).
+    NetSharePurchaseActivity,
+    /// Trading and valuation summary (`summaryDetail`).
+    SummaryDetail,
+    /// Share, short-interest and valuation statistics (`defaultKeyStatistics`).
+    DefaultKeyStatistics,
+    /// Index constituents (`components`).
+    Components,
+}
+
+impl QuoteSummaryModule {
+    const ALL: &'static [Self] = &[
+        Self::AssetProfile,
+        Self::QuoteType,
+        Self::FundProfile,
+        Self::EsgScores,
+        Self::RecommendationTrend,
+        Self::FinancialData,
+        Self::UpgradeDowngradeHistory,
+        Self::EarningsTrend,
+        Self::Earnings,
+        Self::EarningsHistory,
+        Self::CalendarEvents,
+        Self::IncomeStatementHistory,
+        Self::IncomeStatementHistoryQuarterly,
+        Self::BalanceSheetHistory,
+        Self::BalanceSheetHistoryQuarterly,
+        Self::CashflowStatementHistory,
+        Self::CashflowStatementHistoryQuarterly,
+        Self::InstitutionOwnership,
+        Self::FundOwnership,
+        Self::MajorHoldersBreakdown,
+        Self::InsiderTransactions,
+        Self::InsiderHolders,
+        Self::NetSharePurchaseActivity,
+        Self::SummaryDetail,
+        Self::DefaultKeyStatistics,
+        Self::Components,
+    ];
+
+    /// Returns every module variant the crate can parse.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        Self::ALL
+    }
+
+    /// Returns the module name as sent in the `modules` query parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::AssetProfile => "assetProfile",
+            Self::QuoteType => "quoteType",
+            Self::FundProfile => "fundProfile",
+            Self::EsgScores => "esgScores",
+            Self::RecommendationTrend => "recommendationTrend",
+            Self::FinancialData => "financialData",
+            Self::UpgradeDowngradeHistory => "upgradeDowngradeHistory",
+            Self::EarningsTrend => "earningsTrend",
+            Self::Earnings => "earnings",
+            Self::EarningsHistory => "earningsHistory",
+            Self::CalendarEvents => "calendarEvents",
+            Self::IncomeStatementHistory => "incomeStatementHistory",
+            Self::IncomeStatementHistoryQuarterly => "incomeStatementHistoryQuarterly",
+            Self::BalanceSheetHistory => "balanceSheetHistory",
+            Self::BalanceSheetHistoryQuarterly => "balanceSheetHistoryQuarterly",
+            Self::CashflowStatementHistory => "cashflowStatementHistory",
+            Self::CashflowStatementHistoryQuarterly => "cashflowStatementHistoryQuarterly",
+            Self::InstitutionOwnership => "institutionOwnership",
+            Self::FundOwnership => "fundOwnership",
+            Self::MajorHoldersBreakdown => "majorHoldersBreakdown",
+            Self::InsiderTransactions => "insiderTransactions",
+            Self::InsiderHolders => "insiderHolders",
+            Self::NetSharePurchaseActivity => "netSharePurchaseActivity",
+            Self::SummaryDetail => "summaryDetail",
+            Self::DefaultKeyStatistics => "defaultKeyStatistics",
+            Self::Components => "components",
+        }
+    }
+}
+
+impl std::fmt::Display for QuoteSummaryModule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Deserialize)]
 pub struct V10Envelope {
     #[serde(rename = "quoteSummary")]
@@ -22,6 +156,8 @@ pub struct V10QuoteSummary {
 
 #[derive(Deserialize)]
 pub struct V10Error {
+    #[serde(default)]
+    pub(crate) code: String,
     pub(crate) description: String,
 }
 
@@ -48,7 +184,7 @@ pub async fn fetch(
             .await
             .ok_or_else(|| YfError::Auth("Crumb is not set".into()))?;
 
-        let mut url = client.base_quote_api().join(symbol)?;
+        let mut url = client.symbol_url(Endpoint::QuoteSummary, symbol)?;
         {
             let mut qp = url.query_pairs_mut();
             qp.append_pair("modules", modules);
@@ -96,15 +232,17 @@ pub async fn fetch(
                 client.clear_crumb().await;
                 continue;
             }
-            return Err(YfError::Api(format!("yahoo error: {}", error.description)));
+            return Err(YfError::api("yahoo error", &error.code, &error.description));
         }
 
         return Ok(env);
     }
 
-    Err(YfError::Api(format!(
-        "{caller} API call failed after retry"
-    )))
+    // Only a rejected crumb loops back for another attempt.
+    Err(YfError::Api {
+        code: YahooErrorCode::InvalidCrumb,
+        message: format!("{caller} API call failed after retry"),
+    })
 }
 
 pub async fn fetch_module_result<T>(
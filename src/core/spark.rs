@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::core::models::{interval_as_str, range_as_str};
+use crate::core::{Interval, Range, YfClient, YfError, net};
+
+/// A compact close-price series: `(unix_seconds, close)` pairs, oldest first.
+pub type SparkSeries = Vec<(i64, f64)>;
+
+/// Fetches close-price series for several symbols in a single request.
+///
+/// This uses Yahoo's `/v8/finance/spark` endpoint, which is far lighter than one chart
+/// request per symbol and is intended for watchlist sparklines. Bars with a missing close are
+/// skipped. Symbols Yahoo returns no data for are absent from the map.
+///
+/// # Errors
+///
+/// Returns `YfError::InvalidParams` if `symbols` is empty, or an error if the request fails or
+/// the response cannot be parsed.
+pub async fn fetch_spark(
+    client: &YfClient,
+    symbols: &[&str],
+    range: Range,
+    interval: Interval,
+) -> Result<HashMap<String, SparkSeries>, YfError> {
+    if symbols.is_empty() {
+        return Err(YfError::InvalidParams(
+            "symbols list cannot be empty".into(),
+        ));
+    }
+
+    let yahoo_symbols: Vec<&str> = symbols.iter().map(|s| client.yahoo_symbol(s)).collect();
+    let mut url = client.base_spark().clone();
+    url.query_pairs_mut()
+        .append_pair("symbols", &yahoo_symbols.join(","))
+        .append_pair("range", range_as_str(range))
+        .append_pair("interval", interval_as_str(interval));
+
+    let body = if let Some(cached) = client.cache_get(&url).await {
+        cached
+    } else {
+        let resp = client
+            .send_with_retry(client.http().get(url.clone()), None)
+            .await?;
+        if !resp.status().is_success() {
+            return Err(YfError::from_response(&resp, url.as_str()));
+        }
+        let text = net::get_text(resp, "spark", &symbols.join("-"), "json").await?;
+        client.cache_put(&url, &text, None).await;
+        text
+    };
+
+    let env: SparkEnvelope = serde_json::from_str(&body).map_err(YfError::Json)?;
+    Ok(env
+        .into_series()
+        .into_iter()
+        .map(|(mut symbol, series)| {
+            client.unalias_symbol(&mut symbol);
+            (symbol, series)
+        })
+        .collect())
+}
+
+impl SparkEnvelope {
+    fn into_series(self) -> HashMap<String, SparkSeries> {
+        match self {
+            Self::Wrapped { spark } => spark
+                .result
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|r| {
+                    let block = r.response.into_iter().next()?;
+                    let closes = block
+                        .indicators
+                        .and_then(|i| i.quote.into_iter().next())
+                        .map(|q| q.close)
+                        .unwrap_or_default();
+                    Some((r.symbol, zip_series(&block.timestamp, &closes)))
+                })
+                .collect(),
+            Self::Flat(map) => map
+                .into_iter()
+                .map(|(symbol, s)| (symbol, zip_series(&s.timestamp, &s.close)))
+                .collect(),
+        }
+    }
+}
+
+fn zip_series(ts: &[i64], closes: &[Option<f64>]) -> SparkSeries {
+    ts.iter()
+        .zip(closes)
+        .filter_map(|(&t, c)| c.filter(|v| v.is_finite()).map(|v| (t, v)))
+        .collect()
+}
+
+/* --- wire --- */
+
+// Yahoo serves two shapes: the older `{"spark":{"result":[..]}}` envelope and a flat map keyed
+// by symbol.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SparkEnvelope {
+    Wrapped { spark: SparkNode },
+    Flat(HashMap<String, FlatSeries>),
+}
+
+#[derive(Deserialize)]
+struct SparkNode {
+    result: Option<Vec<SparkResult>>,
+}
+
+#[derive(Deserialize)]
+struct SparkResult {
+    symbol: String,
+    #[serde(default)]
+    response: Vec<SparkBlock>,
+}
+
+#[derive(Deserialize)]
+struct SparkBlock {
+    #[serde(default)]
+    timestamp: Vec<i64>,
+    indicators: Option<SparkIndicators>,
+}
+
+#[derive(Deserialize)]
+struct SparkIndicators {
+    #[serde(default)]
+    quote: Vec<SparkQuote>,
+}
+
+#[derive(Deserialize)]
+struct SparkQuote {
+    #[serde(default)]
+    close: Vec<Option<f64>>,
+}
+
+#[derive(Deserialize)]
+struct FlatSeries {
+    #[serde(default)]
+    timestamp: Vec<i64>,
+    #[serde(default)]
+    close: Vec<Option<f64>>,
+}
@@ -9,8 +9,8 @@ pub fn from_raw<T>(raw: Option<RawNum<T>>) -> Option<T> {
     raw.and_then(|n| n.raw)
 }
 
-pub fn from_raw_u32_round(r: Option<RawNum<f64>>) -> Option<u32> {
-    r.and_then(|n| n.raw).and_then(|v| {
+pub fn from_raw_u32_round(r: Option<YahooNumber>) -> Option<u32> {
+    from_yahoo_number(r).and_then(|v| {
         let rounded = v.round();
         if rounded >= 0.0 && rounded <= f64::from(u32::MAX) {
             // This cast is safe as we check the bounds of rounded.
@@ -22,15 +22,6 @@ pub fn from_raw_u32_round(r: Option<RawNum<f64>>) -> Option<u32> {
     })
 }
 
-#[derive(Deserialize, Clone, Copy)]
-pub struct RawDate {
-    pub(crate) raw: Option<i64>,
-}
-
-pub fn from_raw_date(r: Option<RawDate>) -> Option<i64> {
-    r.and_then(|d| d.raw)
-}
-
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
 fn de_u64_from_any_number<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
 where
@@ -63,3 +54,124 @@ pub struct RawNumU64 {
     #[serde(deserialize_with = "de_u64_from_any_number")]
     pub(crate) raw: Option<u64>,
 }
+
+/// A number as Yahoo serializes it in `quoteSummary` payloads.
+///
+/// Deserializes from the `{"raw": .., "fmt": .., "longFmt": ..}` object (using `raw`, or
+/// parsing `fmt` when `raw` is absent), from a bare number, or from a numeric string. `null`,
+/// `{}` and unparseable values become `YahooNumber(None)`, as does a missing field marked
+/// `#[serde(default)]`.
+///
+/// `fmt` strings are read the way Yahoo writes them: thousands separators are ignored, a
+/// trailing `%` divides by 100 (matching `raw`, which is a fraction), and `k`/`M`/`B`/`T`
+/// suffixes scale by powers of a thousand.
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Serialize)]
+#[serde(transparent)]
+pub struct YahooNumber(pub Option<f64>);
+
+impl YahooNumber {
+    /// Returns the parsed value, if any.
+    #[must_use]
+    pub const fn get(self) -> Option<f64> {
+        self.0
+    }
+}
+
+impl From<YahooNumber> for Option<f64> {
+    fn from(n: YahooNumber) -> Self {
+        n.0
+    }
+}
+
+impl<'de> Deserialize<'de> for YahooNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let v = Option::<serde_json::Value>::deserialize(deserializer)?;
+        Ok(Self(v.as_ref().and_then(yahoo_number_from_value)))
+    }
+}
+
+/// Flattens an optional wire number into its value.
+pub fn from_yahoo_number(n: Option<YahooNumber>) -> Option<f64> {
+    n.and_then(YahooNumber::get)
+}
+
+fn yahoo_number_from_value(v: &serde_json::Value) -> Option<f64> {
+    use serde_json::Value;
+    let n = match v {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => parse_fmt(s),
+        Value::Object(map) => map
+            .get("raw")
+            .and_then(Value::as_f64)
+            .or_else(|| map.get("fmt").and_then(Value::as_str).and_then(parse_fmt)),
+        _ => None,
+    };
+    n.filter(|x| x.is_finite())
+}
+
+fn parse_fmt(s: &str) -> Option<f64> {
+    let s = s.trim().replace(',', "");
+    let (body, scale) = match s.chars().last()? {
+        '%' => (&s[..s.len() - 1], 0.01),
+        'k' | 'K' => (&s[..s.len() - 1], 1e3),
+        'M' => (&s[..s.len() - 1], 1e6),
+        'B' => (&s[..s.len() - 1], 1e9),
+        'T' => (&s[..s.len() - 1], 1e12),
+        _ => (s.as_str(), 1.0),
+    };
+    body.trim().parse::<f64>().ok().map(|x| x * scale)
+}
+
+/// A point in time as Yahoo serializes it: unix seconds, bare or wrapped as
+/// `{"raw": .., "fmt": ..}`.
+///
+/// Timestamps are interpreted as UTC. `null`, a missing `raw`, and values outside the range
+/// `chrono` can represent become `YahooDate(None)` rather than an error or the epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize)]
+#[serde(transparent)]
+pub struct YahooDate(pub Option<chrono::DateTime<chrono::Utc>>);
+
+impl YahooDate {
+    /// Returns the parsed instant, if any.
+    #[must_use]
+    pub const fn get(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.0
+    }
+
+    /// Returns the calendar date of the instant in UTC.
+    #[must_use]
+    pub fn date(self) -> Option<chrono::NaiveDate> {
+        self.0.map(|dt| dt.date_naive())
+    }
+
+    /// Returns the instant as unix seconds.
+    #[must_use]
+    pub fn timestamp(self) -> Option<i64> {
+        self.0.map(|dt| dt.timestamp())
+    }
+}
+
+impl<'de> Deserialize<'de> for YahooDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde_json::Value;
+        let secs = match Option::<Value>::deserialize(deserializer)? {
+            Some(Value::Number(n)) => n.as_i64(),
+            Some(Value::Object(map)) => map.get("raw").and_then(Value::as_i64),
+            _ => None,
+        };
+        Ok(Self(
+            secs.and_then(|s| chrono::DateTime::from_timestamp(s, 0)),
+        ))
+    }
+}
+
+/// Flattens an optional wire date into its instant.
+pub fn from_yahoo_date(d: Option<YahooDate>) -> Option<chrono::DateTime<chrono::Utc>> {
+    d.and_then(YahooDate::get)
+}
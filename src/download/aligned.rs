@@ -0,0 +1,85 @@
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{DateTime, Utc};
+use paft::money::Money;
+
+use crate::core::Candle;
+
+/// Candles for several symbols joined onto one shared timeline.
+///
+/// The timeline is the sorted union of every symbol's bar timestamps, so symbols that trade on
+/// different calendars (holidays, listing dates, halts) line up row-for-row. A symbol with no
+/// bar at a given timestamp has `None` in that slot; nothing is forward-filled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedHistory {
+    /// The shared timeline, in ascending order.
+    pub timestamps: Vec<DateTime<Utc>>,
+    /// The symbols in the result, sorted alphabetically. This is the column order of
+    /// [`wide_closes`](Self::wide_closes).
+    pub symbols: Vec<String>,
+    /// Each symbol's candles, one slot per entry in `timestamps`.
+    pub columns: HashMap<String, Vec<Option<Candle>>>,
+}
+
+impl AlignedHistory {
+    pub(crate) fn from_series(series: &HashMap<String, Vec<Candle>>) -> Self {
+        let timestamps: Vec<DateTime<Utc>> = series
+            .values()
+            .flatten()
+            .map(|c| c.ts)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let mut symbols: Vec<String> = series.keys().cloned().collect();
+        symbols.sort();
+
+        let columns = series
+            .iter()
+            .map(|(sym, candles)| {
+                let by_ts: HashMap<DateTime<Utc>, &Candle> =
+                    candles.iter().map(|c| (c.ts, c)).collect();
+                let column = timestamps
+                    .iter()
+                    .map(|ts| by_ts.get(ts).map(|c| (*c).clone()))
+                    .collect();
+                (sym.clone(), column)
+            })
+            .collect();
+
+        Self {
+            timestamps,
+            symbols,
+            columns,
+        }
+    }
+
+    /// Returns the aligned candles for one symbol, or `None` if it is not in the result.
+    #[must_use]
+    pub fn column(&self, symbol: &str) -> Option<&[Option<Candle>]> {
+        self.columns.get(symbol).map(Vec::as_slice)
+    }
+
+    /// Returns one row per timestamp holding each symbol's close, in [`symbols`](Self::symbols)
+    /// order.
+    #[must_use]
+    pub fn wide_closes(&self) -> Vec<(DateTime<Utc>, Vec<Option<Money>>)> {
+        self.timestamps
+            .iter()
+            .enumerate()
+            .map(|(i, ts)| {
+                let row = self
+                    .symbols
+                    .iter()
+                    .map(|sym| {
+                        self.columns
+                            .get(sym)
+                            .and_then(|col| col[i].as_ref())
+                            .map(|c| c.close.clone())
+                    })
+                    .collect();
+                (*ts, row)
+            })
+            .collect()
+    }
+}
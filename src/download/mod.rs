@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use futures::future::try_join_all;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
 
 use crate::{
     core::client::{CacheMode, RetryConfig},
@@ -9,6 +10,10 @@ use crate::{
 };
 use paft::money::Money;
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+
+mod aligned;
+pub use aligned::AlignedHistory;
+
 type DateRange = (chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>);
 type MaybeDateRange = Option<DateRange>;
 
@@ -26,6 +31,16 @@ pub struct DownloadResult {
     pub adjusted: bool,
 }
 
+impl DownloadResult {
+    /// Joins every symbol's candles onto the union of their timestamps.
+    ///
+    /// See [`AlignedHistory`] for the per-symbol and wide-table views of the result.
+    #[must_use]
+    pub fn aligned(&self) -> AlignedHistory {
+        AlignedHistory::from_series(&self.series)
+    }
+}
+
 /// A builder for downloading historical data for multiple symbols concurrently.
 ///
 /// This provides a convenient way to fetch data for a list of tickers with the same
@@ -51,6 +66,7 @@ pub struct DownloadBuilder {
     rounding: bool,
     repair: bool,
 
+    concurrency: Option<usize>,
     cache_mode: CacheMode,
     retry_override: Option<RetryConfig>,
 }
@@ -214,11 +230,23 @@ impl DownloadBuilder {
             keepna: false,
             rounding: false,
             repair: false,
+            concurrency: None,
             cache_mode: CacheMode::Use,
             retry_override: None,
         }
     }
 
+    /// Limits how many symbols [`run`](Self::run) and [`stream`](Self::stream) fetch at the same time.
+    ///
+    /// By default every symbol is requested at once, bounded only by the client's
+    /// [`max_concurrent_requests`](crate::YfClientBuilder::max_concurrent_requests). A limit of
+    /// zero is rejected when the download runs.
+    #[must_use]
+    pub const fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = Some(limit);
+        self
+    }
+
     /// Sets the cache mode for all API calls made by this builder.
     #[must_use]
     pub const fn cache_mode(mut self, mode: CacheMode) -> Self {
@@ -334,11 +362,17 @@ impl DownloadBuilder {
     ///
     /// # Errors
     ///
-    /// Returns an error if any of the underlying history requests fail.
+    /// Returns an error if any of the underlying history requests fail, or if a
+    /// [`concurrency`](Self::concurrency) limit of zero was set.
     pub async fn run(self) -> Result<DownloadResult, YfError> {
         if self.symbols.is_empty() {
             return Err(YfError::InvalidParams("no symbols specified".into()));
         }
+        if self.concurrency == Some(0) {
+            return Err(YfError::InvalidParams(
+                "concurrency limit must be at least 1".into(),
+            ));
+        }
 
         let need_adjust_in_fetch = self.auto_adjust || self.back_adjust;
         let period_dt = self.precompute_period_dt()?;
@@ -353,9 +387,60 @@ impl DownloadBuilder {
             }
         });
 
-        let joined: Vec<(String, HistoryResponse)> = try_join_all(futures).await?;
+        let joined: Vec<(String, HistoryResponse)> = match self.concurrency {
+            Some(limit) => stream::iter(futures).buffered(limit).try_collect().await?,
+            None => try_join_all(futures).await?,
+        };
         Ok(self.process_joined_results(joined, need_adjust_in_fetch))
     }
+
+    /// Fetches every symbol concurrently and yields each symbol's candles as soon as it finishes.
+    ///
+    /// Unlike [`run`](Self::run), a failure for one symbol does not abort the others: each item
+    /// carries its own `Result`. Items arrive in completion order, not in the order the symbols
+    /// were added. Back-adjustment, repair and rounding are applied per symbol exactly as in
+    /// `run`, and at most [`concurrency`](Self::concurrency) symbols are fetched at a time (all
+    /// of them if unset), within the client's
+    /// [`max_concurrent_requests`](crate::YfClientBuilder::max_concurrent_requests) limit.
+    ///
+    /// An empty symbol list yields an empty stream. A concurrency limit of zero yields an
+    /// [`YfError::InvalidParams`] item for every symbol.
+    pub fn stream(self) -> impl Stream<Item = (String, Result<Vec<Candle>, YfError>)> {
+        let need_adjust_in_fetch = self.auto_adjust || self.back_adjust;
+        let limit = self.concurrency.unwrap_or(self.symbols.len()).max(1);
+        let this = std::sync::Arc::new(self);
+
+        let futures: Vec<_> = this
+            .symbols
+            .iter()
+            .map(|sym| {
+                let sym = sym.clone();
+                let this = std::sync::Arc::clone(&this);
+                async move {
+                    let res = async {
+                        if this.concurrency == Some(0) {
+                            return Err(YfError::InvalidParams(
+                                "concurrency limit must be at least 1".into(),
+                            ));
+                        }
+                        let period_dt = this.precompute_period_dt()?;
+                        let mut resp = this
+                            .build_history_for_symbol(&sym, period_dt, need_adjust_in_fetch)
+                            .fetch_full()
+                            .await?;
+                        let mut v = resp.candles;
+                        this.apply_back_adjust(&mut v, &mut resp.unadjusted_close);
+                        this.maybe_repair(&mut v);
+                        this.apply_rounding_if_enabled(&mut v);
+                        Ok(v)
+                    }
+                    .await;
+                    (sym, res)
+                }
+            })
+            .collect();
+        stream::iter(futures).buffer_unordered(limit)
+    }
 }
 
 /* ---------------- internal helpers ---------------- */
@@ -3,18 +3,20 @@ use crate::{
         YfClient, YfError,
         client::{CacheMode, RetryConfig},
         quotesummary,
-        wire::from_raw,
+        wire::from_yahoo_number,
     },
-    esg::wire::V10Result,
+    esg::wire::{EsgScoresNode, V10Result},
 };
 use paft::fundamentals::esg::{EsgInvolvement, EsgScores, EsgSummary};
 
-pub(super) async fn fetch_esg_scores(
+use super::model::InvolvementFlags;
+
+async fn fetch_esg_node(
     client: &YfClient,
     symbol: &str,
     cache_mode: CacheMode,
     retry_override: Option<&RetryConfig>,
-) -> Result<EsgSummary, YfError> {
+) -> Result<EsgScoresNode, YfError> {
     let root: V10Result = quotesummary::fetch_module_result(
         client,
         symbol,
@@ -25,15 +27,23 @@ pub(super) async fn fetch_esg_scores(
     )
     .await?;
 
-    let esg = root
-        .esg_scores
-        .ok_or_else(|| YfError::MissingData("esgScores module missing from response".into()))?;
+    root.esg_scores
+        .ok_or_else(|| YfError::MissingData("esgScores module missing from response".into()))
+}
+
+pub(super) async fn fetch_esg_scores(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<EsgSummary, YfError> {
+    let esg = fetch_esg_node(client, symbol, cache_mode, retry_override).await?;
 
     // Map to paft types: paft::fundamentals::EsgScores now has only environmental/social/governance.
     let scores = EsgScores {
-        environmental: from_raw(esg.environment_score),
-        social: from_raw(esg.social_score),
-        governance: from_raw(esg.governance_score),
+        environmental: from_yahoo_number(esg.environment_score),
+        social: from_yahoo_number(esg.social_score),
+        governance: from_yahoo_number(esg.governance_score),
     };
 
     // Collect involvement booleans as individual entries with simple categories.
@@ -68,3 +78,29 @@ pub(super) async fn fetch_esg_scores(
         involvement,
     })
 }
+
+pub(super) async fn fetch_involvement_flags(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<InvolvementFlags, YfError> {
+    let esg = fetch_esg_node(client, symbol, cache_mode, retry_override).await?;
+    Ok(InvolvementFlags {
+        adult: esg.adult,
+        alcoholic: esg.alcoholic,
+        animal_testing: esg.animal_testing,
+        catholic: esg.catholic,
+        controversial_weapons: esg.controversial_weapons,
+        small_arms: esg.small_arms,
+        fur_leather: esg.fur_leather,
+        gambling: esg.gambling,
+        gmo: esg.gmo,
+        military_contract: esg.military_contract,
+        nuclear: esg.nuclear,
+        palm_oil: esg.palm_oil,
+        pesticides: esg.pesticides,
+        thermal_coal: esg.thermal_coal,
+        tobacco: esg.tobacco,
+    })
+}
@@ -2,7 +2,7 @@ mod api;
 mod model;
 mod wire;
 
-pub use model::{EsgInvolvement, EsgScores, EsgSummary};
+pub use model::{EsgInvolvement, EsgScores, EsgSummary, InvolvementFlags};
 
 use crate::{
     YfClient, YfError,
@@ -56,4 +56,20 @@ impl EsgBuilder {
         )
         .await
     }
+
+    /// Fetches the product-involvement flags (alcohol, tobacco, gambling, ...) as optional
+    /// booleans.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YfError` if the network request fails or the API response cannot be parsed.
+    pub async fn involvement_flags(self) -> Result<InvolvementFlags, YfError> {
+        api::fetch_involvement_flags(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
 }
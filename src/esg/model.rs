@@ -1,2 +1,41 @@
 // Re-export types from paft
 pub use paft::fundamentals::esg::{EsgInvolvement, EsgScores, EsgSummary};
+
+/// Yahoo's product-involvement flags from the `esgScores` module, for exclusion screens.
+///
+/// Unlike [`EsgSummary::involvement`], which lists only the categories that are set, each
+/// flag here distinguishes "not involved" (`Some(false)`) from "not reported" (`None`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InvolvementFlags {
+    /// Adult entertainment.
+    pub adult: Option<bool>,
+    /// Alcoholic beverages.
+    pub alcoholic: Option<bool>,
+    /// Animal testing.
+    pub animal_testing: Option<bool>,
+    /// Conflicts with Catholic values.
+    pub catholic: Option<bool>,
+    /// Controversial weapons.
+    pub controversial_weapons: Option<bool>,
+    /// Small arms.
+    pub small_arms: Option<bool>,
+    /// Fur and specialty leather.
+    pub fur_leather: Option<bool>,
+    /// Gambling.
+    pub gambling: Option<bool>,
+    /// Genetically modified organisms.
+    pub gmo: Option<bool>,
+    /// Military contracting.
+    pub military_contract: Option<bool>,
+    /// Nuclear power.
+    pub nuclear: Option<bool>,
+    /// Palm oil.
+    pub palm_oil: Option<bool>,
+    /// Pesticides.
+    pub pesticides: Option<bool>,
+    /// Thermal coal (Yahoo's `coal` key).
+    pub thermal_coal: Option<bool>,
+    /// Tobacco.
+    pub tobacco: Option<bool>,
+}
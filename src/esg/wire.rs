@@ -1,4 +1,4 @@
-use crate::core::wire::RawNum;
+use crate::core::wire::YahooNumber;
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -13,10 +13,10 @@ pub struct V10Result {
 pub struct EsgScoresNode {
     // These are objects: { "raw": ... }
     #[allow(dead_code)]
-    pub(crate) total_esg: Option<RawNum<f64>>,
-    pub(crate) environment_score: Option<RawNum<f64>>,
-    pub(crate) social_score: Option<RawNum<f64>>,
-    pub(crate) governance_score: Option<RawNum<f64>>,
+    pub(crate) total_esg: Option<YahooNumber>,
+    pub(crate) environment_score: Option<YahooNumber>,
+    pub(crate) social_score: Option<YahooNumber>,
+    pub(crate) governance_score: Option<YahooNumber>,
 
     // These are primitives
     #[allow(dead_code)]
@@ -1,12 +1,13 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use std::collections::BTreeMap;
 
 use crate::{
     core::{
         YfClient, YfError,
-        client::{CacheMode, RetryConfig},
+        client::{CacheMode, Endpoint, RetryConfig},
         conversions::{f64_to_money_with_currency, i64_to_datetime, string_to_period},
-        wire::from_raw,
+        quotesummary,
+        wire::{YahooDate, YahooNumber, from_yahoo_date, from_yahoo_number},
     },
     fundamentals::wire::{TimeseriesData, TimeseriesEnvelope},
 };
@@ -14,11 +15,15 @@ use paft::fundamentals::profile::ShareCount;
 use paft::money::Currency;
 
 use super::fetch::fetch_modules;
+
 use super::{
-    BalanceSheetRow, CashflowRow, Earnings, EarningsQuarter, EarningsQuarterEps, EarningsYear,
-    IncomeStatementRow,
+    BalanceSheetRow, CashflowRow, Earnings, EarningsEvent, EarningsQuarter, EarningsQuarterEps,
+    EarningsYear, IncomeStatementRow,
 };
 
+/// Statement periods the `quoteSummary` statement modules return (four years or quarters).
+const DEFAULT_STATEMENT_PERIODS: usize = 4;
+
 /// Generic helper function to fetch and process timeseries data from the fundamentals API.
 ///
 /// This function handles the common pattern of:
@@ -34,6 +39,7 @@ async fn fetch_timeseries_data<T, F>(
     client: &YfClient,
     symbol: &str,
     quarterly: bool,
+    periods: Option<usize>,
     cache_mode: CacheMode,
     retry_override: Option<&RetryConfig>,
     keys: &[&str],
@@ -48,14 +54,15 @@ where
     let types: Vec<String> = keys.iter().map(|k| format!("{prefix}{k}")).collect();
     let type_str = types.join(",");
 
-    let end_ts = Utc::now().timestamp();
-    let start_ts = Utc::now()
-        .checked_sub_signed(Duration::days(365 * 5))
+    let now = client.now();
+    let end_ts = now.timestamp();
+    let start_ts = now
+        .checked_sub_signed(Duration::days(lookback_days(quarterly, periods)))
         .map_or(0, |dt| dt.timestamp());
 
-    let mut url = client.base_timeseries().join(symbol)?;
+    let mut url = client.symbol_url(Endpoint::Timeseries, symbol)?;
     url.query_pairs_mut()
-        .append_pair("symbol", symbol)
+        .append_pair("symbol", client.yahoo_symbol(symbol))
         .append_pair("type", &type_str)
         .append_pair("period1", &start_ts.to_string())
         .append_pair("period2", &end_ts.to_string());
@@ -113,17 +120,45 @@ where
         }
     }
 
-    Ok(rows_map.into_values().rev().collect())
+    let mut rows: Vec<T> = rows_map.into_values().rev().collect();
+    if let Some(n) = periods {
+        rows.truncate(n);
+    }
+    Ok(rows)
+}
+
+/// Days of history requested from the timeseries endpoint: five years, or enough for
+/// `periods` reports plus one period of slack for late filers.
+fn lookback_days(quarterly: bool, periods: Option<usize>) -> i64 {
+    let per_period = if quarterly { 92 } else { 366 };
+    let wanted = periods
+        .and_then(|n| i64::try_from(n).ok())
+        .map_or(0, |n| n.saturating_add(1).saturating_mul(per_period));
+    wanted.max(365 * 5)
 }
 
 pub(super) async fn income_statement(
     client: &YfClient,
     symbol: &str,
     quarterly: bool,
+    periods: Option<usize>,
     currency: Currency,
     cache_mode: CacheMode,
     retry_override: Option<&RetryConfig>,
 ) -> Result<Vec<IncomeStatementRow>, YfError> {
+    if periods.is_some_and(|n| n > DEFAULT_STATEMENT_PERIODS) {
+        return income_statement_timeseries(
+            client,
+            symbol,
+            quarterly,
+            periods,
+            currency,
+            cache_mode,
+            retry_override,
+        )
+        .await;
+    }
+
     let modules = if quarterly {
         "incomeStatementHistoryQuarterly"
     } else {
@@ -142,42 +177,121 @@ pub(super) async fn income_statement(
 
     Ok(arr
         .into_iter()
+        .take(periods.unwrap_or(usize::MAX))
         .map(|n| IncomeStatementRow {
             period: string_to_period(
                 &n.end_date
-                    .map(|d| d.raw.unwrap_or_default().to_string())
+                    .map(|d| d.timestamp().unwrap_or_default().to_string())
                     .unwrap_or_default(),
             ),
-            total_revenue: from_raw(n.total_revenue)
+            total_revenue: from_yahoo_number(n.total_revenue)
                 .map(|v| f64_to_money_with_currency(v, currency.clone())),
-            gross_profit: from_raw(n.gross_profit)
+            gross_profit: from_yahoo_number(n.gross_profit)
                 .map(|v| f64_to_money_with_currency(v, currency.clone())),
-            operating_income: from_raw(n.operating_income)
+            operating_income: from_yahoo_number(n.operating_income)
                 .map(|v| f64_to_money_with_currency(v, currency.clone())),
-            net_income: from_raw(n.net_income)
+            net_income: from_yahoo_number(n.net_income)
                 .map(|v| f64_to_money_with_currency(v, currency.clone())),
         })
         .collect())
 }
 
+async fn income_statement_timeseries(
+    client: &YfClient,
+    symbol: &str,
+    quarterly: bool,
+    periods: Option<usize>,
+    currency: Currency,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Vec<IncomeStatementRow>, YfError> {
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct TimeseriesValueF64 {
+        #[serde(rename = "reportedValue")]
+        reported_value: Option<YahooNumber>,
+    }
+
+    let keys = [
+        "TotalRevenue",
+        "GrossProfit",
+        "OperatingIncome",
+        "NetIncome",
+    ];
+    let endpoint_name = "income_statement";
+
+    let create_default_row = |period_end: i64| IncomeStatementRow {
+        period: string_to_period(&period_end.to_string()),
+        total_revenue: None,
+        gross_profit: None,
+        operating_income: None,
+        net_income: None,
+    };
+
+    let process_item = |key: &str,
+                        values_json: &serde_json::Value,
+                        rows_map: &mut BTreeMap<i64, IncomeStatementRow>,
+                        timestamps: &[i64],
+                        prefix: &str|
+     -> Result<(), YfError> {
+        if let Ok(values) = serde_json::from_value::<Vec<TimeseriesValueF64>>(values_json.clone()) {
+            for (i, ts) in timestamps.iter().enumerate() {
+                let row = rows_map
+                    .entry(*ts)
+                    .or_insert_with(|| create_default_row(*ts));
+
+                let value = values
+                    .get(i)
+                    .and_then(|v| v.reported_value.and_then(|rv| rv.get()))
+                    .map(|v| f64_to_money_with_currency(v, currency.clone()));
+
+                match key.strip_prefix(prefix) {
+                    Some("TotalRevenue") => row.total_revenue = value,
+                    Some("GrossProfit") => row.gross_profit = value,
+                    Some("OperatingIncome") => row.operating_income = value,
+                    Some("NetIncome") => row.net_income = value,
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    };
+
+    fetch_timeseries_data(
+        client,
+        symbol,
+        quarterly,
+        periods,
+        cache_mode,
+        retry_override,
+        &keys,
+        endpoint_name,
+        create_default_row,
+        process_item,
+    )
+    .await
+}
+
 #[allow(clippy::too_many_lines)]
 #[allow(clippy::cognitive_complexity)]
 pub(super) async fn balance_sheet(
     client: &YfClient,
     symbol: &str,
     quarterly: bool,
+    periods: Option<usize>,
     currency: Currency,
     cache_mode: CacheMode,
     retry_override: Option<&RetryConfig>,
 ) -> Result<Vec<BalanceSheetRow>, YfError> {
     use serde::Deserialize;
 
-    use crate::core::wire::{RawNum, RawNumU64};
+    use crate::core::wire::RawNumU64;
 
     #[derive(Deserialize)]
     struct TimeseriesValueF64 {
         #[serde(rename = "reportedValue")]
-        reported_value: Option<RawNum<f64>>,
+        reported_value: Option<YahooNumber>,
     }
     #[derive(Deserialize)]
     struct TimeseriesValueU64 {
@@ -234,7 +348,7 @@ pub(super) async fn balance_sheet(
 
                 let value = values
                     .get(i)
-                    .and_then(|v| v.reported_value.and_then(|rv| rv.raw));
+                    .and_then(|v| v.reported_value.and_then(|rv| rv.get()));
 
                 if key == format!("{prefix}TotalAssets") {
                     row.total_assets =
@@ -260,6 +374,7 @@ pub(super) async fn balance_sheet(
         client,
         symbol,
         quarterly,
+        periods,
         cache_mode,
         retry_override,
         &keys,
@@ -275,18 +390,17 @@ pub(super) async fn cashflow(
     client: &YfClient,
     symbol: &str,
     quarterly: bool,
+    periods: Option<usize>,
     currency: Currency,
     cache_mode: CacheMode,
     retry_override: Option<&RetryConfig>,
 ) -> Result<Vec<CashflowRow>, YfError> {
     use serde::Deserialize;
 
-    use crate::core::wire::RawNum;
-
     #[derive(Deserialize)]
     struct TimeseriesValueF64 {
         #[serde(rename = "reportedValue")]
-        reported_value: Option<RawNum<f64>>,
+        reported_value: Option<YahooNumber>,
     }
 
     let keys = [
@@ -319,7 +433,7 @@ pub(super) async fn cashflow(
 
                 let value = values
                     .get(i)
-                    .and_then(|v| v.reported_value.and_then(|rv| rv.raw));
+                    .and_then(|v| v.reported_value.and_then(|rv| rv.get()));
 
                 if key == format!("{prefix}OperatingCashFlow") {
                     row.operating_cashflow =
@@ -342,6 +456,7 @@ pub(super) async fn cashflow(
         client,
         symbol,
         quarterly,
+        periods,
         cache_mode,
         retry_override,
         &keys,
@@ -390,11 +505,11 @@ pub(super) async fn earnings(
                         i32::try_from(date).ok().map(|year| EarningsYear {
                             year,
                             revenue: y.revenue.as_ref().and_then(|x| {
-                                x.raw
+                                x.get()
                                     .map(|v| f64_to_money_with_currency(v, currency.clone()))
                             }),
                             earnings: y.earnings.as_ref().and_then(|x| {
-                                x.raw
+                                x.get()
                                     .map(|v| f64_to_money_with_currency(v, currency.clone()))
                             }),
                         })
@@ -413,11 +528,11 @@ pub(super) async fn earnings(
                 .map(|q| EarningsQuarter {
                     period: string_to_period(&q.date.clone().unwrap_or_default()),
                     revenue: q.revenue.as_ref().and_then(|x| {
-                        x.raw
+                        x.get()
                             .map(|v| f64_to_money_with_currency(v, currency.clone()))
                     }),
                     earnings: q.earnings.as_ref().and_then(|x| {
-                        x.raw
+                        x.get()
                             .map(|v| f64_to_money_with_currency(v, currency.clone()))
                     }),
                 })
@@ -434,11 +549,11 @@ pub(super) async fn earnings(
                 .map(|q| EarningsQuarterEps {
                     period: string_to_period(&q.date.clone().unwrap_or_default()),
                     actual: q.actual.as_ref().and_then(|x| {
-                        x.raw
+                        x.get()
                             .map(|v| f64_to_money_with_currency(v, currency.clone()))
                     }),
                     estimate: q.estimate.as_ref().and_then(|x| {
-                        x.raw
+                        x.get()
                             .map(|v| f64_to_money_with_currency(v, currency.clone()))
                     }),
                 })
@@ -469,16 +584,151 @@ pub(super) async fn calendar(
         .earnings_date
         .unwrap_or_default()
         .into_iter()
-        .filter_map(|d| d.raw.map(i64_to_datetime))
+        .filter_map(YahooDate::get)
         .collect();
 
     Ok(super::Calendar {
         earnings_dates,
-        ex_dividend_date: c.ex_dividend_date.and_then(|x| x.raw.map(i64_to_datetime)),
-        dividend_payment_date: c.dividend_date.and_then(|x| x.raw.map(i64_to_datetime)),
+        ex_dividend_date: from_yahoo_date(c.ex_dividend_date),
+        dividend_payment_date: from_yahoo_date(c.dividend_date),
     })
 }
 
+pub(super) async fn ex_dividend_date(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Option<DateTime<Utc>>, YfError> {
+    let root = fetch_modules(client, symbol, "calendarEvents", cache_mode, retry_override).await?;
+    let Some(events) = root.calendar_events else {
+        return Ok(None);
+    };
+    // Yahoo reports the date beside `earnings`; older payloads nested it inside.
+    let date = from_yahoo_date(events.ex_dividend_date).or_else(|| {
+        events
+            .earnings
+            .and_then(|e| from_yahoo_date(e.ex_dividend_date))
+    });
+    // Once the last ex-date passes Yahoo keeps reporting it until the next one is declared.
+    let today = client.now().date_naive();
+    Ok(date.filter(|d| d.date_naive() >= today))
+}
+
+pub(super) async fn calendar_events_raw(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<serde_json::Value, YfError> {
+    let mut result: serde_json::Value = quotesummary::fetch_module_result(
+        client,
+        symbol,
+        "calendarEvents",
+        "fundamentals",
+        cache_mode,
+        retry_override,
+    )
+    .await?;
+    result
+        .get_mut("calendarEvents")
+        .map(serde_json::Value::take)
+        .ok_or_else(|| YfError::MissingData("calendarEvents missing".into()))
+}
+
+pub(super) async fn statements_raw(
+    client: &YfClient,
+    symbol: &str,
+    quarterly: bool,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<serde_json::Value, YfError> {
+    let modules = if quarterly {
+        "incomeStatementHistoryQuarterly,balanceSheetHistoryQuarterly,cashflowStatementHistoryQuarterly"
+    } else {
+        "incomeStatementHistory,balanceSheetHistory,cashflowStatementHistory"
+    };
+    quotesummary::fetch_module_result(
+        client,
+        symbol,
+        modules,
+        "fundamentals",
+        cache_mode,
+        retry_override,
+    )
+    .await
+}
+
+/// A quarter's report lands within this many days after the quarter end.
+const REPORT_WINDOW_DAYS: i64 = 100;
+
+pub(super) async fn earnings_schedule(
+    client: &YfClient,
+    symbol: &str,
+    currency: Currency,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Vec<EarningsEvent>, YfError> {
+    let root = fetch_modules(
+        client,
+        symbol,
+        "earningsHistory,calendarEvents",
+        cache_mode,
+        retry_override,
+    )
+    .await?;
+
+    let money = |x: Option<YahooNumber>| {
+        from_yahoo_number(x).map(|v| f64_to_money_with_currency(v, currency.clone()))
+    };
+
+    let mut out: Vec<EarningsEvent> = root
+        .earnings_history
+        .and_then(|h| h.history)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|row| {
+            let date = from_yahoo_date(row.quarter)?;
+            Some(EarningsEvent {
+                date,
+                eps_actual: money(row.eps_actual),
+                eps_estimate: money(row.eps_estimate),
+                surprise_percent: from_yahoo_number(row.surprise_percent),
+                upcoming: false,
+                date_is_estimate: false,
+            })
+        })
+        .collect();
+
+    if let Some(cal) = root.calendar_events.and_then(|c| c.earnings) {
+        // Yahoo may publish a window (two dates); the first is the expected report date.
+        let next = cal
+            .earnings_date
+            .unwrap_or_default()
+            .into_iter()
+            .find_map(YahooDate::get);
+
+        if let Some(date) = next {
+            let already_reported = out
+                .iter()
+                .any(|e| e.date < date && date <= e.date + Duration::days(REPORT_WINDOW_DAYS));
+            if !already_reported {
+                out.push(EarningsEvent {
+                    date,
+                    eps_actual: None,
+                    eps_estimate: money(cal.earnings_average),
+                    surprise_percent: None,
+                    upcoming: true,
+                    date_is_estimate: cal.is_earnings_date_estimate.unwrap_or(false),
+                });
+            }
+        }
+    }
+
+    out.sort_by_key(|e| e.date);
+    Ok(out)
+}
+
 pub(super) async fn shares(
     client: &YfClient,
     symbol: &str,
@@ -488,9 +738,10 @@ pub(super) async fn shares(
     cache_mode: CacheMode,
     retry_override: Option<&RetryConfig>,
 ) -> Result<Vec<ShareCount>, YfError> {
-    let end_ts = end.unwrap_or_else(Utc::now).timestamp();
+    let now = client.now();
+    let end_ts = end.unwrap_or(now).timestamp();
     let start_ts = start
-        .unwrap_or_else(|| Utc::now() - Duration::days(548))
+        .unwrap_or_else(|| now - Duration::days(548))
         .timestamp();
 
     let type_key = if quarterly {
@@ -499,9 +750,9 @@ pub(super) async fn shares(
         "annualBasicAverageShares"
     };
 
-    let mut url = client.base_timeseries().join(symbol)?;
+    let mut url = client.symbol_url(Endpoint::Timeseries, symbol)?;
     url.query_pairs_mut()
-        .append_pair("symbol", symbol)
+        .append_pair("symbol", client.yahoo_symbol(symbol))
         .append_pair("type", type_key)
         .append_pair("period1", &start_ts.to_string())
         .append_pair("period2", &end_ts.to_string());
@@ -562,7 +813,7 @@ pub(super) async fn shares(
 
     let counts = timestamps
         .into_iter()
-        .zip(values.into_iter())
+        .zip(values)
         .filter_map(|(ts, val)| {
             val.reported_value
                 .and_then(|rv| rv.raw)
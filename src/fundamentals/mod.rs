@@ -5,8 +5,8 @@ mod fetch;
 mod wire;
 
 pub use model::{
-    BalanceSheetRow, Calendar, CashflowRow, Earnings, EarningsQuarter, EarningsQuarterEps,
-    EarningsYear, IncomeStatementRow, ShareCount,
+    BalanceSheetRow, Calendar, CashflowRow, Earnings, EarningsEvent, EarningsQuarter,
+    EarningsQuarterEps, EarningsYear, IncomeStatementRow, ShareCount,
 };
 
 use crate::core::{
@@ -19,6 +19,7 @@ use paft::money::Currency;
 pub struct FundamentalsBuilder {
     client: YfClient,
     symbol: String,
+    periods: Option<usize>,
     cache_mode: CacheMode,
     retry_override: Option<RetryConfig>,
 }
@@ -29,11 +30,25 @@ impl FundamentalsBuilder {
         Self {
             client: client.clone(),
             symbol: symbol.into(),
+            periods: None,
             cache_mode: CacheMode::Use,
             retry_override: None,
         }
     }
 
+    /// Limits the income statement, balance sheet and cash flow to the `n` most recent periods.
+    ///
+    /// The `quoteSummary` statement modules only go back about four years (or quarters), so
+    /// asking for more switches the income statement to the fundamentals timeseries endpoint
+    /// (`/ws/fundamentals-timeseries`), and widens the window the balance sheet and cash flow
+    /// request from it, e.g. to fetch ten years of annual data. Yahoo may still hold fewer
+    /// periods than asked for.
+    #[must_use]
+    pub const fn periods(mut self, n: usize) -> Self {
+        self.periods = Some(n);
+        self
+    }
+
     /// Sets the cache mode for this specific API call.
     #[must_use]
     pub const fn cache_mode(mut self, mode: CacheMode) -> Self {
@@ -52,7 +67,8 @@ impl FundamentalsBuilder {
     ///
     /// Set `quarterly` to `true` to get quarterly reports, or `false` for annual reports.
     /// Provide `Some(currency)` to override the inferred reporting currency; pass `None`
-    /// to use the cached profile-based heuristic.
+    /// to use the cached profile-based heuristic. Amounts are restated in the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) when one is set.
     ///
     /// # Errors
     ///
@@ -67,22 +83,32 @@ impl FundamentalsBuilder {
             .reporting_currency(&self.symbol, override_currency)
             .await;
 
-        api::income_statement(
+        let mut rows = api::income_statement(
             &self.client,
             &self.symbol,
             quarterly,
+            self.periods,
             currency,
             self.cache_mode,
             self.retry_override.as_ref(),
         )
-        .await
+        .await?;
+        crate::fx::to_display_currency(
+            &self.client,
+            &mut rows,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await?;
+        Ok(rows)
     }
 
     /// Fetches the balance sheet.
     ///
     /// Set `quarterly` to `true` to get quarterly reports, or `false` for annual reports.
     /// Provide `Some(currency)` to override the inferred reporting currency; pass `None`
-    /// to use the cached profile-based heuristic.
+    /// to use the cached profile-based heuristic. Amounts are restated in the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) when one is set.
     ///
     /// # Errors
     ///
@@ -97,22 +123,32 @@ impl FundamentalsBuilder {
             .reporting_currency(&self.symbol, override_currency)
             .await;
 
-        api::balance_sheet(
+        let mut rows = api::balance_sheet(
             &self.client,
             &self.symbol,
             quarterly,
+            self.periods,
             currency,
             self.cache_mode,
             self.retry_override.as_ref(),
         )
-        .await
+        .await?;
+        crate::fx::to_display_currency(
+            &self.client,
+            &mut rows,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await?;
+        Ok(rows)
     }
 
     /// Fetches the cash flow statement.
     ///
     /// Set `quarterly` to `true` to get quarterly reports, or `false` for annual reports.
     /// Provide `Some(currency)` to override the inferred reporting currency; pass `None`
-    /// to use the cached profile-based heuristic.
+    /// to use the cached profile-based heuristic. Amounts are restated in the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) when one is set.
     ///
     /// # Errors
     ///
@@ -127,15 +163,24 @@ impl FundamentalsBuilder {
             .reporting_currency(&self.symbol, override_currency)
             .await;
 
-        api::cashflow(
+        let mut rows = api::cashflow(
             &self.client,
             &self.symbol,
             quarterly,
+            self.periods,
             currency,
             self.cache_mode,
             self.retry_override.as_ref(),
         )
-        .await
+        .await?;
+        crate::fx::to_display_currency(
+            &self.client,
+            &mut rows,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await?;
+        Ok(rows)
     }
 
     /// Fetches earnings history and estimates.
@@ -159,6 +204,27 @@ impl FundamentalsBuilder {
         .await
     }
 
+    /// Fetches the raw income statement, balance sheet and cash flow modules in one
+    /// `quoteSummary` call.
+    ///
+    /// The returned object is keyed by module name (e.g. `incomeStatementHistory`,
+    /// `balanceSheetHistory`, `cashflowStatementHistory`, with a `Quarterly` suffix when
+    /// `quarterly` is `true`), giving access to line items the typed rows do not expose.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YfError` if the network request fails or the API response cannot be parsed.
+    pub async fn statements_raw(&self, quarterly: bool) -> Result<serde_json::Value, YfError> {
+        api::statements_raw(
+            &self.client,
+            &self.symbol,
+            quarterly,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
     /// Fetches corporate calendar events like earnings dates.
     ///
     /// # Errors
@@ -174,6 +240,70 @@ impl FundamentalsBuilder {
         .await
     }
 
+    /// Fetches the next ex-dividend date from `calendarEvents`.
+    ///
+    /// Returns the announced ex-date if it falls today or later (per the client's
+    /// [`clock`](crate::YfClientBuilder::clock)). Returns `None` for symbols that do not pay
+    /// dividends, and when the only date Yahoo reports has already passed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YfError` if the network request fails or the API response cannot be parsed.
+    pub async fn ex_dividend_date(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>, YfError> {
+        api::ex_dividend_date(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
+    /// Fetches the raw `calendarEvents` module, including fields [`calendar`](Self::calendar)
+    /// does not expose (e.g. earnings and revenue estimate ranges).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YfError` if the network request fails, the API response cannot be parsed,
+    /// or the module is missing from the response.
+    pub async fn calendar_events_raw(&self) -> Result<serde_json::Value, YfError> {
+        api::calendar_events_raw(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
+    /// Fetches past quarters (`earningsHistory`) and the next scheduled report
+    /// (`calendarEvents`) as one chronologically sorted schedule.
+    ///
+    /// A calendar date falling within a quarter already present in the history is
+    /// treated as that quarter's report and not listed again.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YfError` if the network request fails or the API response cannot be parsed.
+    pub async fn earnings_schedule(
+        &self,
+        override_currency: Option<Currency>,
+    ) -> Result<Vec<EarningsEvent>, YfError> {
+        let currency = self
+            .client
+            .reporting_currency(&self.symbol, override_currency)
+            .await;
+
+        api::earnings_schedule(
+            &self.client,
+            &self.symbol,
+            currency,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
     /// Fetches the historical number of shares outstanding.
     ///
     /// If `quarterly` is true, fetches quarterly data, otherwise annual data is fetched.
@@ -193,4 +323,23 @@ impl FundamentalsBuilder {
         )
         .await
     }
+
+    /// Fetches shares outstanding reported between `start` and `end`.
+    pub(crate) async fn shares_between(
+        &self,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+        quarterly: bool,
+    ) -> Result<Vec<ShareCount>, YfError> {
+        api::shares(
+            &self.client,
+            &self.symbol,
+            Some(start),
+            Some(end),
+            quarterly,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
 }
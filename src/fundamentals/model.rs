@@ -1,3 +1,7 @@
+use chrono::{DateTime, Utc};
+use paft::money::Money;
+use serde::{Deserialize, Serialize};
+
 // Re-export types from paft without using prelude
 pub use paft::fundamentals::analysis::{
     Earnings, EarningsQuarter, EarningsQuarterEps, EarningsYear,
@@ -6,3 +10,21 @@ pub use paft::fundamentals::profile::ShareCount;
 pub use paft::fundamentals::statements::{
     BalanceSheetRow, Calendar, CashflowRow, IncomeStatementRow,
 };
+
+/// One entry of a ticker's earnings schedule, past or upcoming.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EarningsEvent {
+    /// Fiscal quarter end for reported quarters; scheduled report date for upcoming ones.
+    pub date: DateTime<Utc>,
+    /// Reported EPS, `None` for quarters not yet reported.
+    pub eps_actual: Option<Money>,
+    /// Consensus EPS estimate.
+    pub eps_estimate: Option<Money>,
+    /// Surprise versus the estimate, as a fraction (e.g. `0.05` for 5%).
+    pub surprise_percent: Option<f64>,
+    /// `true` for the scheduled, not yet reported, entry from `calendarEvents`.
+    pub upcoming: bool,
+    /// `true` when Yahoo flags an upcoming `date` as estimated (`isEarningsDateEstimate`)
+    /// rather than confirmed by the company. Always `false` for reported quarters.
+    pub date_is_estimate: bool,
+}
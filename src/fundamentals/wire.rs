@@ -1,4 +1,4 @@
-use crate::core::wire::{RawDate, RawNum, RawNumU64};
+use crate::core::wire::{RawNumU64, YahooDate, YahooNumber};
 use serde::Deserialize;
 
 /* ---------------- Serde mapping (only what we need) ---------------- */
@@ -15,6 +15,8 @@ pub struct V10Result {
     pub(crate) earnings: Option<EarningsNode>,
     #[serde(rename = "calendarEvents")]
     pub(crate) calendar_events: Option<CalendarEventsNode>,
+    #[serde(rename = "earningsHistory")]
+    pub(crate) earnings_history: Option<EarningsHistoryNode>,
 }
 
 /* --- income --- */
@@ -27,15 +29,15 @@ pub struct IncomeHistoryNode {
 #[derive(Deserialize)]
 pub struct IncomeRowNode {
     #[serde(rename = "endDate")]
-    pub(crate) end_date: Option<RawDate>,
+    pub(crate) end_date: Option<YahooDate>,
     #[serde(rename = "totalRevenue")]
-    pub(crate) total_revenue: Option<RawNum<f64>>,
+    pub(crate) total_revenue: Option<YahooNumber>,
     #[serde(rename = "grossProfit")]
-    pub(crate) gross_profit: Option<RawNum<f64>>,
+    pub(crate) gross_profit: Option<YahooNumber>,
     #[serde(rename = "operatingIncome")]
-    pub(crate) operating_income: Option<RawNum<f64>>,
+    pub(crate) operating_income: Option<YahooNumber>,
     #[serde(rename = "netIncome")]
-    pub(crate) net_income: Option<RawNum<f64>>,
+    pub(crate) net_income: Option<YahooNumber>,
 }
 
 /* --- earnings --- */
@@ -56,15 +58,15 @@ pub struct FinancialsChartNode {
 #[derive(Deserialize)]
 pub struct FinancialYearNode {
     pub(crate) date: Option<i64>,
-    pub(crate) revenue: Option<RawNum<f64>>,
-    pub(crate) earnings: Option<RawNum<f64>>,
+    pub(crate) revenue: Option<YahooNumber>,
+    pub(crate) earnings: Option<YahooNumber>,
 }
 
 #[derive(Deserialize)]
 pub struct FinancialQuarterNode {
     pub(crate) date: Option<String>,
-    pub(crate) revenue: Option<RawNum<f64>>,
-    pub(crate) earnings: Option<RawNum<f64>>,
+    pub(crate) revenue: Option<YahooNumber>,
+    pub(crate) earnings: Option<YahooNumber>,
 }
 
 #[derive(Deserialize)]
@@ -75,25 +77,48 @@ pub struct EarningsChartNode {
 #[derive(Deserialize)]
 pub struct EpsQuarterNode {
     pub(crate) date: Option<String>,
-    pub(crate) actual: Option<RawNum<f64>>,
-    pub(crate) estimate: Option<RawNum<f64>>,
+    pub(crate) actual: Option<YahooNumber>,
+    pub(crate) estimate: Option<YahooNumber>,
 }
 
 /* --- calendar --- */
 #[derive(Deserialize)]
 pub struct CalendarEventsNode {
     pub(crate) earnings: Option<CalendarEarningsNode>,
+    #[serde(rename = "exDividendDate")]
+    pub(crate) ex_dividend_date: Option<YahooDate>,
 }
 
 #[derive(Deserialize)]
 #[allow(clippy::struct_field_names)]
 pub struct CalendarEarningsNode {
     #[serde(rename = "earningsDate")]
-    pub(crate) earnings_date: Option<Vec<RawDate>>,
+    pub(crate) earnings_date: Option<Vec<YahooDate>>,
     #[serde(rename = "exDividendDate")]
-    pub(crate) ex_dividend_date: Option<RawDate>,
+    pub(crate) ex_dividend_date: Option<YahooDate>,
     #[serde(rename = "dividendDate")]
-    pub(crate) dividend_date: Option<RawDate>,
+    pub(crate) dividend_date: Option<YahooDate>,
+    #[serde(rename = "earningsAverage")]
+    pub(crate) earnings_average: Option<YahooNumber>,
+    #[serde(rename = "isEarningsDateEstimate")]
+    pub(crate) is_earnings_date_estimate: Option<bool>,
+}
+
+/* --- earnings history --- */
+#[derive(Deserialize)]
+pub struct EarningsHistoryNode {
+    pub(crate) history: Option<Vec<EarningsHistoryRowNode>>,
+}
+
+#[derive(Deserialize)]
+pub struct EarningsHistoryRowNode {
+    pub(crate) quarter: Option<YahooDate>,
+    #[serde(rename = "epsActual")]
+    pub(crate) eps_actual: Option<YahooNumber>,
+    #[serde(rename = "epsEstimate")]
+    pub(crate) eps_estimate: Option<YahooNumber>,
+    #[serde(rename = "surprisePercent")]
+    pub(crate) surprise_percent: Option<YahooNumber>,
 }
 
 #[derive(Deserialize)]
@@ -0,0 +1,202 @@
+use paft::money::{Currency, Money};
+use std::collections::HashMap;
+
+use super::{FxBuilder, pair_symbol};
+use crate::core::client::{CacheMode, RetryConfig};
+use crate::core::conversions::{f64_to_money_with_currency, minor_unit_major, money_to_f64};
+use crate::core::{Quote, YfClient, YfError};
+use crate::fundamentals::{BalanceSheetRow, CashflowRow, IncomeStatementRow};
+use crate::quote::QuoteDetails;
+use crate::ticker::FastInfo;
+
+/// Output types whose `Money` fields are restated in the client's display currency.
+pub(crate) trait MoneyFields {
+    fn money_fields(&mut self) -> Vec<&mut Money>;
+}
+
+impl MoneyFields for Quote {
+    fn money_fields(&mut self) -> Vec<&mut Money> {
+        [&mut self.price, &mut self.previous_close]
+            .into_iter()
+            .filter_map(Option::as_mut)
+            .collect()
+    }
+}
+
+impl MoneyFields for QuoteDetails {
+    fn money_fields(&mut self) -> Vec<&mut Money> {
+        self.quote.money_fields()
+    }
+}
+
+impl MoneyFields for IncomeStatementRow {
+    fn money_fields(&mut self) -> Vec<&mut Money> {
+        [
+            &mut self.total_revenue,
+            &mut self.gross_profit,
+            &mut self.operating_income,
+            &mut self.net_income,
+        ]
+        .into_iter()
+        .filter_map(Option::as_mut)
+        .collect()
+    }
+}
+
+impl MoneyFields for BalanceSheetRow {
+    fn money_fields(&mut self) -> Vec<&mut Money> {
+        [
+            &mut self.total_assets,
+            &mut self.total_liabilities,
+            &mut self.total_equity,
+            &mut self.cash,
+            &mut self.long_term_debt,
+        ]
+        .into_iter()
+        .filter_map(Option::as_mut)
+        .collect()
+    }
+}
+
+impl MoneyFields for CashflowRow {
+    fn money_fields(&mut self) -> Vec<&mut Money> {
+        [
+            &mut self.operating_cashflow,
+            &mut self.capital_expenditures,
+            &mut self.free_cash_flow,
+            &mut self.net_income,
+        ]
+        .into_iter()
+        .filter_map(Option::as_mut)
+        .collect()
+    }
+}
+
+/// Restates every `Money` field of `items` in the client's display currency, if one is set.
+///
+/// Amounts already in the display currency are left untouched. Minor-unit currencies such
+/// as `GBp` are converted through their major currency.
+pub(crate) async fn to_display_currency<T: MoneyFields>(
+    client: &YfClient,
+    items: &mut [T],
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<(), YfError> {
+    let Some(mut rates) = DisplayRates::new(client, cache_mode, retry_override) else {
+        return Ok(());
+    };
+    for item in items.iter_mut() {
+        for money in item.money_fields() {
+            let source = money.currency().to_string();
+            if let Some(rate) = rates.rate(&source).await? {
+                *money =
+                    f64_to_money_with_currency(money_to_f64(money) * rate, rates.target.clone());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Restates the prices of `items` in the client's display currency, if one is set.
+///
+/// Like [`to_display_currency`], for [`FastInfo`]'s plain `f64` prices. Entries without a
+/// reported currency are left untouched.
+pub(crate) async fn fast_info_to_display_currency(
+    client: &YfClient,
+    items: &mut [FastInfo],
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<(), YfError> {
+    let Some(mut rates) = DisplayRates::new(client, cache_mode, retry_override) else {
+        return Ok(());
+    };
+    for info in items.iter_mut() {
+        let Some(source) = info.currency.clone() else {
+            continue;
+        };
+        if let Some(rate) = rates.rate(&source).await? {
+            info.last_price *= rate;
+            if let Some(prev) = info.previous_close.as_mut() {
+                *prev *= rate;
+            }
+            info.currency = Some(rates.target_code.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Display-currency rates looked up during one conversion, by source currency code.
+struct DisplayRates<'a> {
+    client: &'a YfClient,
+    cache_mode: CacheMode,
+    retry_override: Option<&'a RetryConfig>,
+    target: Currency,
+    target_code: String,
+    rates: HashMap<String, f64>,
+}
+
+impl<'a> DisplayRates<'a> {
+    /// Returns `None` when the client has no display currency.
+    fn new(
+        client: &'a YfClient,
+        cache_mode: CacheMode,
+        retry_override: Option<&'a RetryConfig>,
+    ) -> Option<Self> {
+        let target = client.display_currency()?.clone();
+        Some(Self {
+            client,
+            cache_mode,
+            retry_override,
+            target_code: target.to_string(),
+            target,
+            rates: HashMap::new(),
+        })
+    }
+
+    /// The rate from `source` to the display currency, or `None` if no conversion is needed.
+    async fn rate(&mut self, source: &str) -> Result<Option<f64>, YfError> {
+        if source.eq_ignore_ascii_case(&self.target_code) && minor_unit_major(source).is_none() {
+            return Ok(None);
+        }
+        if let Some(rate) = self.rates.get(source) {
+            return Ok(Some(*rate));
+        }
+        let rate = display_rate(
+            self.client,
+            source,
+            &self.target_code,
+            self.cache_mode,
+            self.retry_override,
+        )
+        .await?;
+        self.rates.insert(source.to_string(), rate);
+        Ok(Some(rate))
+    }
+}
+
+async fn display_rate(
+    client: &YfClient,
+    source: &str,
+    target: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<f64, YfError> {
+    let (from, scale) = minor_unit_major(source).map_or((source, 1.0), |major| (major, 0.01));
+    let key = pair_symbol(from, target);
+
+    if cache_mode == CacheMode::Use
+        && let Some(rate) = client.cached_display_rate(&key).await
+    {
+        return Ok(rate * scale);
+    }
+
+    let rate = FxBuilder::new(client, from, target)
+        .cache_mode(cache_mode)
+        .retry_policy(retry_override.cloned())
+        .latest()
+        .await?;
+    if cache_mode != CacheMode::Bypass {
+        client.store_display_rate(key, rate).await;
+    }
+    Ok(rate * scale)
+}
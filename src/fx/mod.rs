@@ -0,0 +1,149 @@
+mod display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::client::{CacheMode, RetryConfig};
+use crate::core::conversions::money_to_f64;
+use crate::core::{Interval, Range, YfClient, YfError};
+use crate::history::{HistoryBound, HistoryBuilder};
+
+pub(crate) use display::{fast_info_to_display_currency, to_display_currency};
+
+/// Returns the Yahoo Finance symbol for a currency pair, e.g. `USDEUR=X`.
+#[must_use]
+pub fn pair_symbol(from: &str, to: &str) -> String {
+    format!(
+        "{}{}=X",
+        from.trim().to_ascii_uppercase(),
+        to.trim().to_ascii_uppercase()
+    )
+}
+
+/// An exchange rate observation: one unit of the base currency expressed in the quote currency.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FxRate {
+    /// Timestamp of the daily candle the rate was taken from.
+    pub ts: DateTime<Utc>,
+    /// Closing rate for the day.
+    pub rate: f64,
+}
+
+/// Which rate was used when converting an amount between currencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RateBasis {
+    /// The closing rate on the event date, or the most recent close before it.
+    EventDate,
+    /// The most recent available rate.
+    Latest,
+}
+
+/// Returns the rate in effect at `ts`: the last observation on or before it.
+///
+/// `rates` must be sorted by timestamp, as returned by [`FxBuilder::between`].
+#[must_use]
+pub fn rate_at(rates: &[FxRate], ts: DateTime<Utc>) -> Option<f64> {
+    let idx = rates.partition_point(|r| r.ts <= ts);
+    idx.checked_sub(1).map(|i| rates[i].rate)
+}
+
+/// A builder for fetching exchange rates between two currencies.
+///
+/// Rates are read from the daily chart of Yahoo's `{FROM}{TO}=X` pair. Converting a
+/// currency to itself short-circuits to a rate of `1.0` without any request.
+#[derive(Debug, Clone)]
+pub struct FxBuilder {
+    client: YfClient,
+    from: String,
+    to: String,
+    cache_mode: CacheMode,
+    retry_override: Option<RetryConfig>,
+}
+
+impl FxBuilder {
+    /// Creates a new `FxBuilder` converting `from` into `to` (ISO 4217 codes).
+    pub fn new(client: &YfClient, from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self {
+            client: client.clone(),
+            from: from.into(),
+            to: to.into(),
+            cache_mode: CacheMode::Use,
+            retry_override: None,
+        }
+    }
+
+    /// Sets the cache mode for this specific API call.
+    #[must_use]
+    pub const fn cache_mode(mut self, mode: CacheMode) -> Self {
+        self.cache_mode = mode;
+        self
+    }
+
+    /// Overrides the default retry policy for this specific API call.
+    #[must_use]
+    pub fn retry_policy(mut self, cfg: Option<RetryConfig>) -> Self {
+        self.retry_override = cfg;
+        self
+    }
+
+    fn is_identity(&self) -> bool {
+        self.from.trim().eq_ignore_ascii_case(self.to.trim())
+    }
+
+    fn history(&self) -> HistoryBuilder {
+        HistoryBuilder::new(&self.client, pair_symbol(&self.from, &self.to))
+            .interval(Interval::D1)
+            .auto_adjust(false)
+            .actions(false)
+            .cache_mode(self.cache_mode)
+            .retry_policy(self.retry_override.clone())
+    }
+
+    /// Fetches the most recent available rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::MissingData` if the pair has no recent prices, or any error
+    /// from the underlying chart request.
+    pub async fn latest(self) -> Result<f64, YfError> {
+        if self.is_identity() {
+            return Ok(1.0);
+        }
+        let candles = self.history().range(Range::D5).fetch().await?;
+        candles
+            .last()
+            .map(|c| money_to_f64(&c.close))
+            .ok_or_else(|| {
+                YfError::MissingData(format!(
+                    "no fx rate for {}",
+                    pair_symbol(&self.from, &self.to)
+                ))
+            })
+    }
+
+    /// Fetches daily closing rates between two dates, sorted by timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying chart request.
+    pub async fn between(
+        self,
+        start: impl HistoryBound,
+        end: impl HistoryBound,
+    ) -> Result<Vec<FxRate>, YfError> {
+        if self.is_identity() {
+            return Ok(vec![FxRate {
+                ts: start.to_utc(),
+                rate: 1.0,
+            }]);
+        }
+        let candles = self.history().between(start, end).fetch().await?;
+        Ok(candles
+            .into_iter()
+            .map(|c| FxRate {
+                ts: c.ts,
+                rate: money_to_f64(&c.close),
+            })
+            .collect())
+    }
+}
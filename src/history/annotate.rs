@@ -0,0 +1,85 @@
+use chrono::NaiveDate;
+use paft::money::Money;
+use serde::{Deserialize, Serialize};
+
+use crate::core::conversions::{f64_to_money_with_currency, money_to_f64};
+use crate::core::{Action, Candle, HistoryResponse};
+
+/// A stock split ratio: `numerator` new shares for every `denominator` held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Split {
+    /// New shares received.
+    pub numerator: u32,
+    /// Shares held before the split.
+    pub denominator: u32,
+}
+
+/// A price candle together with the corporate actions that fell within its period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnnotatedCandle {
+    /// The price candle.
+    #[serde(flatten)]
+    pub candle: Candle,
+    /// Dividends paid per share with an ex-date in this candle's period, summed.
+    pub dividend: Option<Money>,
+    /// Split effective in this candle's period.
+    pub split: Option<Split>,
+}
+
+/// Attaches each dividend and split in `resp.actions` to the last candle dated on or before
+/// the event, so weekly and monthly candles collect the events within their period.
+pub(crate) fn annotate(resp: &HistoryResponse) -> Vec<AnnotatedCandle> {
+    let tz = resp.meta.as_ref().and_then(|m| m.timezone);
+    let date_of = |ts: chrono::DateTime<chrono::Utc>| -> NaiveDate {
+        tz.map_or_else(|| ts.date_naive(), |tz| ts.with_timezone(&tz).date_naive())
+    };
+
+    let dates: Vec<NaiveDate> = resp.candles.iter().map(|c| date_of(c.ts)).collect();
+    let mut out: Vec<AnnotatedCandle> = resp
+        .candles
+        .iter()
+        .map(|c| AnnotatedCandle {
+            candle: c.clone(),
+            dividend: None,
+            split: None,
+        })
+        .collect();
+
+    for action in &resp.actions {
+        let ts = match action {
+            Action::Dividend { ts, .. } | Action::Split { ts, .. } => *ts,
+            Action::CapitalGain { .. } => continue,
+        };
+        // Compared by date, so a split stamped before the open still lands on its day.
+        let date = date_of(ts);
+        let Some(idx) = dates.partition_point(|d| *d <= date).checked_sub(1) else {
+            continue;
+        };
+        let slot = &mut out[idx];
+        match action {
+            Action::Dividend { amount, .. } => {
+                slot.dividend = Some(match slot.dividend.take() {
+                    Some(prev) if prev.currency() == amount.currency() => {
+                        f64_to_money_with_currency(
+                            money_to_f64(&prev) + money_to_f64(amount),
+                            amount.currency().clone(),
+                        )
+                    }
+                    _ => amount.clone(),
+                });
+            }
+            Action::Split {
+                numerator,
+                denominator,
+                ..
+            } => {
+                slot.split = Some(Split {
+                    numerator: *numerator,
+                    denominator: *denominator,
+                });
+            }
+            Action::CapitalGain { .. } => {}
+        }
+    }
+    out
+}
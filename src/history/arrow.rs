@@ -0,0 +1,90 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use ::arrow::array::{ArrayRef, Float64Array, TimestampSecondArray, UInt64Array};
+use ::arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use ::arrow::error::ArrowError;
+use ::arrow::record_batch::RecordBatch;
+use paft::money::Money;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+
+use crate::core::conversions::money_to_f64;
+use crate::core::{Candle, HistoryResponse};
+
+/// Conversion of a fetched [`HistoryResponse`] into Apache Arrow, for handing candles to
+/// Polars, `DataFusion` or any other Arrow-native tool without a copy through `serde`.
+///
+/// Available with the `arrow` feature. Columns are `ts` (UTC seconds), `open`, `high`,
+/// `low`, `close`, `volume` and `unadjusted_close`; the last two are nullable.
+pub trait ToRecordBatch {
+    /// Returns the Arrow schema of the batches produced by [`to_record_batch`](Self::to_record_batch).
+    fn arrow_schema() -> SchemaRef
+    where
+        Self: Sized;
+
+    /// Converts the candles into a single Arrow `RecordBatch`, one row per candle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the columns do not match the schema.
+    fn to_record_batch(&self) -> Result<RecordBatch, ArrowError>;
+
+    /// Writes the candles as a Parquet file to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch cannot be built or the Parquet write fails.
+    fn write_parquet<W: Write + Send>(&self, writer: W) -> Result<(), ParquetError> {
+        let batch = self.to_record_batch()?;
+        let mut w = ArrowWriter::try_new(writer, batch.schema(), None)?;
+        w.write(&batch)?;
+        w.close()?;
+        Ok(())
+    }
+}
+
+impl ToRecordBatch for HistoryResponse {
+    fn arrow_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Second, Some("UTC".into())),
+                false,
+            ),
+            Field::new("open", DataType::Float64, false),
+            Field::new("high", DataType::Float64, false),
+            Field::new("low", DataType::Float64, false),
+            Field::new("close", DataType::Float64, false),
+            Field::new("volume", DataType::UInt64, true),
+            Field::new("unadjusted_close", DataType::Float64, true),
+        ]))
+    }
+
+    fn to_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+        let c = &self.candles;
+        let price = |f: fn(&Candle) -> &Money| -> ArrayRef {
+            Arc::new(Float64Array::from_iter_values(
+                c.iter().map(|x| money_to_f64(f(x))),
+            ))
+        };
+        let unadjusted: Float64Array = match &self.unadjusted_close {
+            Some(v) => v.iter().map(|m| Some(money_to_f64(m))).collect(),
+            None => std::iter::repeat_n(None, c.len()).collect(),
+        };
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(
+                TimestampSecondArray::from_iter_values(c.iter().map(|x| x.ts.timestamp()))
+                    .with_timezone("UTC"),
+            ),
+            price(|x| &x.open),
+            price(|x| &x.high),
+            price(|x| &x.low),
+            price(|x| &x.close),
+            Arc::new(c.iter().map(|x| x.volume).collect::<UInt64Array>()),
+            Arc::new(unadjusted),
+        ];
+        RecordBatch::try_new(Self::arrow_schema(), columns)
+    }
+}
@@ -1,12 +1,16 @@
 mod actions;
 mod adjust;
 mod assemble;
+mod columns;
+mod convert;
 mod fetch;
 
 use crate::core::client::{CacheMode, RetryConfig};
 use crate::core::conversions::f64_to_money_with_currency_str;
 use crate::core::{YfClient, YfError};
 use crate::history::wire::MetaNode;
+use crate::history::{AnnotatedCandle, ColumnSet, HistoryColumns, HistoryResponseExt};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use chrono_tz::Tz;
 use paft::market::action::Action;
 use paft::market::requests::history::{Interval, Range};
@@ -15,7 +19,33 @@ use paft::market::responses::history::{Candle, HistoryMeta, HistoryResponse};
 use actions::extract_actions;
 use adjust::cumulative_split_after;
 use assemble::assemble_candles;
-use fetch::fetch_chart;
+use columns::decode_columns;
+use convert::convert_series;
+use fetch::{fetch_chart, fetch_chart_body};
+
+/// A start or end bound accepted by [`HistoryBuilder::between`].
+pub trait HistoryBound {
+    /// Converts the bound to a UTC instant.
+    fn to_utc(self) -> DateTime<Utc>;
+}
+
+impl<T: TimeZone> HistoryBound for DateTime<T> {
+    fn to_utc(self) -> DateTime<Utc> {
+        self.with_timezone(&Utc)
+    }
+}
+
+impl HistoryBound for NaiveDateTime {
+    fn to_utc(self) -> DateTime<Utc> {
+        self.and_utc()
+    }
+}
+
+impl HistoryBound for NaiveDate {
+    fn to_utc(self) -> DateTime<Utc> {
+        self.and_time(NaiveTime::MIN).and_utc()
+    }
+}
 
 /// A builder for fetching historical price data for a single symbol.
 ///
@@ -43,6 +73,14 @@ pub struct HistoryBuilder {
     #[doc(hidden)]
     pub(crate) keepna: bool,
     #[doc(hidden)]
+    pub(crate) convert_to: Option<String>,
+    #[doc(hidden)]
+    pub(crate) limit: Option<usize>,
+    #[doc(hidden)]
+    pub(crate) extra_params: Vec<(String, String)>,
+    #[doc(hidden)]
+    pub(crate) columns: ColumnSet,
+    #[doc(hidden)]
     pub(crate) cache_mode: CacheMode,
     #[doc(hidden)]
     pub(crate) retry_override: Option<RetryConfig>,
@@ -61,6 +99,10 @@ impl HistoryBuilder {
             include_prepost: false,
             include_actions: true,
             keepna: false,
+            convert_to: None,
+            limit: None,
+            extra_params: Vec::new(),
+            columns: ColumnSet::ALL,
             cache_mode: CacheMode::Use,
             retry_override: None,
         }
@@ -90,17 +132,18 @@ impl HistoryBuilder {
         self
     }
 
-    /// Sets an absolute time period for the request using start and end timestamps.
+    /// Sets an absolute time period for the request.
+    ///
+    /// Accepts any [`HistoryBound`]: a `DateTime` in any timezone, a `NaiveDateTime`
+    /// (interpreted as UTC) or a `NaiveDate` (midnight UTC). The bounds are converted to
+    /// epoch seconds for Yahoo's `period1`/`period2` parameters. A `start` that is not
+    /// strictly before `end` makes the request fail with [`YfError::InvalidDates`].
     ///
     /// This will override any previously set range using `range()`.
     #[must_use]
-    pub const fn between(
-        mut self,
-        start: chrono::DateTime<chrono::Utc>,
-        end: chrono::DateTime<chrono::Utc>,
-    ) -> Self {
+    pub fn between(mut self, start: impl HistoryBound, end: impl HistoryBound) -> Self {
         self.range = None;
-        self.period = Some((start.timestamp(), end.timestamp()));
+        self.period = Some((start.to_utc().timestamp(), end.to_utc().timestamp()));
         self
     }
 
@@ -142,6 +185,117 @@ impl HistoryBuilder {
         self
     }
 
+    /// Converts the OHLC prices into `currency` (an ISO 4217 code), e.g. to chart a
+    /// USD-listed stock in EUR.
+    ///
+    /// Each candle is converted at the daily closing rate of the `{FROM}{TO}=X` pair on or
+    /// before its timestamp, fetched through the [`fx`](crate::fx) module. Volumes and
+    /// corporate actions are left untouched. Converted prices are rounded to the target
+    /// currency's minor units.
+    #[must_use]
+    pub fn convert_to(mut self, currency: impl Into<String>) -> Self {
+        self.convert_to = Some(currency.into());
+        self
+    }
+
+    /// Keeps only the most recent `n` candles.
+    ///
+    /// The request itself is unchanged (choose a `range` that covers `n` bars); the response
+    /// is trimmed client-side after parsing, along with the unadjusted closes and any actions
+    /// that predate the first kept candle.
+    #[must_use]
+    pub const fn limit(mut self, n: usize) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// Appends an arbitrary query parameter to the chart request URL.
+    ///
+    /// This is an escape hatch for Yahoo parameters the crate does not model yet. Params the
+    /// crate sets itself (`range`, `period1`/`period2`, `interval`, `events`, `includePrePost`)
+    /// are never overridden; an extra param with one of those keys is ignored.
+    #[must_use]
+    pub fn extra_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra_params.push((key.into(), value.into()));
+        self
+    }
+
+    /// Selects the columns [`fetch_columns`](Self::fetch_columns) returns. (Default:
+    /// [`ColumnSet::ALL`])
+    ///
+    /// Columns left out are skipped while parsing, so they are never allocated. This keeps
+    /// memory down when downloading long histories for hundreds of symbols.
+    #[must_use]
+    pub const fn columns(mut self, set: ColumnSet) -> Self {
+        self.columns = set;
+        self
+    }
+
+    /// Executes the request and returns the series column by column, holding only the
+    /// columns selected with [`columns`](Self::columns).
+    ///
+    /// [`auto_adjust`](Self::auto_adjust) adjusts the open, high, low and close columns (and
+    /// volumes for splits) exactly as [`fetch`](Self::fetch) does; the adjusted close column is
+    /// always Yahoo's own. Unless [`keepna`](Self::keepna) is set, rows with a missing value in
+    /// any returned price column are dropped. `limit` keeps only the most recent rows.
+    /// Corporate actions are not returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YfError` if the network request fails or the API response cannot be parsed,
+    /// or `YfError::InvalidParams` if [`convert_to`](Self::convert_to) is set, since columnar
+    /// output is not currency-converted.
+    pub async fn fetch_columns(self) -> Result<HistoryColumns, YfError> {
+        if self.convert_to.is_some() {
+            return Err(YfError::InvalidParams(
+                "convert_to is not supported by fetch_columns".into(),
+            ));
+        }
+        let body = fetch_chart_body(
+            &self.client,
+            &self.symbol,
+            self.range,
+            self.period,
+            self.interval,
+            self.auto_adjust,
+            self.include_prepost,
+            &self.extra_params,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await?;
+        let mut out = decode_columns(
+            &body,
+            self.columns,
+            self.client.normalize_minor_units(),
+            self.auto_adjust,
+            self.keepna,
+        )?;
+
+        if let Some(n) = self.limit
+            && out.ts.len() > n
+        {
+            let skip = out.ts.len() - n;
+            out.ts.drain(..skip);
+            for col in [
+                &mut out.open,
+                &mut out.high,
+                &mut out.low,
+                &mut out.close,
+                &mut out.adj_close,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                col.drain(..skip.min(col.len()));
+            }
+            if let Some(col) = &mut out.volume {
+                col.drain(..skip.min(col.len()));
+            }
+        }
+        Ok(out)
+    }
+
     /// Executes the request and returns only the price candles.
     ///
     /// # Errors
@@ -152,6 +306,20 @@ impl HistoryBuilder {
         Ok(resp.candles)
     }
 
+    /// Executes the request and returns the candles annotated with the dividend and split in
+    /// each candle's period. See [`HistoryResponseExt::annotated_candles`](crate::HistoryResponseExt::annotated_candles).
+    ///
+    /// Annotations come from the response's actions, so none are attached when
+    /// [`actions`](Self::actions) is disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YfError` if the network request fails or the API response cannot be parsed.
+    pub async fn fetch_annotated(self) -> Result<Vec<AnnotatedCandle>, YfError> {
+        let resp = self.fetch_full().await?;
+        Ok(resp.annotated_candles())
+    }
+
     /// Executes the request and returns the full response, including candles, actions, and metadata.
     ///
     /// # Errors
@@ -168,6 +336,7 @@ impl HistoryBuilder {
             self.interval,
             self.include_actions,
             self.include_prepost,
+            &self.extra_params,
             self.cache_mode,
             self.retry_override.as_ref(),
         )
@@ -184,7 +353,7 @@ impl HistoryBuilder {
 
         // 4) Assemble candles (+ raw close) with/without adjustments
         let currency = fetched.meta.as_ref().and_then(|m| m.currency.as_deref());
-        let (candles, raw_close) = assemble_candles(
+        let (mut candles, raw_close) = assemble_candles(
             &fetched.ts,
             &fetched.quote,
             &fetched.adjclose,
@@ -201,7 +370,44 @@ impl HistoryBuilder {
             | Action::CapitalGain { ts, .. } => ts.timestamp(),
         });
 
-        // 5) Map metadata
+        let mut unadjusted_close: Vec<_> = raw_close
+            .into_iter()
+            .map(|price| f64_to_money_with_currency_str(price, currency))
+            .collect();
+
+        if let Some(n) = self.limit
+            && candles.len() > n
+        {
+            let skip = candles.len() - n;
+            candles.drain(..skip);
+            unadjusted_close.drain(..skip.min(unadjusted_close.len()));
+            let first_ts = candles.first().map(|c| c.ts);
+            actions_out.retain(|a| match (a, first_ts) {
+                (
+                    Action::Dividend { ts, .. }
+                    | Action::Split { ts, .. }
+                    | Action::CapitalGain { ts, .. },
+                    Some(first),
+                ) => *ts >= first,
+                (_, None) => false,
+            });
+        }
+
+        // 5) Optional currency conversion
+        if let Some(target) = self.convert_to.as_deref() {
+            convert_series(
+                &self.client,
+                currency,
+                target,
+                &mut candles,
+                &mut unadjusted_close,
+                self.cache_mode,
+                self.retry_override.as_ref(),
+            )
+            .await?;
+        }
+
+        // 6) Map metadata
         let meta_out = map_meta(fetched.meta.as_ref());
 
         Ok(HistoryResponse {
@@ -209,16 +415,34 @@ impl HistoryBuilder {
             actions: actions_out,
             adjusted: self.auto_adjust,
             meta: meta_out,
-            unadjusted_close: Some(
-                raw_close
-                    .into_iter()
-                    .map(|price| f64_to_money_with_currency_str(price, currency))
-                    .collect(),
-            ),
+            unadjusted_close: Some(unadjusted_close),
         })
     }
 }
 
+/// Fetches only the chart `meta` block for a symbol over a short range.
+pub(crate) async fn fetch_chart_meta(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Option<MetaNode>, YfError> {
+    let fetched = fetch_chart(
+        client,
+        symbol,
+        Some(Range::D1),
+        None,
+        Interval::D1,
+        false,
+        false,
+        &[],
+        cache_mode,
+        retry_override,
+    )
+    .await?;
+    Ok(fetched.meta)
+}
+
 /* --- tiny private helper --- */
 
 fn map_meta(m: Option<&MetaNode>) -> Option<HistoryMeta> {
@@ -1,4 +1,5 @@
 use crate::core::conversions::{f64_to_money_with_currency, i64_to_datetime};
+use crate::core::wire::YahooDate;
 use crate::history::wire::Events;
 use paft::market::action::Action;
 use paft::money::Currency;
@@ -17,7 +18,9 @@ pub fn extract_actions(
 
     if let Some(divs) = ev.dividends.as_ref() {
         for (k, d) in divs {
-            let ts = k.parse::<i64>().unwrap_or_else(|_| d.date.unwrap_or(0));
+            let ts = k
+                .parse::<i64>()
+                .unwrap_or_else(|_| d.date.and_then(YahooDate::timestamp).unwrap_or(0));
             if let Some(amount) = d.amount {
                 out.push(Action::Dividend {
                     ts: i64_to_datetime(ts),
@@ -29,7 +32,9 @@ pub fn extract_actions(
 
     if let Some(gains) = ev.capital_gains.as_ref() {
         for (k, g) in gains {
-            let ts = k.parse::<i64>().unwrap_or_else(|_| g.date.unwrap_or(0));
+            let ts = k
+                .parse::<i64>()
+                .unwrap_or_else(|_| g.date.and_then(YahooDate::timestamp).unwrap_or(0));
             if let Some(gain) = g.amount {
                 out.push(Action::CapitalGain {
                     ts: i64_to_datetime(ts),
@@ -41,7 +46,9 @@ pub fn extract_actions(
 
     if let Some(splits) = ev.splits.as_ref() {
         for (k, s) in splits {
-            let ts = k.parse::<i64>().unwrap_or_else(|_| s.date.unwrap_or(0));
+            let ts = k
+                .parse::<i64>()
+                .unwrap_or_else(|_| s.date.and_then(YahooDate::timestamp).unwrap_or(0));
             let (num, den) = if let (Some(n), Some(d)) = (s.numerator, s.denominator) {
                 (n as u32, d as u32)
             } else if let Some(r) = s.split_ratio.as_deref() {
@@ -0,0 +1,257 @@
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::value::RawValue;
+
+use paft::money::{Currency, IsoCurrency};
+
+use crate::core::YfError;
+use crate::core::conversions::{i64_to_datetime, minor_unit_major};
+use crate::history::wire::{ChartError, Events, MetaNode};
+use crate::history::{ColumnSet, HistoryColumns};
+
+use super::actions::extract_actions;
+use super::adjust::{cumulative_split_after, price_factor_for_row};
+use super::fetch::check_consistent_currency;
+use super::map_meta;
+
+// Mirrors `wire::ChartEnvelope`, but keeps each indicator column as a slice of the body so
+// that only the requested ones are ever parsed.
+#[derive(Deserialize)]
+struct Envelope<'a> {
+    #[serde(borrow)]
+    chart: Option<Node<'a>>,
+}
+
+#[derive(Deserialize)]
+struct Node<'a> {
+    #[serde(borrow)]
+    result: Option<Vec<ResultNode<'a>>>,
+    error: Option<ChartError>,
+}
+
+#[derive(Deserialize)]
+struct ResultNode<'a> {
+    #[serde(default)]
+    meta: Option<MetaNode>,
+    #[serde(default)]
+    timestamp: Option<Vec<i64>>,
+    #[serde(borrow)]
+    indicators: Indicators<'a>,
+    #[serde(default)]
+    events: Option<Events>,
+}
+
+#[derive(Deserialize)]
+struct Indicators<'a> {
+    #[serde(default, borrow)]
+    quote: Vec<QuoteColumns<'a>>,
+    #[serde(default, borrow)]
+    adjclose: Vec<AdjCloseColumn<'a>>,
+}
+
+#[derive(Deserialize)]
+struct QuoteColumns<'a> {
+    #[serde(borrow)]
+    open: Option<&'a RawValue>,
+    #[serde(borrow)]
+    high: Option<&'a RawValue>,
+    #[serde(borrow)]
+    low: Option<&'a RawValue>,
+    #[serde(borrow)]
+    close: Option<&'a RawValue>,
+    #[serde(borrow)]
+    volume: Option<&'a RawValue>,
+}
+
+#[derive(Deserialize)]
+struct AdjCloseColumn<'a> {
+    #[serde(borrow)]
+    adjclose: Option<&'a RawValue>,
+}
+
+/// Parses `raw` when the column is wanted; an absent column becomes an empty one.
+fn column<T: DeserializeOwned>(
+    wanted: bool,
+    raw: Option<&RawValue>,
+) -> Result<Option<Vec<Option<T>>>, YfError> {
+    if !wanted {
+        return Ok(None);
+    }
+    raw.map_or_else(
+        || Ok(Vec::new()),
+        |r| serde_json::from_str(r.get()).map_err(YfError::Json),
+    )
+    .map(Some)
+}
+
+pub fn decode_columns(
+    body: &str,
+    set: ColumnSet,
+    normalize_minor_units: bool,
+    auto_adjust: bool,
+    keepna: bool,
+) -> Result<HistoryColumns, YfError> {
+    let envelope: Envelope<'_> = serde_json::from_str(body).map_err(YfError::Json)?;
+    let chart = envelope
+        .chart
+        .ok_or_else(|| YfError::MissingData("missing chart".into()))?;
+    if let Some(error) = chart.error {
+        return Err(YfError::api("chart error", &error.code, &error.description));
+    }
+    let mut result = chart
+        .result
+        .ok_or_else(|| YfError::MissingData("missing result".into()))?;
+    check_consistent_currency(result.iter().map(|r| r.meta.as_ref()))?;
+    if result.is_empty() {
+        return Err(YfError::MissingData("empty result".into()));
+    }
+    let first = result.swap_remove(0);
+
+    let quote = first
+        .indicators
+        .quote
+        .first()
+        .ok_or_else(|| YfError::MissingData("missing quote".into()))?;
+    let adjclose = first.indicators.adjclose.first().and_then(|a| a.adjclose);
+
+    // The adjustment factor needs both closes, even when neither is returned.
+    let adjust = auto_adjust && (set.open || set.high || set.low || set.close || set.volume);
+    let raw_ts = first.timestamp.unwrap_or_default();
+    let mut currency = first.meta.as_ref().and_then(|m| m.currency.clone());
+    let mut out = HistoryColumns {
+        ts: raw_ts.iter().copied().map(i64_to_datetime).collect(),
+        currency: None,
+        meta: map_meta(first.meta.as_ref()),
+        open: column(set.open, quote.open)?,
+        high: column(set.high, quote.high)?,
+        low: column(set.low, quote.low)?,
+        close: column(set.close || adjust, quote.close)?,
+        adj_close: column(set.adj_close || adjust, adjclose)?,
+        volume: column(set.volume, quote.volume)?,
+    };
+
+    if normalize_minor_units && let Some(major) = currency.as_deref().and_then(minor_unit_major) {
+        currency = Some(major.to_string());
+        for price in [
+            &mut out.open,
+            &mut out.high,
+            &mut out.low,
+            &mut out.close,
+            &mut out.adj_close,
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .flatten()
+        {
+            *price /= 100.0;
+        }
+    }
+    out.currency = currency;
+
+    if adjust {
+        // Only the split ratios are used; the currency of the actions is irrelevant.
+        let (_, splits) = extract_actions(first.events.as_ref(), &Currency::Iso(IsoCurrency::USD));
+        adjust_columns(&mut out, &raw_ts, &splits);
+    }
+    if !set.close {
+        out.close = None;
+    }
+    if !set.adj_close {
+        out.adj_close = None;
+    }
+    if !keepna {
+        drop_missing_rows(&mut out);
+    }
+    Ok(out)
+}
+
+/// Applies the same split and dividend adjustment as the candle path to the open, high, low
+/// and close columns, and the split adjustment to volumes.
+fn adjust_columns(out: &mut HistoryColumns, raw_ts: &[i64], splits: &[(i64, f64)]) {
+    let cum_split_after = cumulative_split_after(raw_ts, splits);
+    let at = |col: &Option<Vec<Option<f64>>>, i: usize| col.as_ref()?.get(i).copied().flatten();
+    let factors: Vec<f64> = (0..raw_ts.len())
+        .map(|i| {
+            price_factor_for_row(
+                i,
+                at(&out.adj_close, i),
+                at(&out.close, i),
+                &cum_split_after,
+            )
+        })
+        .collect();
+
+    for col in [&mut out.open, &mut out.high, &mut out.low, &mut out.close]
+        .into_iter()
+        .flatten()
+    {
+        for (v, pf) in col.iter_mut().zip(&factors) {
+            if let Some(v) = v.as_mut() {
+                *v *= pf;
+            }
+        }
+    }
+    if let Some(col) = &mut out.volume {
+        for (v, split) in col.iter_mut().zip(&cum_split_after) {
+            if let Some(v) = v.as_mut() {
+                #[allow(clippy::cast_precision_loss)]
+                let v_adj = (*v as f64) * split;
+                if v_adj.is_finite() && v_adj >= 0.0 {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    {
+                        *v = v_adj.round() as u64;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Drops rows where any returned price column has no value. Price columns Yahoo did not
+/// send at all are ignored.
+fn drop_missing_rows(out: &mut HistoryColumns) {
+    let prices: Vec<&Vec<Option<f64>>> =
+        [&out.open, &out.high, &out.low, &out.close, &out.adj_close]
+            .into_iter()
+            .flatten()
+            .filter(|col| !col.is_empty())
+            .collect();
+    let keep: Vec<bool> = (0..out.ts.len())
+        .map(|i| {
+            prices
+                .iter()
+                .all(|col| col.get(i).is_some_and(Option::is_some))
+        })
+        .collect();
+    if keep.iter().all(|k| *k) {
+        return;
+    }
+
+    retain_rows(&mut out.ts, &keep);
+    for col in [
+        &mut out.open,
+        &mut out.high,
+        &mut out.low,
+        &mut out.close,
+        &mut out.adj_close,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        retain_rows(col, &keep);
+    }
+    if let Some(col) = &mut out.volume {
+        retain_rows(col, &keep);
+    }
+}
+
+/// Keeps the entries of `col` whose row is marked in `keep`.
+fn retain_rows<T>(col: &mut Vec<T>, keep: &[bool]) {
+    let mut i = 0;
+    col.retain(|_| {
+        let k = keep.get(i).copied().unwrap_or(false);
+        i += 1;
+        k
+    });
+}
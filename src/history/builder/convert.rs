@@ -0,0 +1,61 @@
+use chrono::Duration;
+use paft::market::responses::history::Candle;
+use paft::money::{Currency, Money};
+use std::str::FromStr;
+
+use crate::core::client::{CacheMode, RetryConfig};
+use crate::core::conversions::{f64_to_money_with_currency, money_to_f64};
+use crate::core::{YfClient, YfError};
+use crate::fx::{self, FxBuilder, FxRate};
+
+/// Converts every candle (and the matching unadjusted close) into `target`.
+///
+/// Each bar uses the daily FX close on or before its timestamp; bars older than the first FX
+/// observation use that earliest rate.
+pub async fn convert_series(
+    client: &YfClient,
+    source: Option<&str>,
+    target: &str,
+    candles: &mut [Candle],
+    raw_close: &mut [Money],
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<(), YfError> {
+    let target_ccy = Currency::from_str(target.trim())
+        .map_err(|_| YfError::InvalidParams(format!("unknown currency: {target}")))?;
+    let source = source
+        .ok_or_else(|| YfError::MissingData("chart meta has no currency to convert from".into()))?;
+    let (Some(first), Some(last)) = (candles.first(), candles.last()) else {
+        return Ok(());
+    };
+
+    let rates = Box::pin(
+        FxBuilder::new(client, source, target)
+            .cache_mode(cache_mode)
+            .retry_policy(retry_override.cloned())
+            .between(first.ts - Duration::days(7), last.ts + Duration::days(1)),
+    )
+    .await?;
+    let earliest = rates.first().map(|r: &FxRate| r.rate).ok_or_else(|| {
+        YfError::MissingData(format!(
+            "no fx rates for {}",
+            fx::pair_symbol(source, target)
+        ))
+    })?;
+
+    let convert = |m: &Money, rate: f64| {
+        f64_to_money_with_currency(money_to_f64(m) * rate, target_ccy.clone())
+    };
+
+    for (i, c) in candles.iter_mut().enumerate() {
+        let rate = fx::rate_at(&rates, c.ts).unwrap_or(earliest);
+        c.open = convert(&c.open, rate);
+        c.high = convert(&c.high, rate);
+        c.low = convert(&c.low, rate);
+        c.close = convert(&c.close, rate);
+        if let Some(raw) = raw_close.get_mut(i) {
+            *raw = convert(raw, rate);
+        }
+    }
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use crate::core::client::{CacheMode, RetryConfig};
+use crate::core::client::{CacheMode, Endpoint, RetryConfig};
+use crate::core::conversions::minor_unit_major;
 use crate::history::wire::{Events, MetaNode, QuoteBlock};
 
 pub struct Fetched {
@@ -18,10 +19,41 @@ pub async fn fetch_chart(
     interval: crate::core::Interval,
     include_actions: bool,
     include_prepost: bool,
+    extra: &[(String, String)],
     cache_mode: CacheMode,
     retry_override: Option<&RetryConfig>,
 ) -> Result<Fetched, crate::core::YfError> {
-    let mut url = client.base_chart().join(symbol)?;
+    let body = fetch_chart_body(
+        client,
+        symbol,
+        range,
+        period,
+        interval,
+        include_actions,
+        include_prepost,
+        extra,
+        cache_mode,
+        retry_override,
+    )
+    .await?;
+    decode_chart(&body, client.normalize_minor_units())
+}
+
+/// Fetches the raw `/chart` body, honouring the cache and its HTTP validators.
+#[allow(clippy::too_many_arguments)]
+pub async fn fetch_chart_body(
+    client: &crate::core::YfClient,
+    symbol: &str,
+    range: Option<crate::core::Range>,
+    period: Option<(i64, i64)>,
+    interval: crate::core::Interval,
+    include_actions: bool,
+    include_prepost: bool,
+    extra: &[(String, String)],
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<String, crate::core::YfError> {
+    let mut url = client.symbol_url(Endpoint::Chart, symbol)?;
     {
         let mut qp = url.query_pairs_mut();
 
@@ -48,44 +80,40 @@ pub async fn fetch_chart(
             if include_prepost { "true" } else { "false" },
         );
     }
+    crate::core::net::append_extra_params(&mut url, extra);
 
     if cache_mode == CacheMode::Use
         && let Some(body) = client.cache_get(&url).await
     {
-        return decode_chart(&body);
+        return Ok(body);
     }
 
-    let resp = client
-        .send_with_retry(client.http().get(url.clone()), retry_override)
-        .await?;
+    let mut req = client.http().get(url.clone());
+    if cache_mode != CacheMode::Bypass {
+        req = client.with_cache_validators(&url, req).await;
+    }
+    let resp = client.send_with_retry(req, retry_override).await?;
+    if resp.status() == reqwest::StatusCode::NOT_MODIFIED
+        && let Some(body) = client.cache_revalidated(&url).await
+    {
+        return Ok(body);
+    }
     if !resp.status().is_success() {
-        let code = resp.status().as_u16();
-        let url_s = url.to_string();
-        return Err(match code {
-            404 => crate::core::YfError::NotFound { url: url_s },
-            429 => crate::core::YfError::RateLimited { url: url_s },
-            500..=599 => crate::core::YfError::ServerError {
-                status: code,
-                url: url_s,
-            },
-            _ => crate::core::YfError::Status {
-                status: code,
-                url: url_s,
-            },
-        });
+        return Err(crate::core::YfError::from_response(&resp, url.as_str()));
     }
 
+    let headers = resp.headers().clone();
     let body = crate::core::net::get_text(resp, "history_chart", symbol, "json").await?;
 
     if cache_mode != CacheMode::Bypass {
-        client.cache_put(&url, &body, None).await;
+        client.cache_put_validated(&url, &body, &headers).await;
     }
 
-    decode_chart(&body)
+    Ok(body)
 }
 
 // NEW helper to keep fetch_chart compact
-fn decode_chart(body: &str) -> Result<Fetched, crate::core::YfError> {
+fn decode_chart(body: &str, normalize_minor_units: bool) -> Result<Fetched, crate::core::YfError> {
     let envelope: crate::history::wire::ChartEnvelope =
         serde_json::from_str(body).map_err(crate::core::YfError::Json)?;
 
@@ -94,16 +122,19 @@ fn decode_chart(body: &str) -> Result<Fetched, crate::core::YfError> {
         .ok_or_else(|| crate::core::YfError::MissingData("missing chart".into()))?;
 
     if let Some(error) = chart.error {
-        return Err(crate::core::YfError::Api(format!(
-            "chart error: {} - {}",
-            error.code, error.description
-        )));
+        return Err(crate::core::YfError::api(
+            "chart error",
+            &error.code,
+            &error.description,
+        ));
     }
 
     let result = chart
         .result
         .ok_or_else(|| crate::core::YfError::MissingData("missing result".into()))?;
 
+    check_consistent_currency(result.iter().map(|r| r.meta.as_ref()))?;
+
     let first = result
         .first()
         .ok_or_else(|| crate::core::YfError::MissingData("empty result".into()))?;
@@ -120,11 +151,77 @@ fn decode_chart(body: &str) -> Result<Fetched, crate::core::YfError> {
         .map(|a| a.adjclose.clone())
         .unwrap_or_default();
 
-    Ok(Fetched {
+    let mut fetched = Fetched {
         ts: first.timestamp.clone().unwrap_or_default(),
         quote: quote.clone(),
         adjclose,
         events: first.events.clone(),
         meta: first.meta.clone(),
-    })
+    };
+    if normalize_minor_units {
+        fetched.normalize_minor_units();
+    }
+    Ok(fetched)
+}
+
+/// Rejects a chart payload whose result blocks report different `meta.currency` values.
+///
+/// A re-denomination or ADR change can make Yahoo stitch segments priced in different
+/// currencies into one response; treating them as one series would silently corrupt
+/// returns and conversions. Blocks without a currency are ignored.
+///
+/// This only catches a change Yahoo reports as separate result blocks. The chart carries no
+/// per-candle currency, so a single block whose earlier prices Yahoo left in the old
+/// currency cannot be detected here.
+pub fn check_consistent_currency<'a>(
+    metas: impl IntoIterator<Item = Option<&'a MetaNode>>,
+) -> Result<(), crate::core::YfError> {
+    let mut currencies = metas
+        .into_iter()
+        .filter_map(|m| m.and_then(|m| m.currency.as_deref()));
+    let Some(first) = currencies.next() else {
+        return Ok(());
+    };
+    match currencies.find(|c| *c != first) {
+        Some(other) => Err(crate::core::YfError::MissingData(format!(
+            "chart currency changes within the series: {first} -> {other}"
+        ))),
+        None => Ok(()),
+    }
+}
+
+impl Fetched {
+    /// Restates prices quoted in a minor unit (e.g. `GBp` pence) in the major currency.
+    /// Corporate actions are left as reported.
+    fn normalize_minor_units(&mut self) {
+        let Some(meta) = self.meta.as_mut() else {
+            return;
+        };
+        let Some(major) = meta.currency.as_deref().and_then(minor_unit_major) else {
+            return;
+        };
+        meta.currency = Some(major.to_string());
+        for price in [
+            &mut meta.previous_close,
+            &mut meta.chart_previous_close,
+            &mut meta.regular_market_price,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *price /= 100.0;
+        }
+        let q = &mut self.quote;
+        for price in q
+            .open
+            .iter_mut()
+            .chain(q.high.iter_mut())
+            .chain(q.low.iter_mut())
+            .chain(q.close.iter_mut())
+            .chain(self.adjclose.iter_mut())
+            .flatten()
+        {
+            *price /= 100.0;
+        }
+    }
 }
@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use paft::market::responses::history::HistoryMeta;
+use serde::Serialize;
+
+/// The price columns [`HistoryBuilder::fetch_columns`](crate::HistoryBuilder::fetch_columns)
+/// keeps. Timestamps are always returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ColumnSet {
+    /// Open prices.
+    pub open: bool,
+    /// High prices.
+    pub high: bool,
+    /// Low prices.
+    pub low: bool,
+    /// Close prices.
+    pub close: bool,
+    /// Yahoo's split- and dividend-adjusted close.
+    pub adj_close: bool,
+    /// Volumes.
+    pub volume: bool,
+}
+
+impl ColumnSet {
+    /// Every column.
+    pub const ALL: Self = Self {
+        open: true,
+        high: true,
+        low: true,
+        close: true,
+        adj_close: true,
+        volume: true,
+    };
+    /// OHLC and volume, without the adjusted close.
+    pub const RAW: Self = Self {
+        adj_close: false,
+        ..Self::ALL
+    };
+    /// Only the adjusted close, e.g. for return matrices over many symbols.
+    pub const ADJ_CLOSE: Self = Self {
+        open: false,
+        high: false,
+        low: false,
+        close: false,
+        adj_close: true,
+        volume: false,
+    };
+}
+
+impl Default for ColumnSet {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Price history stored column by column, holding only the columns that were requested.
+///
+/// Prices are plain `f64` in [`currency`](Self::currency). The open, high, low and close
+/// columns follow the builder's [`auto_adjust`](crate::HistoryBuilder::auto_adjust) setting;
+/// no repair or currency conversion is applied. A missing value is `None` (kept only with
+/// [`keepna`](crate::HistoryBuilder::keepna)). Columns that were not requested are `None`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistoryColumns {
+    /// Bar timestamps, oldest first.
+    pub ts: Vec<DateTime<Utc>>,
+    /// Currency code of the prices, if Yahoo reported one.
+    pub currency: Option<String>,
+    /// Timezone metadata of the series.
+    pub meta: Option<HistoryMeta>,
+    /// Open prices.
+    pub open: Option<Vec<Option<f64>>>,
+    /// High prices.
+    pub high: Option<Vec<Option<f64>>>,
+    /// Low prices.
+    pub low: Option<Vec<Option<f64>>>,
+    /// Close prices.
+    pub close: Option<Vec<Option<f64>>>,
+    /// Adjusted close prices.
+    pub adj_close: Option<Vec<Option<f64>>>,
+    /// Volumes.
+    pub volume: Option<Vec<Option<u64>>>,
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc, Weekday};
+
+use super::annotate::{AnnotatedCandle, annotate};
+use crate::core::conversions::money_to_f64;
+use crate::core::{Action, HistoryResponse};
+
+/// Convenience analytics over a fetched [`HistoryResponse`].
+///
+/// `HistoryResponse` is defined in `paft`, so these helpers are provided as an
+/// extension trait. Bring it into scope with `use yfinance_rs::HistoryResponseExt;`.
+pub trait HistoryResponseExt {
+    /// Returns the volume-weighted average price over the loaded candles.
+    ///
+    /// Each candle contributes its typical price `(high + low + close) / 3` weighted by
+    /// its volume. Candles without volume are ignored. Returns `None` when the total
+    /// volume is zero.
+    fn vwap(&self) -> Option<f64>;
+
+    /// Returns the weekdays between the first and last candle that have no candle, e.g. to
+    /// flag an incomplete download.
+    ///
+    /// Dates are taken in the exchange timezone from the response metadata (UTC if absent).
+    /// Holidays are excluded heuristically: only weekends and 1 January / 25 December are
+    /// skipped, so exchange-specific holidays are reported too. Meant for daily or intraday
+    /// series; weekly and monthly candles naturally leave gaps.
+    fn gaps(&self) -> Vec<NaiveDate>;
+
+    /// Returns the candles with each dividend and split attached to the candle whose period
+    /// contains it, e.g. to mark events on a price chart.
+    ///
+    /// Dates are compared in the exchange timezone (UTC if absent) and an event goes to the
+    /// last candle dated on or before it, so weekly and monthly candles collect the events of
+    /// their period; several dividends in one period are summed. Events before the first
+    /// candle are left out, and nothing is attached unless the response was fetched with
+    /// actions.
+    fn annotated_candles(&self) -> Vec<AnnotatedCandle>;
+
+    /// Sorts candles and actions by timestamp and drops duplicates, keeping the latest
+    /// occurrence (the one furthest along in the vectors) of each.
+    ///
+    /// Candles are duplicates when they share a timestamp; actions when they share a
+    /// timestamp and kind. `unadjusted_close` is kept aligned with the candles.
+    fn dedup_timestamps(&mut self);
+
+    /// Appends a newer, possibly overlapping response and deduplicates the result, so
+    /// values from `newer` win wherever both have the same timestamp.
+    ///
+    /// `newer`'s metadata replaces `self`'s when present. `unadjusted_close` is kept only
+    /// if both sides carry it.
+    fn stitch(&mut self, newer: Self);
+}
+
+/// Sorts by `key` and keeps the last of each run of equal keys, preserving input order
+/// otherwise.
+fn keep_latest<T, K: Ord>(items: &mut Vec<T>, key: impl Fn(&T) -> K) {
+    items.reverse();
+    items.sort_by_key(&key);
+    items.dedup_by(|later, earlier| key(later) == key(earlier));
+}
+
+fn action_key(action: &Action) -> (DateTime<Utc>, u8) {
+    match action {
+        Action::Dividend { ts, .. } => (*ts, 0),
+        Action::Split { ts, .. } => (*ts, 1),
+        Action::CapitalGain { ts, .. } => (*ts, 2),
+    }
+}
+
+/// Whether `date` is expected to be a trading day on most exchanges.
+fn expected_trading_day(date: NaiveDate) -> bool {
+    let weekend = matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+    let closed_holiday = matches!((date.month(), date.day()), (1, 1) | (12, 25));
+    !weekend && !closed_holiday
+}
+
+impl HistoryResponseExt for HistoryResponse {
+    fn vwap(&self) -> Option<f64> {
+        let mut pv = 0.0;
+        let mut total_volume = 0u64;
+
+        for c in &self.candles {
+            let Some(v) = c.volume.filter(|v| *v > 0) else {
+                continue;
+            };
+            let typical =
+                (money_to_f64(&c.high) + money_to_f64(&c.low) + money_to_f64(&c.close)) / 3.0;
+            #[allow(clippy::cast_precision_loss)]
+            let vf = v as f64;
+            pv += typical * vf;
+            total_volume = total_volume.saturating_add(v);
+        }
+
+        if total_volume == 0 {
+            return None;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        Some(pv / total_volume as f64)
+    }
+
+    fn gaps(&self) -> Vec<NaiveDate> {
+        let tz = self.meta.as_ref().and_then(|m| m.timezone);
+        let dates: BTreeSet<NaiveDate> = self
+            .candles
+            .iter()
+            .map(|c| {
+                tz.map_or_else(
+                    || c.ts.date_naive(),
+                    |tz| c.ts.with_timezone(&tz).date_naive(),
+                )
+            })
+            .collect();
+        let (Some(&first), Some(&last)) = (dates.first(), dates.last()) else {
+            return Vec::new();
+        };
+
+        first
+            .iter_days()
+            .take_while(|d| *d <= last)
+            .filter(|d| expected_trading_day(*d) && !dates.contains(d))
+            .collect()
+    }
+
+    fn annotated_candles(&self) -> Vec<AnnotatedCandle> {
+        annotate(self)
+    }
+
+    fn dedup_timestamps(&mut self) {
+        match self.unadjusted_close.take() {
+            Some(raw) if raw.len() == self.candles.len() => {
+                let mut rows: Vec<_> = self.candles.drain(..).zip(raw).collect();
+                keep_latest(&mut rows, |(c, _)| c.ts);
+                let (candles, raw) = rows.into_iter().unzip();
+                self.candles = candles;
+                self.unadjusted_close = Some(raw);
+            }
+            raw => {
+                self.unadjusted_close = raw;
+                keep_latest(&mut self.candles, |c| c.ts);
+            }
+        }
+        keep_latest(&mut self.actions, action_key);
+    }
+
+    fn stitch(&mut self, newer: Self) {
+        self.unadjusted_close = match (self.unadjusted_close.take(), newer.unadjusted_close) {
+            (Some(mut old), Some(new))
+                if old.len() == self.candles.len() && new.len() == newer.candles.len() =>
+            {
+                old.extend(new);
+                Some(old)
+            }
+            _ => None,
+        };
+        self.candles.extend(newer.candles);
+        self.actions.extend(newer.actions);
+        if newer.meta.is_some() {
+            self.meta = newer.meta;
+        }
+        self.dedup_timestamps();
+    }
+}
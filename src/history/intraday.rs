@@ -0,0 +1,34 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::{Candle, Interval};
+
+/// Returns the longest lookback Yahoo serves for an intraday `interval`, or `None` for daily
+/// and longer intervals.
+///
+/// Requests reaching further back are truncated or rejected by Yahoo: `1m` candles cover the
+/// last 7 days, `2m` to `90m` the last 60 days, and `1h` the last 730 days.
+#[must_use]
+pub const fn max_intraday_lookback(interval: Interval) -> Option<Duration> {
+    match interval {
+        Interval::I1m => Some(Duration::days(7)),
+        Interval::I2m | Interval::I5m | Interval::I15m | Interval::I30m | Interval::I90m => {
+            Some(Duration::days(60))
+        }
+        Interval::I1h => Some(Duration::days(730)),
+        Interval::D1 | Interval::D5 | Interval::W1 | Interval::M1 | Interval::M3 => None,
+    }
+}
+
+/// Intraday candles together with the window they were requested for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntradayHistory {
+    /// The interval of each candle.
+    pub interval: Interval,
+    /// Start of the requested window (`period1`).
+    pub start: DateTime<Utc>,
+    /// End of the requested window (`period2`), the time of the request.
+    pub end: DateTime<Utc>,
+    /// The candles, oldest first.
+    pub candles: Vec<Candle>,
+}
@@ -1,7 +1,22 @@
+mod annotate;
+#[cfg(feature = "arrow")]
+mod arrow;
 mod builder;
-mod wire;
+mod columns;
+mod ext;
+mod intraday;
+mod returns;
+pub(crate) mod wire;
 
-pub use builder::HistoryBuilder;
+pub use annotate::{AnnotatedCandle, Split};
+#[cfg(feature = "arrow")]
+pub use arrow::ToRecordBatch;
+pub(crate) use builder::fetch_chart_meta;
+pub use builder::{HistoryBound, HistoryBuilder};
+pub use columns::{ColumnSet, HistoryColumns};
+pub use ext::HistoryResponseExt;
+pub use intraday::{IntradayHistory, max_intraday_lookback};
+pub use returns::ReturnsSummary;
 
 use crate::core::{HistoryRequest, HistoryResponse, HistoryService, YfClient, YfError};
 use core::future::Future;
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::conversions::money_to_f64;
+use crate::core::{Candle, YfError};
+
+/// Trading days per year used to annualize daily statistics.
+const TRADING_DAYS: f64 = 252.0;
+
+/// Return and risk statistics over a series of daily closes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReturnsSummary {
+    /// Number of daily log returns the statistics are based on.
+    pub periods: usize,
+    /// Geometric annualized return: `exp(mean(log returns) × 252) − 1`.
+    pub annualized_return: f64,
+    /// Annualized volatility: sample standard deviation of daily log returns `× √252`.
+    pub volatility: f64,
+    /// Largest peak-to-trough decline of the close, as a positive fraction (`0.1` = 10%).
+    pub max_drawdown: f64,
+    /// `(annualized_return − risk_free_rate) / volatility`, or `None` when volatility is zero.
+    pub sharpe: Option<f64>,
+}
+
+impl ReturnsSummary {
+    /// Computes the summary from daily candles ordered oldest first.
+    ///
+    /// Candles with a non-finite or non-positive close are skipped. `risk_free_rate` is an
+    /// annual rate as a fraction (e.g. `0.04`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::MissingData` if fewer than three usable closes remain, since the
+    /// volatility needs at least two returns.
+    pub fn from_candles(candles: &[Candle], risk_free_rate: f64) -> Result<Self, YfError> {
+        let closes: Vec<f64> = candles
+            .iter()
+            .map(|c| money_to_f64(&c.close))
+            .filter(|c| c.is_finite() && *c > 0.0)
+            .collect();
+        if closes.len() < 3 {
+            return Err(YfError::MissingData(format!(
+                "returns summary needs at least 3 closes, got {}",
+                closes.len()
+            )));
+        }
+
+        let returns: Vec<f64> = closes.windows(2).map(|w| (w[1] / w[0]).ln()).collect();
+        #[allow(clippy::cast_precision_loss)]
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+        let annualized_return = (mean * TRADING_DAYS).exp() - 1.0;
+        let volatility = variance.sqrt() * TRADING_DAYS.sqrt();
+
+        let mut peak = closes[0];
+        let mut max_drawdown = 0.0_f64;
+        for &c in &closes {
+            peak = peak.max(c);
+            max_drawdown = max_drawdown.max((peak - c) / peak);
+        }
+
+        let sharpe = (volatility > 0.0).then(|| (annualized_return - risk_free_rate) / volatility);
+
+        Ok(Self {
+            periods: returns.len(),
+            annualized_return,
+            volatility,
+            max_drawdown,
+            sharpe,
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use crate::core::wire::YahooDate;
 use serde::Deserialize;
 use serde::Deserializer;
 use std::collections::BTreeMap;
@@ -32,12 +33,31 @@ pub struct ChartResult {
 
 #[derive(Deserialize, Clone)]
 pub struct MetaNode {
+    #[serde(default)]
+    pub(crate) symbol: Option<String>,
     #[serde(default)]
     pub(crate) timezone: Option<String>,
     #[serde(default)]
     pub(crate) gmtoffset: Option<i64>,
     #[serde(default)]
     pub(crate) currency: Option<String>,
+    #[serde(default, rename = "previousClose")]
+    pub(crate) previous_close: Option<f64>,
+    #[serde(default, rename = "chartPreviousClose")]
+    pub(crate) chart_previous_close: Option<f64>,
+    #[serde(default, rename = "regularMarketPrice")]
+    pub(crate) regular_market_price: Option<f64>,
+    #[serde(default, rename = "fullExchangeName")]
+    pub(crate) full_exchange_name: Option<String>,
+    #[serde(default, rename = "exchangeName")]
+    pub(crate) exchange_name: Option<String>,
+}
+
+impl MetaNode {
+    /// The prior session's close: `previousClose`, or `chartPreviousClose` when Yahoo omits it.
+    pub(crate) fn last_close(&self) -> Option<f64> {
+        self.previous_close.or(self.chart_previous_close)
+    }
 }
 
 #[derive(Deserialize)]
@@ -81,7 +101,7 @@ pub struct Events {
 #[derive(Deserialize, Clone)]
 pub struct DividendEvent {
     pub(crate) amount: Option<f64>,
-    pub(crate) date: Option<i64>,
+    pub(crate) date: Option<YahooDate>,
 }
 
 #[derive(Deserialize, Clone)]
@@ -92,13 +112,13 @@ pub struct SplitEvent {
     pub(crate) denominator: Option<u64>,
     #[serde(rename = "splitRatio")]
     pub(crate) split_ratio: Option<String>,
-    pub(crate) date: Option<i64>,
+    pub(crate) date: Option<YahooDate>,
 }
 
 #[derive(Deserialize, Clone)]
 pub struct CapitalGainEvent {
     pub(crate) amount: Option<f64>,
-    pub(crate) date: Option<i64>,
+    pub(crate) date: Option<YahooDate>,
 }
 
 /// Accepts u64, integer-like f64 (e.g., 4.0), numeric strings ("4"), or null/missing.
@@ -1,19 +1,17 @@
 use super::model::{
-    InsiderRosterHolder, InsiderTransaction, InstitutionalHolder, MajorHolder,
+    Holders, InsiderRosterHolder, InsiderTransaction, InstitutionalHolder, MajorHolder,
     NetSharePurchaseActivity,
 };
-use super::wire::V10Result;
-use crate::core::wire::{from_raw, from_raw_date};
+use super::wire::{InsiderHoldersNode, MajorHoldersBreakdownNode, OwnershipNode, V10Result};
+use crate::core::wire::{from_raw, from_yahoo_date, from_yahoo_number};
 use crate::core::{
     YfClient, YfError,
     client::{CacheMode, RetryConfig},
     conversions::{
-        i64_to_datetime, string_to_insider_position, string_to_transaction_type,
-        u64_to_money_with_currency,
+        string_to_insider_position, string_to_transaction_type, u64_to_money_with_currency,
     },
     quotesummary,
 };
-use chrono::DateTime;
 use paft::money::Currency;
 
 #[inline]
@@ -52,21 +50,25 @@ pub(super) async fn major_holders(
         .major_holders_breakdown
         .ok_or_else(|| YfError::MissingData("majorHoldersBreakdown missing".into()))?;
 
+    Ok(map_major_holders(breakdown))
+}
+
+fn map_major_holders(breakdown: MajorHoldersBreakdownNode) -> Vec<MajorHolder> {
     let mut result = Vec::new();
 
-    if let Some(v) = from_raw(breakdown.insiders_percent_held) {
+    if let Some(v) = from_yahoo_number(breakdown.insiders_percent_held) {
         result.push(MajorHolder {
             category: "% of Shares Held by All Insiders".into(),
             value: v,
         });
     }
-    if let Some(v) = from_raw(breakdown.institutions_percent_held) {
+    if let Some(v) = from_yahoo_number(breakdown.institutions_percent_held) {
         result.push(MajorHolder {
             category: "% of Shares Held by Institutions".into(),
             value: v,
         });
     }
-    if let Some(v) = from_raw(breakdown.institutions_float_percent_held) {
+    if let Some(v) = from_yahoo_number(breakdown.institutions_float_percent_held) {
         result.push(MajorHolder {
             category: "% of Float Held by Institutions".into(),
             value: v,
@@ -79,11 +81,11 @@ pub(super) async fn major_holders(
         });
     }
 
-    Ok(result)
+    result
 }
 
 fn map_ownership_list(
-    node: Option<super::wire::OwnershipNode>,
+    node: Option<OwnershipNode>,
     currency: &Currency,
 ) -> Vec<InstitutionalHolder> {
     node.and_then(|n| n.ownership_list)
@@ -92,11 +94,8 @@ fn map_ownership_list(
         .map(|h| InstitutionalHolder {
             holder: h.organization.unwrap_or_default(),
             shares: from_raw(h.shares),
-            date_reported: from_raw_date(h.date_reported).map_or_else(
-                || DateTime::from_timestamp(0, 0).unwrap_or_default(),
-                i64_to_datetime,
-            ),
-            pct_held: from_raw(h.pct_held),
+            date_reported: from_yahoo_date(h.date_reported).unwrap_or_default(),
+            pct_held: from_yahoo_number(h.pct_held),
             value: from_raw(h.value).map(|v| u64_to_money_with_currency(v, currency.clone())),
         })
         .collect()
@@ -145,10 +144,7 @@ pub(super) async fn insider_transactions(
             transaction_type: string_to_transaction_type(&t.transaction.unwrap_or_default()),
             shares: from_raw(t.shares),
             value: from_raw(t.value).map(|v| u64_to_money_with_currency(v, currency.clone())),
-            transaction_date: from_raw_date(t.start_date).map_or_else(
-                || DateTime::from_timestamp(0, 0).unwrap_or_default(),
-                i64_to_datetime,
-            ),
+            transaction_date: from_yahoo_date(t.start_date).unwrap_or_default(),
             url: t.url.unwrap_or_default(),
         })
         .collect())
@@ -161,12 +157,12 @@ pub(super) async fn insider_roster_holders(
     retry_override: Option<&RetryConfig>,
 ) -> Result<Vec<InsiderRosterHolder>, YfError> {
     let root = fetch_holders_modules(client, symbol, cache_mode, retry_override).await?;
-    let holders = root
-        .insider_holders
-        .and_then(|ih| ih.holders)
-        .unwrap_or_default();
+    Ok(map_insider_roster(root.insider_holders))
+}
 
-    Ok(holders
+fn map_insider_roster(node: Option<InsiderHoldersNode>) -> Vec<InsiderRosterHolder> {
+    node.and_then(|ih| ih.holders)
+        .unwrap_or_default()
         .into_iter()
         .map(|h| InsiderRosterHolder {
             name: h.name.unwrap_or_default(),
@@ -174,17 +170,62 @@ pub(super) async fn insider_roster_holders(
             most_recent_transaction: string_to_transaction_type(
                 &h.most_recent_transaction.unwrap_or_default(),
             ),
-            latest_transaction_date: from_raw_date(h.latest_transaction_date).map_or_else(
-                || DateTime::from_timestamp(0, 0).unwrap_or_default(),
-                i64_to_datetime,
-            ),
+            latest_transaction_date: from_yahoo_date(h.latest_transaction_date).unwrap_or_default(),
             shares_owned_directly: from_raw(h.shares_owned_directly),
-            position_direct_date: from_raw_date(h.position_direct_date).map_or_else(
-                || DateTime::from_timestamp(0, 0).unwrap_or_default(),
-                i64_to_datetime,
-            ),
+            position_direct_date: from_yahoo_date(h.position_direct_date).unwrap_or_default(),
         })
-        .collect())
+        .collect()
+}
+
+/// Decodes one module of a raw quoteSummary result, recording a parse failure instead of
+/// failing the whole response.
+fn section<T>(root: &serde_json::Value, key: &str, errors: &mut Vec<(String, YfError)>) -> Option<T>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    let value = root.get(key)?;
+    match serde_json::from_value(value.clone()) {
+        Ok(node) => Some(node),
+        Err(e) => {
+            errors.push((key.to_string(), YfError::Json(e)));
+            None
+        }
+    }
+}
+
+pub(super) async fn holders(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Holders, YfError> {
+    let root: serde_json::Value = quotesummary::fetch_module_result(
+        client,
+        symbol,
+        MODULES,
+        "holders",
+        cache_mode,
+        retry_override,
+    )
+    .await?;
+    let currency = client.reporting_currency(symbol, None).await;
+
+    let mut errors = Vec::new();
+    let major = section::<MajorHoldersBreakdownNode>(&root, "majorHoldersBreakdown", &mut errors)
+        .map(map_major_holders)
+        .unwrap_or_default();
+    let institutional = map_ownership_list(
+        section(&root, "institutionOwnership", &mut errors),
+        &currency,
+    );
+    let insider_roster = map_insider_roster(section(&root, "insiderHolders", &mut errors));
+
+    Ok(Holders {
+        major,
+        institutional,
+        insider_roster,
+        errors,
+    })
 }
 
 pub(super) async fn net_share_purchase_activity(
@@ -205,6 +246,6 @@ pub(super) async fn net_share_purchase_activity(
             net_shares: from_raw(n.net_info_shares),
             net_count: from_raw(n.net_info_count),
             total_insider_shares: from_raw(n.total_insider_shares),
-            net_percent_insider_shares: from_raw(n.net_percent_insider_shares),
+            net_percent_insider_shares: from_yahoo_number(n.net_percent_insider_shares),
         }))
 }
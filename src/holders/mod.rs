@@ -3,7 +3,7 @@ mod model;
 mod wire;
 
 pub use model::{
-    InsiderRosterHolder, InsiderTransaction, InstitutionalHolder, MajorHolder,
+    Holders, InsiderRosterHolder, InsiderTransaction, InstitutionalHolder, MajorHolder,
     NetSharePurchaseActivity,
 };
 
@@ -45,6 +45,23 @@ impl HoldersBuilder {
         self
     }
 
+    /// Fetches major, institutional and insider roster holders with one request.
+    ///
+    /// A section that fails to parse is returned empty, with its error in [`Holders::errors`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `YfError` if the network request fails or the response has no result.
+    pub async fn all(&self) -> Result<Holders, YfError> {
+        api::holders(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
     /// Fetches the major holders breakdown (e.g., % insiders, % institutions).
     ///
     /// # Errors
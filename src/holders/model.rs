@@ -3,3 +3,22 @@ pub use paft::fundamentals::holders::{
     InsiderRosterHolder, InsiderTransaction, InstitutionalHolder, MajorHolder,
     NetSharePurchaseActivity,
 };
+
+use crate::YfError;
+
+/// Major, institutional and insider holders for a symbol, fetched in a single request.
+///
+/// A section that fails to parse is left empty and its error recorded in `errors`, so one
+/// malformed module does not hide the others.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct Holders {
+    /// The major holders breakdown (e.g., % insiders, % institutions).
+    pub major: Vec<MajorHolder>,
+    /// The top institutional holders.
+    pub institutional: Vec<InstitutionalHolder>,
+    /// Company insiders and their holdings.
+    pub insider_roster: Vec<InsiderRosterHolder>,
+    /// Sections that could not be parsed, keyed by quoteSummary module name.
+    pub errors: Vec<(String, YfError)>,
+}
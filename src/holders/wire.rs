@@ -1,4 +1,4 @@
-use crate::core::wire::{RawDate, RawNum};
+use crate::core::wire::{RawNum, YahooDate, YahooNumber};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -29,20 +29,20 @@ pub struct InstitutionalHolderNode {
     #[serde(rename = "position")]
     pub(crate) shares: Option<RawNum<u64>>,
     #[serde(rename = "reportDate")]
-    pub(crate) date_reported: Option<RawDate>,
+    pub(crate) date_reported: Option<YahooDate>,
     #[serde(rename = "pctHeld")]
-    pub(crate) pct_held: Option<RawNum<f64>>,
+    pub(crate) pct_held: Option<YahooNumber>,
     pub(crate) value: Option<RawNum<u64>>,
 }
 
 #[derive(Deserialize)]
 pub struct MajorHoldersBreakdownNode {
     #[serde(rename = "insidersPercentHeld")]
-    pub(crate) insiders_percent_held: Option<RawNum<f64>>,
+    pub(crate) insiders_percent_held: Option<YahooNumber>,
     #[serde(rename = "institutionsPercentHeld")]
-    pub(crate) institutions_percent_held: Option<RawNum<f64>>,
+    pub(crate) institutions_percent_held: Option<YahooNumber>,
     #[serde(rename = "institutionsFloatPercentHeld")]
-    pub(crate) institutions_float_percent_held: Option<RawNum<f64>>,
+    pub(crate) institutions_float_percent_held: Option<YahooNumber>,
     #[serde(rename = "institutionsCount")]
     pub(crate) institutions_count: Option<RawNum<u64>>,
 }
@@ -63,7 +63,7 @@ pub struct InsiderTransactionNode {
     pub(crate) shares: Option<RawNum<u64>>,
     pub(crate) value: Option<RawNum<u64>>,
     #[serde(rename = "startDate")]
-    pub(crate) start_date: Option<RawDate>,
+    pub(crate) start_date: Option<YahooDate>,
     #[serde(rename = "filerUrl")]
     pub(crate) url: Option<String>,
 }
@@ -80,11 +80,11 @@ pub struct InsiderRosterHolderNode {
     #[serde(rename = "transactionDescription")]
     pub(crate) most_recent_transaction: Option<String>,
     #[serde(rename = "latestTransDate")]
-    pub(crate) latest_transaction_date: Option<RawDate>,
+    pub(crate) latest_transaction_date: Option<YahooDate>,
     #[serde(rename = "positionDirect")]
     pub(crate) shares_owned_directly: Option<RawNum<u64>>,
     #[serde(rename = "positionDirectDate")]
-    pub(crate) position_direct_date: Option<RawDate>,
+    pub(crate) position_direct_date: Option<YahooDate>,
 }
 
 #[derive(Deserialize)]
@@ -105,5 +105,5 @@ pub struct NetSharePurchaseActivityNode {
     #[serde(rename = "totalInsiderShares")]
     pub(crate) total_insider_shares: Option<RawNum<u64>>,
     #[serde(rename = "netPercentInsiderShares")]
-    pub(crate) net_percent_insider_shares: Option<RawNum<f64>>,
+    pub(crate) net_percent_insider_shares: Option<YahooNumber>,
 }
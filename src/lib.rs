@@ -130,6 +130,8 @@ pub mod download;
 pub mod esg;
 /// Fetch financial statements (income, balance sheet, cash flow) and earnings data.
 pub mod fundamentals;
+/// Fetch foreign-exchange rates between currencies.
+pub mod fx;
 /// Fetch historical OHLCV data for a single symbol.
 pub mod history;
 /// Fetch holder information, including major, institutional, and insider holders.
@@ -149,20 +151,41 @@ pub mod ticker;
 
 // --- re-exports (public API remains the same names as before) ---
 // Core types that are provider-specific
+#[cfg(feature = "fault-injection")]
+pub use core::FaultConfig;
 pub use core::client::ApiPreference;
-pub use core::{CacheMode, RetryConfig, YfClient, YfClientBuilder, YfError};
+pub use core::{
+    CacheMode, ClientMetrics, Clock, DefaultPathResolver, Endpoint, PathResolver,
+    QuoteSummaryModule, RetryConfig, SystemClock, YahooErrorCode, YahooHost, YfClient,
+    YfClientBuilder, YfError,
+};
 
 // Provider-specific builders and utilities
-pub use download::{DownloadBuilder, DownloadResult};
+pub use download::{AlignedHistory, DownloadBuilder, DownloadResult};
 pub use esg::EsgBuilder;
 pub use fundamentals::FundamentalsBuilder;
-pub use history::HistoryBuilder;
+pub use fx::{FxBuilder, FxRate, RateBasis};
+#[cfg(feature = "arrow")]
+pub use history::ToRecordBatch;
+pub use history::{
+    AnnotatedCandle, ColumnSet, HistoryBound, HistoryBuilder, HistoryColumns, HistoryResponseExt,
+    IntradayHistory, ReturnsSummary, Split, max_intraday_lookback,
+};
 pub use holders::HoldersBuilder;
 pub use news::{NewsBuilder, NewsTab};
-pub use quote::{QuotesBuilder, quotes};
-pub use search::{SearchBuilder, search};
-pub use stream::{StreamBuilder, StreamConfig, StreamHandle, StreamMethod};
-pub use ticker::{FastInfo, Info, Ticker};
+pub use quote::{
+    FullQuote, FuturesDetails, PricePreference, QuoteDetails, QuoteDiff, QuoteExt,
+    QuoteFieldPreset, QuotesBuilder, SessionPrice, bottom_n, quotes, rank_by_change_percent, top_n,
+};
+pub use search::{SearchBuilder, search, search_many};
+pub use stream::{
+    ChangeThreshold, Overflow, StreamBuilder, StreamConfig, StreamHandle, StreamMethod,
+};
+pub use ticker::{
+    Alert, Beta, BetaSource, Dividend, DividendConversion, DividendYieldPoint, DividendsBuilder,
+    FastInfo, Freshness, Info, IvSurface, KeyStatistics, MarketCapPoint, OptionChainBuilder,
+    SummaryDetail, Ticker,
+};
 
 // Explicitly re-export selected paft core types commonly used by users of this crate
 pub use crate::core::{Action, Candle, HistoryMeta, HistoryResponse, Quote};
@@ -50,20 +50,7 @@ pub(super) async fn fetch_news(
     let resp = client.send_with_retry(req, retry_override).await?;
 
     if !resp.status().is_success() {
-        let code = resp.status().as_u16();
-        let url_s = resp.url().to_string();
-        return Err(match code {
-            404 => YfError::NotFound { url: url_s },
-            429 => YfError::RateLimited { url: url_s },
-            500..=599 => YfError::ServerError {
-                status: code,
-                url: url_s,
-            },
-            _ => YfError::Status {
-                status: code,
-                url: url_s,
-            },
-        });
+        return Err(YfError::from_response(&resp, resp.url().as_str()));
     }
 
     let endpoint = format!("news_{}", tab_as_str(tab));
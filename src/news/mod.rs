@@ -30,6 +30,18 @@ pub(crate) const fn tab_as_str(tab: NewsTab) -> &'static str {
     }
 }
 
+/// Returns `true` if the article looks like an earnings call transcript, judged by its title or
+/// link.
+pub(crate) fn is_earnings_transcript(article: &NewsArticle) -> bool {
+    let title = article.title.to_ascii_lowercase();
+    let link = article
+        .link
+        .as_deref()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    title.contains("transcript") || link.contains("transcript")
+}
+
 /// A builder for fetching news articles for a specific symbol.
 pub struct NewsBuilder {
     client: YfClient,
@@ -1,5 +1,6 @@
 //! Scrape the Yahoo quote HTML and extract profile data.
 
+use crate::core::client::Endpoint;
 use crate::{YfClient, YfError};
 use paft::domain::Isin;
 use serde::Deserialize;
@@ -17,10 +18,10 @@ use extract::extract_bootstrap_json;
 pub async fn load_from_scrape(client: &YfClient, symbol: &str) -> Result<Profile, YfError> {
     let debug = std::env::var("YF_DEBUG").ok().as_deref() == Some("1");
 
-    let mut url = client.base_quote().join(symbol)?;
+    let mut url = client.symbol_url(Endpoint::QuotePage, symbol)?;
     {
         let mut qp = url.query_pairs_mut();
-        qp.append_pair("p", symbol);
+        qp.append_pair("p", client.yahoo_symbol(symbol));
     }
 
     let body = if let Some(body) = client.cache_get(&url).await {
@@ -31,7 +32,7 @@ pub async fn load_from_scrape(client: &YfClient, symbol: &str) -> Result<Profile
         if !quote_page_resp.status().is_success() {
             return Err(YfError::Status {
                 status: quote_page_resp.status().as_u16(),
-                url: url.to_string(),
+                url: crate::core::error::response_url(&quote_page_resp, url.as_str()),
             });
         }
         let body =
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::core::Quote;
+use crate::core::conversions::i64_to_datetime;
+use crate::core::quotes::{V7QuoteNode, V7QuoteNodeWithExtras};
+
+/// A [`Quote`] together with v7 fields that the shared `paft` model does not carry.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct QuoteDetails {
+    /// The standard quote.
+    pub quote: Quote,
+    /// Full instrument name (`longName`); `quote.shortname` carries the abbreviated one.
+    pub long_name: Option<String>,
+    /// Yahoo's display name (`displayName`), often a cleaner company name such as "Apple".
+    /// Use [`display_name()`](Self::display_name) for a label with fallbacks.
+    pub display_name: Option<String>,
+    /// Instrument type as reported by Yahoo (e.g. "EQUITY", "FUTURE", "ETF").
+    pub quote_type: Option<String>,
+    /// Human-readable instrument type (`typeDisp`, e.g. "Equity", "Futures").
+    pub type_disp: Option<String>,
+    /// When the regular-market price was last updated (`regularMarketTime`).
+    pub regular_market_time: Option<DateTime<Utc>>,
+    /// Contract details, present only when `quote_type` is "FUTURE".
+    pub futures: Option<FuturesDetails>,
+    /// Raw v7 fields this crate does not model, keyed by Yahoo's field name.
+    ///
+    /// Only populated when requested with
+    /// [`QuotesBuilder::extras`](crate::QuotesBuilder::extras); `None` otherwise.
+    pub extras: Option<HashMap<String, Value>>,
+}
+
+/// Futures-specific fields of a v7 quote (e.g. for `ES=F`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FuturesDetails {
+    /// The specific contract, when Yahoo reports one as a symbol.
+    pub contract_symbol: Option<String>,
+    /// The continuous/underlying symbol the contract belongs to.
+    pub underlying_symbol: Option<String>,
+    /// Contract expiration.
+    pub expire_date: Option<DateTime<Utc>>,
+    /// Number of open contracts.
+    pub open_interest: Option<u64>,
+}
+
+impl From<V7QuoteNode> for QuoteDetails {
+    fn from(n: V7QuoteNode) -> Self {
+        let quote = Quote::from(&n);
+        let is_future = n
+            .quote_type
+            .as_deref()
+            .is_some_and(|t| t.eq_ignore_ascii_case("FUTURE"));
+
+        let futures = is_future.then(|| FuturesDetails {
+            contract_symbol: n
+                .contract_symbol
+                .as_ref()
+                .and_then(|v| v.as_str())
+                .map(str::to_owned),
+            underlying_symbol: n.underlying_symbol.clone(),
+            expire_date: n.expire_date.map(i64_to_datetime),
+            open_interest: n
+                .open_interest
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .map(|v| v.round() as u64),
+        });
+
+        Self {
+            quote,
+            long_name: n.long_name,
+            display_name: n.display_name,
+            quote_type: n.quote_type,
+            type_disp: n.type_disp,
+            regular_market_time: n.regular_market_time.map(i64_to_datetime),
+            futures,
+            extras: None,
+        }
+    }
+}
+
+impl From<V7QuoteNodeWithExtras> for QuoteDetails {
+    fn from(n: V7QuoteNodeWithExtras) -> Self {
+        Self {
+            extras: Some(n.extras),
+            ..n.node.into()
+        }
+    }
+}
+
+impl QuoteDetails {
+    /// Returns the name best suited for display: `displayName`, then `longName`, then
+    /// `shortName`, skipping blank values.
+    #[must_use]
+    pub fn display_name(&self) -> Option<&str> {
+        [
+            self.display_name.as_deref(),
+            self.long_name.as_deref(),
+            self.quote.shortname.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|s| !s.trim().is_empty())
+    }
+
+    /// Returns `true` if the price is older than `max_age`, or if Yahoo did not report when
+    /// it was last updated.
+    #[must_use]
+    pub fn is_stale(&self, max_age: std::time::Duration) -> bool {
+        is_stale(self.regular_market_time, max_age, Utc::now())
+    }
+}
+
+pub(crate) fn is_stale(
+    updated: Option<DateTime<Utc>>,
+    max_age: std::time::Duration,
+    now: DateTime<Utc>,
+) -> bool {
+    let Some(updated) = updated else {
+        return true;
+    };
+    chrono::Duration::from_std(max_age).is_ok_and(|age| now - updated > age)
+}
@@ -0,0 +1,120 @@
+use paft::domain::MarketState;
+use paft::money::Money;
+use rust_decimal::prelude::ToPrimitive;
+use serde::Serialize;
+
+use crate::core::Quote;
+
+/// What changed between two snapshots of the same quote.
+///
+/// Each field is `Some((before, after))` when the value differs and `None` when it is
+/// unchanged. Prices are compared currency-aware, so the same amount in a different
+/// currency counts as a change. The v7 `Quote` carries no volume, so volume is not tracked.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct QuoteDiff {
+    /// Change in the last price.
+    pub price: Option<(Option<Money>, Option<Money>)>,
+    /// Change in the previous close.
+    pub previous_close: Option<(Option<Money>, Option<Money>)>,
+    /// Change in the market state.
+    pub market_state: Option<(Option<MarketState>, Option<MarketState>)>,
+}
+
+impl QuoteDiff {
+    /// Returns `true` if nothing changed.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.price.is_none() && self.previous_close.is_none() && self.market_state.is_none()
+    }
+}
+
+/// Change-detection helpers for [`Quote`] snapshots, useful in polling loops.
+///
+/// `Quote` is defined in `paft`, so these are provided as an extension trait.
+/// Bring it into scope with `use yfinance_rs::QuoteExt;`.
+pub trait QuoteExt {
+    /// Returns `true` if both quotes carry the same price in the same currency.
+    ///
+    /// Two missing prices compare equal; a missing price never equals a present one.
+    fn same_price_as(&self, other: &Quote) -> bool;
+
+    /// Describes what changed going from `self` to `newer`.
+    fn diff(&self, newer: &Quote) -> QuoteDiff;
+
+    /// Percent change of the price against the previous close, e.g. `1.5` for +1.5%.
+    ///
+    /// Returns `None` if either value is missing, the two are in different currencies,
+    /// or the previous close is zero.
+    fn change_percent(&self) -> Option<f64>;
+}
+
+fn same_money(a: Option<&Money>, b: Option<&Money>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => a.currency() == b.currency() && a.amount() == b.amount(),
+        _ => false,
+    }
+}
+
+fn money_change(a: Option<&Money>, b: Option<&Money>) -> Option<(Option<Money>, Option<Money>)> {
+    (!same_money(a, b)).then(|| (a.cloned(), b.cloned()))
+}
+
+impl QuoteExt for Quote {
+    fn same_price_as(&self, other: &Quote) -> bool {
+        same_money(self.price.as_ref(), other.price.as_ref())
+    }
+
+    fn diff(&self, newer: &Quote) -> QuoteDiff {
+        QuoteDiff {
+            price: money_change(self.price.as_ref(), newer.price.as_ref()),
+            previous_close: money_change(
+                self.previous_close.as_ref(),
+                newer.previous_close.as_ref(),
+            ),
+            market_state: (self.market_state != newer.market_state)
+                .then_some((self.market_state, newer.market_state)),
+        }
+    }
+
+    fn change_percent(&self) -> Option<f64> {
+        let (price, prev) = (self.price.as_ref()?, self.previous_close.as_ref()?);
+        if price.currency() != prev.currency() {
+            return None;
+        }
+        let (price, prev) = (price.amount().to_f64()?, prev.amount().to_f64()?);
+        let pct = (price - prev) / prev * 100.0;
+        pct.is_finite().then_some(pct)
+    }
+}
+
+/// Ranks quotes by [`QuoteExt::change_percent`], biggest gainer first.
+///
+/// Quotes without a usable change (missing price or previous close, or mismatched
+/// currencies) are left out. Ties keep their input order.
+#[must_use]
+pub fn rank_by_change_percent(quotes: &[Quote]) -> Vec<&Quote> {
+    let mut ranked: Vec<(&Quote, f64)> = quotes
+        .iter()
+        .filter_map(|q| q.change_percent().map(|pct| (q, pct)))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked.into_iter().map(|(q, _)| q).collect()
+}
+
+/// The `n` biggest gainers by percent change, best first.
+#[must_use]
+pub fn top_n(quotes: &[Quote], n: usize) -> Vec<&Quote> {
+    let mut ranked = rank_by_change_percent(quotes);
+    ranked.truncate(n);
+    ranked
+}
+
+/// The `n` biggest losers by percent change, worst first.
+#[must_use]
+pub fn bottom_n(quotes: &[Quote], n: usize) -> Vec<&Quote> {
+    let mut ranked = rank_by_change_percent(quotes);
+    ranked.reverse();
+    ranked.truncate(n);
+    ranked
+}
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use paft::domain::MarketState;
+use paft::money::Money;
+use serde::Serialize;
+
+use crate::core::conversions::{f64_to_money_with_currency_str, i64_to_datetime};
+use crate::core::quotes::V7QuoteNode;
+
+/// Regular, pre-market and post-market prices of one symbol, fetched together.
+///
+/// Unlike [`PricePreference`](crate::PricePreference), nothing is chosen for you: every
+/// session Yahoo reports is present, so a dashboard can show whichever is relevant.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct FullQuote {
+    /// The ticker symbol.
+    pub symbol: String,
+    /// The current market state (e.g. `PRE`, `REGULAR`, `POST`).
+    pub market_state: Option<MarketState>,
+    /// The regular-session price.
+    pub regular: Option<SessionPrice>,
+    /// The pre-market price, when Yahoo reports one.
+    pub pre_market: Option<SessionPrice>,
+    /// The post-market price, when Yahoo reports one.
+    pub post_market: Option<SessionPrice>,
+}
+
+/// A price for one trading session with its change and update time.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SessionPrice {
+    /// The last price in the session.
+    pub price: Money,
+    /// Absolute change Yahoo reports for the session.
+    pub change: Option<Money>,
+    /// Percentage change Yahoo reports for the session (`1.5` means 1.5%).
+    pub change_percent: Option<f64>,
+    /// When the price was last updated.
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl From<V7QuoteNode> for FullQuote {
+    fn from(n: V7QuoteNode) -> Self {
+        let currency = n.currency.as_deref();
+        let session = |price: Option<f64>,
+                       change: Option<f64>,
+                       change_percent: Option<f64>,
+                       time: Option<i64>| {
+            price.map(|p| SessionPrice {
+                price: f64_to_money_with_currency_str(p, currency),
+                change: change.map(|c| f64_to_money_with_currency_str(c, currency)),
+                change_percent,
+                time: time.map(i64_to_datetime),
+            })
+        };
+
+        Self {
+            regular: session(
+                n.regular_market_price,
+                n.regular_market_change,
+                n.regular_market_change_percent,
+                n.regular_market_time,
+            ),
+            pre_market: session(
+                n.pre_market_price,
+                n.pre_market_change,
+                n.pre_market_change_percent,
+                n.pre_market_time,
+            ),
+            post_market: session(
+                n.post_market_price,
+                n.post_market_change,
+                n.post_market_change_percent,
+                n.post_market_time,
+            ),
+            market_state: n.market_state.as_deref().and_then(|s| s.parse().ok()),
+            symbol: n.symbol.unwrap_or_default(),
+        }
+    }
+}
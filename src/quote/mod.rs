@@ -1,8 +1,103 @@
+mod details;
+mod ext;
+mod full;
+
+pub(crate) use details::is_stale;
+pub use details::{FuturesDetails, QuoteDetails};
+pub use ext::{QuoteDiff, QuoteExt, bottom_n, rank_by_change_percent, top_n};
+pub use full::{FullQuote, SessionPrice};
+
 use crate::core::client::CacheMode;
 use crate::core::client::RetryConfig;
+use crate::core::quotes::V7Request;
 use crate::core::{Quote, YfClient, YfError, quotes as core_quotes};
+use crate::ticker::FastInfo;
+use crate::ticker::quote::{FAST_INFO_FIELDS, fast_info_from_node};
+use futures::future::try_join_all;
 use serde_json::Value;
 
+/// Which price fills [`Quote::price`] when the market is in extended hours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PricePreference {
+    /// Always use the regular-session price. (Default)
+    #[default]
+    Regular,
+    /// Use the pre-market price in `PRE` and the post-market price in `POST`, falling back to
+    /// the regular price when Yahoo reports none.
+    Extended,
+}
+
+/// A curated set of v7 `fields=` names for common use cases.
+///
+/// Pass one to [`QuotesBuilder::preset`] instead of spelling out Yahoo field names. Fields not
+/// modeled by [`Quote`] are still returned by [`QuotesBuilder::fetch_raw`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum QuoteFieldPreset {
+    /// Last price, previous close, currency, market state and the time of the last price.
+    Minimal,
+    /// `Minimal` plus the session's open/high/low, volume, bid/ask with sizes, and pre- and
+    /// post-market prices.
+    Trading,
+    /// Name, last price, market cap, shares outstanding, PE and EPS (trailing and forward),
+    /// price-to-book, dividend yield and the 52-week range.
+    Fundamentals,
+    /// No field filter: Yahoo's default (full) payload.
+    Full,
+}
+
+impl QuoteFieldPreset {
+    /// Returns the field names this preset expands to. `Full` is empty, meaning no
+    /// `fields=` parameter is sent.
+    #[must_use]
+    pub const fn fields(self) -> &'static [&'static str] {
+        match self {
+            Self::Minimal => &[
+                "regularMarketPrice",
+                "regularMarketPreviousClose",
+                "currency",
+                "marketState",
+                "regularMarketTime",
+            ],
+            Self::Trading => &[
+                "regularMarketPrice",
+                "regularMarketPreviousClose",
+                "currency",
+                "marketState",
+                "regularMarketTime",
+                "regularMarketOpen",
+                "regularMarketDayHigh",
+                "regularMarketDayLow",
+                "regularMarketVolume",
+                "bid",
+                "bidSize",
+                "ask",
+                "askSize",
+                "preMarketPrice",
+                "postMarketPrice",
+            ],
+            Self::Fundamentals => &[
+                "shortName",
+                "longName",
+                "regularMarketPrice",
+                "currency",
+                "marketCap",
+                "sharesOutstanding",
+                "trailingPE",
+                "forwardPE",
+                "epsTrailingTwelveMonths",
+                "epsForward",
+                "priceToBook",
+                "bookValue",
+                "dividendYield",
+                "fiftyTwoWeekLow",
+                "fiftyTwoWeekHigh",
+            ],
+            Self::Full => &[],
+        }
+    }
+}
+
 /// Fetches quotes for multiple symbols.
 ///
 /// # Errors
@@ -25,6 +120,13 @@ pub struct QuotesBuilder {
     client: YfClient,
     symbols: Vec<String>,
     fields: Vec<String>,
+    region: Option<String>,
+    group_by_exchange: bool,
+    extra_params: Vec<(String, String)>,
+    extras: bool,
+    max_symbols: Option<usize>,
+    max_age: Option<std::time::Duration>,
+    use_post: bool,
     cache_mode: CacheMode,
     retry_override: Option<RetryConfig>,
 }
@@ -37,6 +139,13 @@ impl QuotesBuilder {
             client,
             symbols: Vec::new(),
             fields: Vec::new(),
+            region: None,
+            group_by_exchange: false,
+            extra_params: Vec::new(),
+            extras: false,
+            max_symbols: None,
+            max_age: None,
+            use_post: false,
             cache_mode: CacheMode::Use,
             retry_override: None,
         }
@@ -92,57 +201,351 @@ impl QuotesBuilder {
         self
     }
 
-    /// Fetches the quotes for the configured symbols.
+    /// Replaces the requested fields with a curated [`QuoteFieldPreset`].
+    #[must_use]
+    pub fn preset(mut self, preset: QuoteFieldPreset) -> Self {
+        self.fields = preset.fields().iter().map(|f| (*f).to_string()).collect();
+        self
+    }
+
+    /// Sets the `region` for this request, overriding the client's default region.
+    #[must_use]
+    pub fn region(mut self, code: impl Into<String>) -> Self {
+        self.region = Some(code.into());
+        self
+    }
+
+    /// Sends one request per exchange instead of a single mixed batch. (Default: `false`)
     ///
-    /// # Errors
+    /// Symbols are grouped by their Yahoo suffix (`VOD.L`, `SAP.DE`; no suffix for US
+    /// listings), the groups are fetched concurrently, and the results are concatenated in
+    /// order of each group's first symbol. This avoids Yahoo mixing up currency and exchange
+    /// metadata across regions in one response.
+    #[must_use]
+    pub const fn group_by_exchange(mut self, yes: bool) -> Self {
+        self.group_by_exchange = yes;
+        self
+    }
+
+    /// Appends an arbitrary query parameter to the request URL.
     ///
-    /// Returns `YfError` if no symbols were provided, the network request fails,
-    /// the response cannot be parsed, or data for the symbols is not available.
-    pub async fn fetch(self) -> Result<Vec<crate::core::Quote>, crate::core::YfError> {
+    /// This is an escape hatch for Yahoo parameters the crate does not model yet. Params the
+    /// crate sets itself (`symbols`, `fields`, `region`, `crumb`) are never overridden; an
+    /// extra param with one of those keys is ignored.
+    #[must_use]
+    pub fn extra_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra_params.push((key.into(), value.into()));
+        self
+    }
+
+    /// Keeps v7 fields the crate does not model in [`QuoteDetails::extras`]. (Default: `false`)
+    ///
+    /// Only affects [`fetch_details`](Self::fetch_details). Collecting the extras costs an
+    /// extra pass over each result node, so it is off unless asked for.
+    #[must_use]
+    pub const fn extras(mut self, yes: bool) -> Self {
+        self.extras = yes;
+        self
+    }
+
+    /// Makes [`fetch_fast_info`](Self::fetch_fast_info) drop results whose price is older
+    /// than `max_age`. (Default: keep everything)
+    ///
+    /// A result without a `regularMarketTime` counts as stale.
+    #[must_use]
+    pub const fn max_age(mut self, max_age: std::time::Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Sends the symbols in a POST form body instead of the query string. (Default: `false`)
+    ///
+    /// Useful for very large batches that would otherwise exceed URL-length limits. Other
+    /// parameters stay in the query string, and responses share the cache with GET requests.
+    #[must_use]
+    pub const fn use_post(mut self, yes: bool) -> Self {
+        self.use_post = yes;
+        self
+    }
+
+    /// Rejects requests with more than `max` symbols before anything is sent.
+    /// (Default: no limit)
+    ///
+    /// This is a hard cap, not a batch size: an oversized list fails with
+    /// `YfError::InvalidParams` instead of being split.
+    #[must_use]
+    pub const fn max_symbols(mut self, max: usize) -> Self {
+        self.max_symbols = Some(max);
+        self
+    }
+
+    /// Checks the symbol list before any request is sent: it must be non-empty, within
+    /// [`max_symbols`](Self::max_symbols), and free of empty or whitespace-only symbols.
+    fn validate_symbols(&self) -> Result<(), YfError> {
         if self.symbols.is_empty() {
-            return Err(crate::core::YfError::InvalidParams(
+            return Err(YfError::InvalidParams(
                 "symbols list cannot be empty".into(),
             ));
         }
+        if let Some(max) = self.max_symbols
+            && self.symbols.len() > max
+        {
+            return Err(YfError::InvalidParams(format!(
+                "{} symbols requested, at most {max} allowed",
+                self.symbols.len()
+            )));
+        }
+        if let Some(i) = self.symbols.iter().position(|s| s.trim().is_empty()) {
+            return Err(YfError::InvalidParams(format!(
+                "symbol #{i} is empty or whitespace"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Splits the symbols into request batches: one batch, or one per exchange suffix.
+    fn batches(&self) -> Vec<Vec<&str>> {
+        if !self.group_by_exchange {
+            return vec![self.symbols.iter().map(AsRef::as_ref).collect()];
+        }
+        let mut groups: Vec<(String, Vec<&str>)> = Vec::new();
+        for sym in &self.symbols {
+            let suffix = sym
+                .rsplit_once('.')
+                .map(|(_, s)| s.to_ascii_uppercase())
+                .unwrap_or_default();
+            match groups.iter_mut().find(|(k, _)| *k == suffix) {
+                Some((_, g)) => g.push(sym.as_str()),
+                None => groups.push((suffix, vec![sym.as_str()])),
+            }
+        }
+        groups.into_iter().map(|(_, g)| g).collect()
+    }
+
+    fn v7_request<'a>(&'a self, fields: Option<&'a [&'a str]>) -> V7Request<'a> {
+        V7Request {
+            fields,
+            region: self.region.as_deref(),
+            extra: &self.extra_params,
+            use_post: self.use_post,
+            cache_mode: self.cache_mode,
+            retry_override: self.retry_override.as_ref(),
+        }
+    }
+
+    async fn fetch_nodes(&self) -> Result<Vec<core_quotes::V7QuoteNode>, YfError> {
+        self.validate_symbols()?;
 
-        let symbol_slices: Vec<&str> = self.symbols.iter().map(AsRef::as_ref).collect();
         let field_slices: Option<Vec<&str>> =
             (!self.fields.is_empty()).then(|| self.fields.iter().map(AsRef::as_ref).collect());
-        let results = core_quotes::fetch_v7_quotes(
+        let req = self.v7_request(field_slices.as_deref());
+        let batches = self.batches();
+        let results = try_join_all(
+            batches
+                .iter()
+                .map(|symbols| core_quotes::fetch_v7_quotes(&self.client, symbols, &req)),
+        )
+        .await?;
+
+        Ok(results.into_iter().flatten().collect())
+    }
+
+    /// Fetches the quotes for the configured symbols.
+    ///
+    /// Prices are restated in the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) when one is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError` if the symbols fail validation (empty list, empty symbol, or more
+    /// than [`max_symbols`](Self::max_symbols)), the network request fails, the response
+    /// cannot be parsed, or data for the symbols is not available.
+    pub async fn fetch(self) -> Result<Vec<crate::core::Quote>, crate::core::YfError> {
+        let results = self.fetch_nodes().await?;
+        let mut quotes: Vec<Quote> = results.into_iter().map(Into::into).collect();
+        crate::fx::to_display_currency(
             &self.client,
-            &symbol_slices,
-            field_slices.as_deref(),
+            &mut quotes,
             self.cache_mode,
             self.retry_override.as_ref(),
         )
         .await?;
+        Ok(quotes)
+    }
 
-        Ok(results.into_iter().map(Into::into).collect())
+    /// Fetches quotes together with fields outside the shared [`Quote`] model, such as
+    /// futures contract details.
+    ///
+    /// Prices are restated in the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) when one is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError` if no symbols were provided, the network request fails,
+    /// or the response cannot be parsed.
+    pub async fn fetch_details(self) -> Result<Vec<QuoteDetails>, YfError> {
+        let client = self.client.clone();
+        let cache_mode = self.cache_mode;
+        let retry_override = self.retry_override.clone();
+        let mut details: Vec<QuoteDetails> = if self.extras {
+            let normalize = self.client.normalize_minor_units();
+            self.fetch_raw()
+                .await?
+                .into_iter()
+                .map(|node| {
+                    let symbol = node
+                        .get("symbol")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_owned();
+                    let mut node =
+                        serde_json::from_value::<core_quotes::V7QuoteNodeWithExtras>(node)
+                            .map_err(|e| YfError::parse("quote_v7", &[&symbol], e))?;
+                    if normalize {
+                        node.node.normalize_minor_units();
+                    }
+                    Ok(node.into())
+                })
+                .collect::<Result<_, YfError>>()?
+        } else {
+            let results = self.fetch_nodes().await?;
+            results.into_iter().map(Into::into).collect()
+        };
+        crate::fx::to_display_currency(&client, &mut details, cache_mode, retry_override.as_ref())
+            .await?;
+        Ok(details)
     }
 
-    /// Fetches raw quote payloads for the configured symbols.
+    /// Fetches quotes, collecting per-symbol failures instead of failing the whole batch.
+    ///
+    /// Each result node is parsed on its own: a malformed node is reported as
+    /// `(symbol, YfError::Parse)` and a requested symbol missing from the response as
+    /// `(symbol, YfError::MissingData)`, while the remaining quotes are still returned.
+    ///
+    /// Prices are restated in the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) when one is set.
     ///
     /// # Errors
     ///
-    /// Returns `YfError` if no symbols were provided or the underlying HTTP request fails.
-    pub async fn fetch_raw(self) -> Result<Vec<Value>, crate::core::YfError> {
-        if self.symbols.is_empty() {
-            return Err(crate::core::YfError::InvalidParams(
-                "symbols list cannot be empty".into(),
-            ));
+    /// Returns `YfError` only for failures affecting the entire request: no symbols were
+    /// provided, the network request fails, or the response envelope cannot be parsed.
+    pub async fn fetch_partial(
+        self,
+    ) -> Result<(Vec<crate::core::Quote>, Vec<(String, YfError)>), YfError> {
+        let requested = self.symbols.clone();
+        let normalize = self.client.normalize_minor_units();
+        let client = self.client.clone();
+        let cache_mode = self.cache_mode;
+        let retry_override = self.retry_override.clone();
+        let nodes = self.fetch_raw().await?;
+
+        let mut quotes = Vec::with_capacity(nodes.len());
+        let mut failures = Vec::new();
+        for (i, node) in nodes.into_iter().enumerate() {
+            let symbol = node
+                .get("symbol")
+                .and_then(Value::as_str)
+                .map_or_else(|| format!("#{i}"), str::to_owned);
+            match serde_json::from_value::<core_quotes::V7QuoteNode>(node) {
+                Ok(mut n) => {
+                    if normalize {
+                        n.normalize_minor_units();
+                    }
+                    quotes.push(Quote::from(n));
+                }
+                Err(e) => {
+                    let err = YfError::parse("quote_v7", &[&symbol], e);
+                    failures.push((symbol, err));
+                }
+            }
         }
 
-        let symbol_slices: Vec<&str> = self.symbols.iter().map(AsRef::as_ref).collect();
-        let field_slices: Option<Vec<&str>> =
-            (!self.fields.is_empty()).then(|| self.fields.iter().map(AsRef::as_ref).collect());
+        for sym in requested {
+            let seen = quotes.iter().any(|q| q.symbol.eq_ignore_ascii_case(&sym))
+                || failures.iter().any(|(s, _)| s.eq_ignore_ascii_case(&sym));
+            if !seen {
+                failures.push((
+                    sym.clone(),
+                    YfError::MissingData(format!("no quote result found for symbol {sym}")),
+                ));
+            }
+        }
 
-        core_quotes::fetch_v7_quotes_raw(
+        crate::fx::to_display_currency(&client, &mut quotes, cache_mode, retry_override.as_ref())
+            .await?;
+        Ok((quotes, failures))
+    }
+
+    /// Fetches the reduced [`FastInfo`] for every configured symbol in batched requests.
+    ///
+    /// Returns the kept results and, separately, the symbols that were dropped: those older
+    /// than [`max_age`](Self::max_age) (measured against the client's
+    /// [`Clock`](crate::Clock)), those Yahoo reported without any price, and requested symbols
+    /// missing from the response. Requested fields are replaced by the fast-info field set.
+    ///
+    /// Prices are restated in the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) when one is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError` if the symbols fail validation, the network request fails, or the
+    /// response cannot be parsed.
+    pub async fn fetch_fast_info(mut self) -> Result<(Vec<FastInfo>, Vec<String>), YfError> {
+        self.fields = FAST_INFO_FIELDS.iter().map(|f| (*f).to_string()).collect();
+        let nodes = self.fetch_nodes().await?;
+        let now = self.client.now();
+
+        let mut kept: Vec<FastInfo> = Vec::with_capacity(nodes.len());
+        let mut dropped: Vec<String> = Vec::new();
+        for node in nodes {
+            // A node without a symbol cannot be attributed; the symbol it stood for is
+            // reported as missing below.
+            let Some(symbol) = node.symbol.clone() else {
+                continue;
+            };
+            match fast_info_from_node(node, false) {
+                Ok(info) if !self.max_age.is_some_and(|age| info.is_stale_at(age, now)) => {
+                    kept.push(info);
+                }
+                _ => dropped.push(symbol),
+            }
+        }
+        for sym in &self.symbols {
+            let seen = kept.iter().any(|i| i.symbol.eq_ignore_ascii_case(sym))
+                || dropped.iter().any(|s| s.eq_ignore_ascii_case(sym));
+            if !seen {
+                dropped.push(sym.clone());
+            }
+        }
+        crate::fx::fast_info_to_display_currency(
             &self.client,
-            &symbol_slices,
-            field_slices.as_deref(),
+            &mut kept,
             self.cache_mode,
             self.retry_override.as_ref(),
         )
-        .await
+        .await?;
+        Ok((kept, dropped))
+    }
+
+    /// Fetches raw quote payloads for the configured symbols.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError` if the symbols fail validation or the underlying HTTP request fails.
+    pub async fn fetch_raw(self) -> Result<Vec<Value>, crate::core::YfError> {
+        self.validate_symbols()?;
+
+        let field_slices: Option<Vec<&str>> =
+            (!self.fields.is_empty()).then(|| self.fields.iter().map(AsRef::as_ref).collect());
+        let req = self.v7_request(field_slices.as_deref());
+        let batches = self.batches();
+        let results = try_join_all(
+            batches
+                .iter()
+                .map(|symbols| core_quotes::fetch_v7_quotes_raw(&self.client, symbols, &req)),
+        )
+        .await?;
+
+        Ok(results.into_iter().flatten().collect())
     }
 }
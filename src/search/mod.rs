@@ -1,3 +1,6 @@
+use std::collections::{HashMap, HashSet};
+
+use futures::stream::{self, StreamExt};
 use serde::Deserialize;
 use serde::Serialize;
 use url::Url;
@@ -39,8 +42,26 @@ pub async fn search(client: &YfClient, query: &str) -> Result<SearchResponse, Yf
     SearchBuilder::new(client, query).fetch().await
 }
 
+/// Searches several queries concurrently and returns the quotes found for each.
+///
+/// Useful for bulk symbol resolution, e.g. turning a list of company names into tickers.
+/// See [`SearchBuilder::fetch_many`] for how concurrency and failures are handled.
+pub async fn search_many<I, S>(
+    client: &YfClient,
+    queries: I,
+) -> (HashMap<String, Vec<SearchQuote>>, Vec<(String, YfError)>)
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    SearchBuilder::new(client, "").fetch_many(queries).await
+}
+
+/// Maximum number of searches `fetch_many` keeps in flight at once.
+const SEARCH_MANY_CONCURRENCY: usize = 4;
+
 /// A builder for searching for tickers and other assets on Yahoo Finance.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SearchBuilder {
     client: YfClient,
     base: Url,
@@ -57,14 +78,13 @@ pub struct SearchBuilder {
 impl SearchBuilder {
     /// Creates a new `SearchBuilder` for a given search query.
     ///
-    /// # Panics
-    ///
-    /// This function will panic if the hardcoded `DEFAULT_BASE_SEARCH_V1` constant
-    /// is not a valid URL. This indicates a bug within the crate itself.
+    /// Requests go to the client's search base URL, which follows
+    /// [`YfClientBuilder::host`](crate::YfClientBuilder::host) unless overridden with
+    /// [`YfClientBuilder::base_search`](crate::YfClientBuilder::base_search).
     pub fn new(client: &YfClient, query: impl Into<String>) -> Self {
         Self {
             client: client.clone(),
-            base: Url::parse(DEFAULT_BASE_SEARCH_V1).unwrap(),
+            base: client.base_search().clone(),
             query: query.into(),
             quotes_count: Some(10),
             news_count: Some(0),
@@ -125,7 +145,7 @@ impl SearchBuilder {
         self
     }
 
-    /// Sets the region for the search results.
+    /// Sets the region for the search results, overriding the client's default region.
     #[must_use]
     pub fn region(mut self, s: impl Into<String>) -> Self {
         self.region = Some(s.into());
@@ -148,7 +168,9 @@ impl SearchBuilder {
             self.news_count,
             self.lists_count,
             self.lang.as_deref(),
-            self.region.as_deref(),
+            self.region
+                .as_deref()
+                .or_else(|| self.client.default_region()),
         );
 
         if self.cache_mode == CacheMode::Use
@@ -185,7 +207,9 @@ impl SearchBuilder {
                     self.news_count,
                     self.lists_count,
                     self.lang.as_deref(),
-                    self.region.as_deref(),
+                    self.region
+                        .as_deref()
+                        .or_else(|| self.client.default_region()),
                 );
                 url2.query_pairs_mut().append_pair("crumb", &crumb);
 
@@ -198,20 +222,7 @@ impl SearchBuilder {
                     .await?;
 
                 if !resp.status().is_success() {
-                    let code = resp.status().as_u16();
-                    let url_s = url2.to_string();
-                    return Err(match code {
-                        404 => crate::core::YfError::NotFound { url: url_s },
-                        429 => crate::core::YfError::RateLimited { url: url_s },
-                        500..=599 => crate::core::YfError::ServerError {
-                            status: code,
-                            url: url_s,
-                        },
-                        _ => crate::core::YfError::Status {
-                            status: code,
-                            url: url_s,
-                        },
-                    });
+                    return Err(crate::core::YfError::from_response(&resp, url2.as_str()));
                 }
 
                 let body =
@@ -222,19 +233,7 @@ impl SearchBuilder {
                 return parse_search_body(&body);
             }
 
-            let url_s = url.to_string();
-            return Err(match code {
-                404 => crate::core::YfError::NotFound { url: url_s },
-                429 => crate::core::YfError::RateLimited { url: url_s },
-                500..=599 => crate::core::YfError::ServerError {
-                    status: code,
-                    url: url_s,
-                },
-                _ => crate::core::YfError::Status {
-                    status: code,
-                    url: url_s,
-                },
-            });
+            return Err(crate::core::YfError::from_response(&resp, url.as_str()));
         }
 
         let body = crate::core::net::get_text(resp, "search_v1", &self.query, "json").await?;
@@ -244,6 +243,53 @@ impl SearchBuilder {
         parse_search_body(&body)
     }
 
+    /// Runs this search once per query, ignoring the builder's own query.
+    ///
+    /// Every other setting (counts, language, region, cache and retry policy) applies to each
+    /// query. At most four searches are in flight at a time, and the client's
+    /// [`max_concurrent_requests`](crate::YfClientBuilder::max_concurrent_requests) limit still
+    /// applies. Duplicate queries are searched once.
+    ///
+    /// Returns the quotes found for each successful query, and `(query, error)` for each query
+    /// that failed, so one failure does not sink the whole batch.
+    pub async fn fetch_many<I, S>(
+        self,
+        queries: I,
+    ) -> (HashMap<String, Vec<SearchQuote>>, Vec<(String, YfError)>)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut seen = HashSet::new();
+        let unique: Vec<String> = queries
+            .into_iter()
+            .map(Into::into)
+            .filter(|q: &String| seen.insert(q.clone()))
+            .collect();
+
+        let results: Vec<(String, Result<SearchResponse, YfError>)> = stream::iter(unique)
+            .map(|query| {
+                let mut builder = self.clone();
+                builder.query.clone_from(&query);
+                async move { (query, builder.fetch().await) }
+            })
+            .buffer_unordered(SEARCH_MANY_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut out = HashMap::with_capacity(results.len());
+        let mut failures = Vec::new();
+        for (query, res) in results {
+            match res {
+                Ok(resp) => {
+                    out.insert(query, resp.quotes);
+                }
+                Err(e) => failures.push((query, e)),
+            }
+        }
+        (out, failures)
+    }
+
     fn append_query_params(
         url: &mut Url,
         query: &str,
@@ -303,8 +349,6 @@ pub struct SearchQuote {
     pub type_disp: Option<String>,
 }
 
-const DEFAULT_BASE_SEARCH_V1: &str = "https://query2.finance.yahoo.com/v1/finance/search";
-
 /* ------------- Minimal serde mapping of /v1/finance/search ------------- */
 
 #[derive(Deserialize)]
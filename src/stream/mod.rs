@@ -7,6 +7,7 @@ use tokio::{
     select,
     sync::{mpsc, oneshot},
     task::JoinHandle,
+    time::Instant,
 };
 use tokio_tungstenite::{
     connect_async,
@@ -19,6 +20,7 @@ use tokio_tungstenite::{
 use crate::{
     YfClient, YfError,
     core::client::{CacheMode, RetryConfig},
+    core::quotes::V7Request,
 };
 
 mod wire_ws {
@@ -42,6 +44,41 @@ pub struct QuoteUpdate {
     pub ts: i64,
 }
 
+/// The minimum price move required before a polling stream emits another update.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChangeThreshold {
+    /// An absolute move in price units, e.g. `0.5` for fifty cents.
+    Absolute(f64),
+    /// A relative move in percent, e.g. `1.0` for 1%.
+    Percent(f64),
+}
+
+impl ChangeThreshold {
+    /// Returns `true` if moving from `prev` to `now` is strictly larger than the threshold.
+    ///
+    /// With [`Percent`](Self::Percent), any move away from a `prev` of `0.0` counts as
+    /// exceeding the threshold, since the relative change is unbounded.
+    #[must_use]
+    pub fn is_exceeded(self, prev: f64, now: f64) -> bool {
+        match self {
+            Self::Absolute(a) => (now - prev).abs() > a,
+            Self::Percent(_) if prev == 0.0 => now != 0.0,
+            Self::Percent(p) => ((now - prev) / prev).abs() * 100.0 > p,
+        }
+    }
+}
+
+/// What a polling stream does when its buffer is full because the consumer is slow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Wait for the consumer, pausing polling until there is room. (Default)
+    #[default]
+    Block,
+    /// Keep polling and discard the oldest buffered update to make room for the newest.
+    /// The update that is next to be received is kept.
+    DropOldest,
+}
+
 /// Configuration for a polling-based quote stream.
 #[derive(Debug, Clone)]
 pub struct StreamConfig {
@@ -49,6 +86,16 @@ pub struct StreamConfig {
     pub interval: Duration,
     /// If `true`, only emit updates when the price has changed.
     pub diff_only: bool,
+    /// If set, only emit updates when the price has moved by more than this amount since the
+    /// last emitted update. The first observation of each symbol is always emitted.
+    pub min_change: Option<ChangeThreshold>,
+    /// The maximum number of updates buffered for the consumer. Must be at least 1.
+    pub buffer: usize,
+    /// What to do when `buffer` updates are waiting to be received.
+    pub overflow: Overflow,
+    /// If set, a WebSocket connection that stays silent (no message or pong) for this long is
+    /// dropped and reopened.
+    pub heartbeat_timeout: Option<Duration>,
 }
 
 impl Default for StreamConfig {
@@ -56,17 +103,52 @@ impl Default for StreamConfig {
         Self {
             interval: Duration::from_secs(1),
             diff_only: true,
+            min_change: None,
+            buffer: 1024,
+            overflow: Overflow::Block,
+            heartbeat_timeout: None,
         }
     }
 }
 
-/// A handle to a running quote stream, used to stop it gracefully.
+/// A handle to a running quote stream, used to change its symbols or stop it gracefully.
 pub struct StreamHandle {
     join: JoinHandle<()>,
     stop_tx: Option<oneshot::Sender<()>>,
+    change_tx: mpsc::UnboundedSender<SymbolChange>,
 }
 
 impl StreamHandle {
+    /// Adds symbols to the running stream.
+    ///
+    /// A WebSocket stream forwards the subscription to the server right away; a polling stream
+    /// includes the symbols from its next poll on. Symbols already streamed are ignored, and the
+    /// full set is re-subscribed if the connection is reopened.
+    ///
+    /// Returns `false` if the stream has already ended.
+    pub fn subscribe<I, S>(&self, syms: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let syms = syms.into_iter().map(Into::into).collect();
+        self.change_tx.send(SymbolChange::Subscribe(syms)).is_ok()
+    }
+
+    /// Removes symbols from the running stream.
+    ///
+    /// Updates already buffered for these symbols are still delivered.
+    ///
+    /// Returns `false` if the stream has already ended.
+    pub fn unsubscribe<I, S>(&self, syms: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let syms = syms.into_iter().map(Into::into).collect();
+        self.change_tx.send(SymbolChange::Unsubscribe(syms)).is_ok()
+    }
+
     /// Stops the stream and waits for the background task to complete.
     pub async fn stop(mut self) {
         if let Some(tx) = self.stop_tx.take() {
@@ -170,11 +252,52 @@ impl StreamBuilder {
         self
     }
 
+    /// Only emit updates once the price has moved by more than `threshold` since the last
+    /// emitted update for that symbol. (Only used for `Polling` method).
+    ///
+    /// The first observation of each symbol is always emitted.
+    #[must_use]
+    pub const fn min_change(mut self, threshold: ChangeThreshold) -> Self {
+        self.cfg.min_change = Some(threshold);
+        self
+    }
+
+    /// Sets how many updates may wait for the consumer. (Default: 1024)
+    ///
+    /// Once the buffer is full, a polling stream applies the [`overflow`](Self::overflow)
+    /// policy instead of fetching further ahead of the consumer.
+    #[must_use]
+    pub const fn buffer(mut self, size: usize) -> Self {
+        self.cfg.buffer = size;
+        self
+    }
+
+    /// Sets what a polling stream does when the buffer is full. (Default: [`Overflow::Block`])
+    ///
+    /// WebSocket streams always wait for the consumer.
+    #[must_use]
+    pub const fn overflow(mut self, policy: Overflow) -> Self {
+        self.cfg.overflow = policy;
+        self
+    }
+
+    /// Reconnects a WebSocket stream that has been silent for `timeout`. (Default: disabled)
+    ///
+    /// Halfway through the window a ping is sent, so a quiet but healthy connection stays
+    /// open as long as the server answers with a pong. (Only used for `Websocket` and
+    /// `WebsocketWithFallback` methods).
+    #[must_use]
+    pub const fn heartbeat_timeout(mut self, timeout: Duration) -> Self {
+        self.cfg.heartbeat_timeout = Some(timeout);
+        self
+    }
+
     /// Starts the stream, returning a handle to control it and a channel receiver for quote updates.
     ///
     /// # Errors
     ///
-    /// This method will return an error if no symbols have been added to the builder.
+    /// This method will return an error if no symbols have been added to the builder or the
+    /// buffer size is zero.
     pub fn start(
         self,
     ) -> Result<(StreamHandle, tokio::sync::mpsc::Receiver<QuoteUpdate>), crate::core::YfError>
@@ -184,16 +307,35 @@ impl StreamBuilder {
                 "symbols list cannot be empty".into(),
             ));
         }
+        if self.cfg.buffer == 0 {
+            return Err(crate::core::YfError::InvalidParams(
+                "stream buffer must be at least 1".into(),
+            ));
+        }
+        if self.cfg.heartbeat_timeout == Some(Duration::ZERO) {
+            return Err(crate::core::YfError::InvalidParams(
+                "heartbeat timeout must be non-zero".into(),
+            ));
+        }
 
-        let (tx, rx) = tokio::sync::mpsc::channel::<QuoteUpdate>(1024);
+        // With `DropOldest` the polling task keeps the backlog itself so it can discard the
+        // oldest entry; the channel then only holds the next update to be received.
+        let capacity = match self.cfg.overflow {
+            Overflow::Block => self.cfg.buffer,
+            Overflow::DropOldest => 1,
+        };
+        let (tx, rx) = tokio::sync::mpsc::channel::<QuoteUpdate>(capacity);
         let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+        let (change_tx, change_rx) = mpsc::unbounded_channel::<SymbolChange>();
 
         let join = tokio::spawn({
             let client = self.client;
-            let symbols = self.symbols.clone();
+            let mut symbols = self.symbols.clone();
             let cfg = self.cfg.clone();
+            let heartbeat = self.cfg.heartbeat_timeout;
 
             let mut stop_rx = stop_rx;
+            let mut change_rx = change_rx;
 
             // NEW:
             let cache_mode = self.cache_mode;
@@ -202,17 +344,30 @@ impl StreamBuilder {
             async move {
                 match self.method {
                     StreamMethod::Websocket => {
-                        if let Err(e) =
-                            run_websocket_stream(&client, symbols, tx, &mut stop_rx).await
+                        if let Err(e) = run_websocket_stream(
+                            &client,
+                            &mut symbols,
+                            tx,
+                            &mut stop_rx,
+                            &mut change_rx,
+                            heartbeat,
+                        )
+                        .await
                             && std::env::var("YF_DEBUG").ok().as_deref() == Some("1")
                         {
                             eprintln!("YF_DEBUG(stream): websocket stream failed: {e}");
                         }
                     }
                     StreamMethod::WebsocketWithFallback => {
-                        if let Err(e) =
-                            run_websocket_stream(&client, symbols.clone(), tx.clone(), &mut stop_rx)
-                                .await
+                        if let Err(e) = run_websocket_stream(
+                            &client,
+                            &mut symbols,
+                            tx.clone(),
+                            &mut stop_rx,
+                            &mut change_rx,
+                            heartbeat,
+                        )
+                        .await
                         {
                             if std::env::var("YF_DEBUG").ok().as_deref() == Some("1") {
                                 eprintln!(
@@ -225,6 +380,7 @@ impl StreamBuilder {
                                 cfg,
                                 tx,
                                 &mut stop_rx,
+                                &mut change_rx,
                                 cache_mode,
                                 retry_override.as_ref(),
                             )
@@ -238,6 +394,7 @@ impl StreamBuilder {
                             cfg,
                             tx,
                             &mut stop_rx,
+                            &mut change_rx,
                             cache_mode,
                             retry_override.as_ref(),
                         )
@@ -251,23 +408,100 @@ impl StreamBuilder {
             StreamHandle {
                 join,
                 stop_tx: Some(stop_tx),
+                change_tx,
             },
             rx,
         ))
     }
 }
 
+/// A change to the streamed symbols, serialized as the WebSocket command that applies it
+/// (`{"subscribe":[..]}` or `{"unsubscribe":[..]}`).
 #[derive(Serialize)]
-struct WsSubscribe<'a> {
-    subscribe: &'a [String],
+#[serde(rename_all = "lowercase")]
+enum SymbolChange {
+    Subscribe(Vec<String>),
+    Unsubscribe(Vec<String>),
+}
+
+impl SymbolChange {
+    /// Applies the change to `symbols`, keeping it free of duplicates.
+    fn apply(&self, symbols: &mut Vec<String>) {
+        match self {
+            Self::Subscribe(add) => {
+                for sym in add {
+                    if !symbols.contains(sym) {
+                        symbols.push(sym.clone());
+                    }
+                }
+            }
+            Self::Unsubscribe(remove) => symbols.retain(|s| !remove.contains(s)),
+        }
+    }
+
+    /// The same change with every symbol mapped to the one Yahoo knows it by.
+    fn to_yahoo(&self, client: &YfClient) -> Self {
+        let map = |syms: &[String]| {
+            syms.iter()
+                .map(|s| client.yahoo_symbol(s).to_string())
+                .collect()
+        };
+        match self {
+            Self::Subscribe(syms) => Self::Subscribe(map(syms)),
+            Self::Unsubscribe(syms) => Self::Unsubscribe(map(syms)),
+        }
+    }
+}
+
+/// How a single WebSocket connection ended without an error.
+enum WsExit {
+    /// Stopped by the handle, the server or the consumer; the stream is over.
+    Done,
+    /// Silent past the heartbeat timeout; the caller should reconnect.
+    Stale,
 }
 
 async fn run_websocket_stream(
     client: &YfClient,
-    symbols: Vec<String>,
+    symbols: &mut Vec<String>,
     tx: mpsc::Sender<QuoteUpdate>,
     stop_rx: &mut oneshot::Receiver<()>,
+    change_rx: &mut mpsc::UnboundedReceiver<SymbolChange>,
+    heartbeat: Option<Duration>,
 ) -> Result<(), YfError> {
+    loop {
+        match run_websocket_session(client, symbols, &tx, stop_rx, change_rx, heartbeat).await? {
+            WsExit::Done => return Ok(()),
+            WsExit::Stale => {
+                if std::env::var("YF_DEBUG").ok().as_deref() == Some("1") {
+                    eprintln!("YF_DEBUG(stream): websocket silent past heartbeat, reconnecting.");
+                }
+            }
+        }
+    }
+}
+
+/// Resolves when the connection has been silent long enough to act: at half the heartbeat
+/// window to send a ping, at the full window once a ping is outstanding. Never resolves
+/// without a heartbeat.
+async fn heartbeat_due(heartbeat: Option<Duration>, last_seen: Instant, pinged: bool) {
+    match heartbeat {
+        Some(window) => {
+            let wait = if pinged { window } else { window / 2 };
+            tokio::time::sleep_until(last_seen + wait).await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+async fn run_websocket_session(
+    client: &YfClient,
+    symbols: &mut Vec<String>,
+    tx: &mpsc::Sender<QuoteUpdate>,
+    stop_rx: &mut oneshot::Receiver<()>,
+    change_rx: &mut mpsc::UnboundedReceiver<SymbolChange>,
+    heartbeat: Option<Duration>,
+) -> Result<WsExit, YfError> {
     let base = client.base_stream();
     let host = base
         .host_str()
@@ -288,18 +522,21 @@ async fn run_websocket_stream(
     let (ws_stream, _) = connect_async(request).await?;
     let (mut write, mut read) = ws_stream.split();
 
-    let sub_msg = serde_json::to_string(&WsSubscribe {
-        subscribe: &symbols,
-    })
-    .map_err(YfError::Json)?;
+    let sub_msg = serde_json::to_string(&SymbolChange::Subscribe(symbols.clone()).to_yahoo(client))
+        .map_err(YfError::Json)?;
     write.send(WsMessage::Text(sub_msg.into())).await?;
 
     #[cfg(feature = "test-mode")]
     let mut recorded = false;
 
+    let mut last_seen = Instant::now();
+    let mut pinged = false;
+
     loop {
         select! {
             msg = read.next() => {
+                last_seen = Instant::now();
+                pinged = false;
                 match msg {
                     Some(Ok(WsMessage::Text(text))) => {
                         #[cfg(feature = "test-mode")]
@@ -313,7 +550,8 @@ async fn run_websocket_stream(
                         }
 
                         match decode_and_map_message(&text) {
-                            Ok(update) => {
+                            Ok(mut update) => {
+                                client.unalias_symbol(&mut update.symbol);
                                 if tx.send(update).await.is_err() {
                                     break; // Receiver was dropped, exit loop
                                 }
@@ -329,7 +567,8 @@ async fn run_websocket_stream(
                     Some(Ok(WsMessage::Binary(bin))) => {
                         // Try to interpret as UTF-8 JSON-wrapped base64 first
                         let handled = if let Ok(as_text) = std::str::from_utf8(&bin)
-                            && let Ok(update) = decode_and_map_message(as_text) {
+                            && let Ok(mut update) = decode_and_map_message(as_text) {
+                                client.unalias_symbol(&mut update.symbol);
                                 if tx.send(update).await.is_err() {
                                     break; // Receiver was dropped
                                 }
@@ -339,13 +578,14 @@ async fn run_websocket_stream(
                         if !handled {
                             match wire_ws::PricingData::decode(&*bin) {
                                 Ok(ticker) => {
-                                    let update = QuoteUpdate {
+                                    let mut update = QuoteUpdate {
                                         symbol: ticker.id,
                                         last_price: Some(f64::from(ticker.price)),
                                         previous_close: Some(f64::from(ticker.previous_close)),
                                         currency: Some(ticker.currency),
                                         ts: ticker.time,
                                     };
+                                    client.unalias_symbol(&mut update.symbol);
                                     if tx.send(update).await.is_err() {
                                         break; // Receiver was dropped
                                     }
@@ -363,12 +603,24 @@ async fn run_websocket_stream(
                     None => break,
                 }
             },
+            () = heartbeat_due(heartbeat, last_seen, pinged) => {
+                if pinged {
+                    return Ok(WsExit::Stale);
+                }
+                write.send(WsMessage::Ping(Vec::new().into())).await?;
+                pinged = true;
+            }
+            Some(change) = change_rx.recv() => {
+                change.apply(symbols);
+                let msg = serde_json::to_string(&change.to_yahoo(client)).map_err(YfError::Json)?;
+                write.send(WsMessage::Text(msg.into())).await?;
+            }
             _ = &mut *stop_rx => {
                 break;
             }
         }
     }
-    Ok(())
+    Ok(WsExit::Done)
 }
 
 /// Decodes a single base64-encoded protobuf message from the Yahoo Finance WebSocket stream.
@@ -411,25 +663,35 @@ pub fn decode_and_map_message(text: &str) -> Result<QuoteUpdate, YfError> {
 #[allow(clippy::too_many_arguments)]
 async fn run_polling_stream(
     client: crate::core::YfClient,
-    symbols: Vec<String>,
+    mut symbols: Vec<String>,
     cfg: StreamConfig,
     tx: tokio::sync::mpsc::Sender<QuoteUpdate>,
     stop_rx: &mut tokio::sync::oneshot::Receiver<()>,
+    change_rx: &mut mpsc::UnboundedReceiver<SymbolChange>,
     cache_mode: CacheMode,
     retry_override: Option<&RetryConfig>,
 ) {
     let mut ticker = tokio::time::interval(cfg.interval);
+    // After waiting on a slow consumer, resume the normal pace instead of catching up.
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // Updates waiting for room in the channel. They are handed over by the `reserve` arm so
+    // that a slow consumer never blocks the stop signal.
+    let mut pending: std::collections::VecDeque<QuoteUpdate> = std::collections::VecDeque::new();
+    let backlog = cfg.buffer.saturating_sub(1);
+    let blocking = cfg.overflow == Overflow::Block;
     let mut last_price: std::collections::HashMap<String, Option<f64>> =
         std::collections::HashMap::new();
-
-    let symbol_slices: Vec<&str> = symbols.iter().map(AsRef::as_ref).collect();
+    let mut last_emitted: std::collections::HashMap<String, f64> = std::collections::HashMap::new();
 
     loop {
         tokio::select! {
-            _ = ticker.tick() => {
+            // With `Block`, polling pauses until the previous poll's updates are all delivered.
+            _ = ticker.tick(), if !blocking || pending.is_empty() => {
                 if tx.is_closed() { break; }
-                let ts = chrono::Utc::now().timestamp();
-                match crate::core::quotes::fetch_v7_quotes(&client, &symbol_slices, None, cache_mode, retry_override).await {
+                if symbols.is_empty() { continue; }
+                let symbol_slices: Vec<&str> = symbols.iter().map(AsRef::as_ref).collect();
+                let ts = client.now().timestamp();
+                match crate::core::quotes::fetch_v7_quotes(&client, &symbol_slices, &V7Request::new(cache_mode, retry_override)).await {
                     Ok(quotes) => {
                         for q in quotes {
                             let lp = q.regular_market_price.or(q.regular_market_previous_close);
@@ -440,15 +702,35 @@ async fn run_polling_stream(
                                     continue;
                                 }
                             }
-                            if tx.send(QuoteUpdate {
+                            if let Some(threshold) = cfg.min_change {
+                                let symbol = q.symbol.clone().unwrap_or_default();
+                                match (last_emitted.get(&symbol), lp) {
+                                    (Some(&prev), Some(now)) if !threshold.is_exceeded(prev, now) => continue,
+                                    (Some(_), None) => continue,
+                                    (_, Some(now)) => { last_emitted.insert(symbol, now); }
+                                    (None, None) => {}
+                                }
+                            }
+                            let update = QuoteUpdate {
                                 symbol: q.symbol.unwrap_or_default(),
                                 last_price: lp,
                                 previous_close: q.regular_market_previous_close,
                                 currency: q.currency,
                                 ts,
-                            }).await.is_err() {
-                                // Break outer loop if receiver is dropped
-                                break;
+                            };
+                            match cfg.overflow {
+                                Overflow::Block => pending.push_back(update),
+                                Overflow::DropOldest => {
+                                    pending.push_back(update);
+                                    while pending.len() > backlog {
+                                        // Hand over directly if the channel has room,
+                                        // otherwise the oldest update is lost.
+                                        let Some(oldest) = pending.pop_front() else { break };
+                                        if let Err(mpsc::error::TrySendError::Closed(_)) = tx.try_send(oldest) {
+                                            break;
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
@@ -460,6 +742,15 @@ async fn run_polling_stream(
                 }
                 if tx.is_closed() { break; }
             }
+            permit = tx.reserve(), if !pending.is_empty() => {
+                let Ok(permit) = permit else { break };
+                if let Some(update) = pending.pop_front() {
+                    permit.send(update);
+                }
+            }
+            Some(change) = change_rx.recv() => {
+                change.apply(&mut symbols);
+            }
             _ = &mut *stop_rx => { break; }
         }
     }
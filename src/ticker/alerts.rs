@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// A price condition evaluated by [`Ticker::check_alerts`](crate::Ticker::check_alerts).
+///
+/// Level alerts (`Above`/`Below`) fire whenever the current price is on the given side of the
+/// level. Crossing alerts also need the price you observed last time, so they fire only on
+/// the check where the price moves through the level.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Alert {
+    /// The price is strictly above the level.
+    Above(f64),
+    /// The price is strictly below the level.
+    Below(f64),
+    /// The price was at or below `level` at `previous` and is now strictly above it.
+    CrossesAbove {
+        /// The level to cross.
+        level: f64,
+        /// The previously observed price.
+        previous: f64,
+    },
+    /// The price was at or above `level` at `previous` and is now strictly below it.
+    CrossesBelow {
+        /// The level to cross.
+        level: f64,
+        /// The previously observed price.
+        previous: f64,
+    },
+}
+
+impl Alert {
+    /// Returns `true` if this alert fires at `price`.
+    #[must_use]
+    pub fn is_triggered(&self, price: f64) -> bool {
+        match *self {
+            Self::Above(level) => price > level,
+            Self::Below(level) => price < level,
+            Self::CrossesAbove { level, previous } => previous <= level && price > level,
+            Self::CrossesBelow { level, previous } => previous >= level && price < level,
+        }
+    }
+}
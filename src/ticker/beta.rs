@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::core::{
+    Candle, YfClient, YfError,
+    client::{CacheMode, RetryConfig},
+    conversions::money_to_f64,
+    quotesummary,
+    wire::YahooNumber,
+};
+use crate::ticker::BetaSource;
+
+/// Fewest aligned daily returns a computed beta is based on.
+pub(super) const MIN_BETA_RETURNS: usize = 20;
+
+/// Fetches Yahoo's reported beta, preferring `summaryDetail.beta`, then
+/// `defaultKeyStatistics.beta`, then `defaultKeyStatistics.beta3Year`.
+pub(super) async fn fetch_reported_beta(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Option<(f64, BetaSource)>, YfError> {
+    let root: V10Result = quotesummary::fetch_module_result(
+        client,
+        symbol,
+        "summaryDetail,defaultKeyStatistics",
+        "beta",
+        cache_mode,
+        retry_override,
+    )
+    .await?;
+
+    let sd = root.summary_detail.unwrap_or_default();
+    let ks = root.default_key_statistics.unwrap_or_default();
+    Ok(sd
+        .beta
+        .get()
+        .or_else(|| ks.beta.get())
+        .map(|b| (b, BetaSource::Reported))
+        .or_else(|| ks.beta3_year.get().map(|b| (b, BetaSource::Reported3Year))))
+}
+
+/// Computes beta as `cov(asset, benchmark) / var(benchmark)` over the daily simple returns of
+/// the two series, aligned by UTC date.
+///
+/// Returns the beta and the number of returns it is based on, or `None` with fewer than
+/// [`MIN_BETA_RETURNS`] aligned returns or a flat benchmark.
+pub(super) fn compute_beta(asset: &[Candle], benchmark: &[Candle]) -> Option<(f64, usize)> {
+    let bench: BTreeMap<NaiveDate, f64> = benchmark
+        .iter()
+        .map(|c| (c.ts.date_naive(), money_to_f64(&c.close)))
+        .collect();
+    let pairs: Vec<(f64, f64)> = asset
+        .iter()
+        .filter_map(|c| {
+            let b = *bench.get(&c.ts.date_naive())?;
+            Some((money_to_f64(&c.close), b))
+        })
+        .collect();
+    let returns: Vec<(f64, f64)> = pairs
+        .windows(2)
+        .filter(|w| w[0].0 > 0.0 && w[0].1 > 0.0)
+        .map(|w| (w[1].0 / w[0].0 - 1.0, w[1].1 / w[0].1 - 1.0))
+        .filter(|(a, b)| a.is_finite() && b.is_finite())
+        .collect();
+    if returns.len() < MIN_BETA_RETURNS {
+        return None;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let n = returns.len() as f64;
+    let mean_a = returns.iter().map(|r| r.0).sum::<f64>() / n;
+    let mean_b = returns.iter().map(|r| r.1).sum::<f64>() / n;
+    let (cov, var) = returns.iter().fold((0.0, 0.0), |(cov, var), (a, b)| {
+        (
+            (a - mean_a).mul_add(b - mean_b, cov),
+            (b - mean_b).mul_add(b - mean_b, var),
+        )
+    });
+    (var > 0.0).then(|| (cov / var, returns.len()))
+}
+
+/* --- wire --- */
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct V10Result {
+    summary_detail: Option<BetaNode>,
+    default_key_statistics: Option<BetaNode>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct BetaNode {
+    beta: YahooNumber,
+    beta3_year: YahooNumber,
+}
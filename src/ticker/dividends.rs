@@ -0,0 +1,177 @@
+use chrono::{DateTime, Duration, Utc};
+use paft::money::{Currency, Money};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use super::model::{Dividend, DividendConversion};
+use crate::core::conversions::{f64_to_money_with_currency, money_to_f64};
+use crate::core::{Action, CacheMode, Range, RetryConfig, YfClient, YfError};
+use crate::fx::{self, FxBuilder, RateBasis};
+use crate::history::HistoryBuilder;
+
+/// A builder for fetching a ticker's dividend history, optionally converted to another currency.
+///
+/// Obtain one via [`Ticker::dividends_builder`](crate::Ticker::dividends_builder).
+#[derive(Debug, Clone)]
+pub struct DividendsBuilder {
+    client: YfClient,
+    symbol: String,
+    range: Range,
+    target: Option<(String, RateBasis)>,
+    cache_mode: CacheMode,
+    retry_override: Option<RetryConfig>,
+}
+
+impl DividendsBuilder {
+    pub(crate) fn new(client: &YfClient, symbol: impl Into<String>) -> Self {
+        Self {
+            client: client.clone(),
+            symbol: symbol.into(),
+            range: Range::Max,
+            target: None,
+            cache_mode: CacheMode::Use,
+            retry_override: None,
+        }
+    }
+
+    /// Sets the time range to fetch dividends for. Defaults to `Range::Max`.
+    #[must_use]
+    pub const fn range(mut self, range: Range) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Converts amounts into `currency` (an ISO 4217 code) using the [`fx`](crate::fx) module.
+    ///
+    /// With [`RateBasis::EventDate`] each dividend uses the closing rate on its ex-date (or the
+    /// last close before it); with [`RateBasis::Latest`] all dividends use the current rate.
+    /// Converted amounts are rounded to the target currency's minor units.
+    ///
+    /// Without this, dividends follow the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) at the latest rate, if set.
+    #[must_use]
+    pub fn convert_to(mut self, currency: impl Into<String>, basis: RateBasis) -> Self {
+        self.target = Some((currency.into(), basis));
+        self
+    }
+
+    /// Sets the cache mode for this specific API call.
+    #[must_use]
+    pub const fn cache_mode(mut self, mode: CacheMode) -> Self {
+        self.cache_mode = mode;
+        self
+    }
+
+    /// Overrides the default retry policy for this specific API call.
+    #[must_use]
+    pub fn retry_policy(mut self, cfg: Option<RetryConfig>) -> Self {
+        self.retry_override = cfg;
+        self
+    }
+
+    /// Fetches the dividends, sorted by timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::InvalidParams` for an unknown target currency, or any error from the
+    /// underlying chart or FX requests.
+    pub async fn fetch(self) -> Result<Vec<Dividend>, YfError> {
+        let resp = HistoryBuilder::new(&self.client, &self.symbol)
+            .range(self.range)
+            .auto_adjust(true)
+            .actions(true)
+            .cache_mode(self.cache_mode)
+            .retry_policy(self.retry_override.clone())
+            .fetch_full()
+            .await?;
+
+        let mut divs: Vec<(DateTime<Utc>, Money)> = resp
+            .actions
+            .into_iter()
+            .filter_map(|a| match a {
+                Action::Dividend { ts, amount } => Some((ts, amount)),
+                _ => None,
+            })
+            .collect();
+        divs.sort_by_key(|(ts, _)| *ts);
+
+        let display = self
+            .client
+            .display_currency()
+            .map(|c| (c.to_string(), RateBasis::Latest));
+        let Some((target, basis)) = self.target.clone().or(display) else {
+            return Ok(divs
+                .into_iter()
+                .map(|(ts, amount)| Dividend {
+                    ts,
+                    amount,
+                    conversion: None,
+                })
+                .collect());
+        };
+
+        let target_ccy = Currency::from_str(target.trim())
+            .map_err(|_| YfError::InvalidParams(format!("unknown currency: {target}")))?;
+        let fx = |source: &str| {
+            FxBuilder::new(&self.client, source, &target)
+                .cache_mode(self.cache_mode)
+                .retry_policy(self.retry_override.clone())
+        };
+
+        // Dividends can change currency (e.g. after a redomiciliation), so rates are looked up
+        // per source currency rather than once for the whole series.
+        let mut histories: HashMap<String, Vec<fx::FxRate>> = HashMap::new();
+        if basis == RateBasis::EventDate {
+            let mut spans: HashMap<String, (DateTime<Utc>, DateTime<Utc>)> = HashMap::new();
+            for (ts, amount) in &divs {
+                spans
+                    .entry(amount.currency().to_string())
+                    .and_modify(|(_, last)| *last = *ts)
+                    .or_insert((*ts, *ts));
+            }
+            for (source, (first, last)) in spans {
+                let history = fx(&source)
+                    .between(first - Duration::days(7), last + Duration::days(1))
+                    .await?;
+                histories.insert(source, history);
+            }
+        }
+
+        let mut latest: HashMap<String, f64> = HashMap::new();
+        let mut out = Vec::with_capacity(divs.len());
+        for (ts, original) in divs {
+            let source = original.currency().to_string();
+            let event_rate = histories
+                .get(&source)
+                .and_then(|history| fx::rate_at(history, ts));
+            let (rate, used) = match event_rate {
+                Some(r) => (r, RateBasis::EventDate),
+                None => {
+                    let rate = match latest.get(&source) {
+                        Some(r) => *r,
+                        None => {
+                            let r = fx(&source).latest().await?;
+                            latest.insert(source, r);
+                            r
+                        }
+                    };
+                    (rate, RateBasis::Latest)
+                }
+            };
+
+            let amount =
+                f64_to_money_with_currency(money_to_f64(&original) * rate, target_ccy.clone());
+
+            out.push(Dividend {
+                ts,
+                amount,
+                conversion: Some(DividendConversion {
+                    original,
+                    rate,
+                    basis: used,
+                }),
+            });
+        }
+        Ok(out)
+    }
+}
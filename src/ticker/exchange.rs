@@ -0,0 +1,35 @@
+use crate::YfError;
+
+/// Yahoo listing suffixes (the part after the `.` in `RY.TO`), sorted for binary search.
+const KNOWN_SUFFIXES: &[&str] = &[
+    "AS", "AT", "AX", "BA", "BC", "BD", "BE", "BK", "BO", "BR", "CBT", "CME", "CMX", "CN", "CO",
+    "CR", "DE", "DU", "F", "HE", "HK", "HM", "IC", "IL", "IR", "IS", "JK", "JO", "KL", "KQ", "KS",
+    "L", "LS", "MC", "ME", "MI", "MU", "MX", "NE", "NS", "NYB", "NYM", "NZ", "OL", "PA", "PR",
+    "QA", "RG", "SA", "SAU", "SG", "SI", "SN", "SR", "SS", "ST", "SW", "SZ", "T", "TA", "TI", "TL",
+    "TO", "TW", "TWO", "V", "VI", "VS", "WA",
+];
+
+fn is_known(suffix: &str) -> bool {
+    KNOWN_SUFFIXES.binary_search(&suffix).is_ok()
+}
+
+/// Appends a Yahoo exchange suffix to `symbol`, e.g. `("RY", "TO")` to `RY.TO`.
+///
+/// The suffix may be given with or without the leading dot and in any case. Unknown
+/// suffixes, and symbols that already carry a known suffix, are rejected.
+pub(super) fn symbol_on_exchange(symbol: &str, suffix: &str) -> Result<String, YfError> {
+    let suffix = suffix.trim().trim_start_matches('.').to_ascii_uppercase();
+    if !is_known(&suffix) {
+        return Err(YfError::InvalidParams(format!(
+            "unknown exchange suffix: {suffix:?}"
+        )));
+    }
+    if let Some((_, existing)) = symbol.rsplit_once('.')
+        && is_known(&existing.to_ascii_uppercase())
+    {
+        return Err(YfError::InvalidParams(format!(
+            "symbol {symbol:?} already has an exchange suffix"
+        )));
+    }
+    Ok(format!("{symbol}.{suffix}"))
+}
@@ -60,6 +60,40 @@ pub(super) async fn fetch_info(
     Ok(info)
 }
 
+/// Fetches the v7 quote and every supported `quoteSummary` module, merged as
+/// `{"quote": {...}, "quoteSummary": {"<module>": {...}, ...}}`.
+pub(super) async fn fetch_info_raw(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<serde_json::Value, YfError> {
+    let modules = crate::core::QuoteSummaryModule::all()
+        .iter()
+        .map(|m| m.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let (quote_res, summary_res) = tokio::join!(
+        crate::ticker::quote::fetch_quote_raw(client, symbol, None, cache_mode, retry_override),
+        crate::core::quotesummary::fetch_module_result::<serde_json::Value>(
+            client,
+            symbol,
+            &modules,
+            "info",
+            cache_mode,
+            retry_override,
+        )
+    );
+
+    let summary = summary_res?;
+    let quote = log_err_async(quote_res, "quote", symbol).unwrap_or(serde_json::Value::Null);
+    Ok(serde_json::json!({
+        "quote": quote,
+        "quoteSummary": summary,
+    }))
+}
+
 async fn fetch_info_parts(
     client: &YfClient,
     symbol: &str,
@@ -75,8 +109,9 @@ async fn fetch_info_parts(
     ),
     YfError,
 > {
+    let quote_opts = crate::ticker::quote::QuoteFetchOptions::new(cache_mode, retry_override);
     let (quote_res, profile_res, price_target_res, rec_summary_res, esg_res) = tokio::join!(
-        crate::ticker::quote::fetch_quote(client, symbol, cache_mode, retry_override),
+        crate::ticker::quote::fetch_quote(client, symbol, &quote_opts),
         crate::profile::load_profile(client, symbol),
         analysis::AnalysisBuilder::new(client, symbol)
             .cache_mode(cache_mode)
@@ -52,7 +52,7 @@ async fn fetch_isin_body(
     let mut url = client.base_insider_search().clone();
     url.query_pairs_mut()
         .append_pair("max_results", "5")
-        .append_pair("query", symbol);
+        .append_pair("query", client.yahoo_symbol(symbol));
 
     let req = client.http().get(url.clone());
     let resp = client.send_with_retry(req, retry_override).await?;
@@ -256,7 +256,38 @@ fn looks_like_isin(s: &str) -> bool {
     if !t[2..11].chars().all(|c| c.is_ascii_alphanumeric()) {
         return false;
     }
-    b[11].is_ascii_digit()
+    b[11].is_ascii_digit() && isin_checksum_ok(t)
+}
+
+/// Validates the ISIN check digit: letters expand to two digits (`A` = 10 … `Z` = 35) and
+/// the resulting digit string must pass the Luhn check.
+fn isin_checksum_ok(isin: &str) -> bool {
+    let mut digits: Vec<u32> = Vec::with_capacity(24);
+    for c in isin.chars() {
+        match c.to_digit(36) {
+            Some(v) if v >= 10 => {
+                digits.push(v / 10);
+                digits.push(v % 10);
+            }
+            Some(v) => digits.push(v),
+            None => return false,
+        }
+    }
+
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 1 {
+                let dd = d * 2;
+                dd / 10 + dd % 10
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
 }
 
 fn pick_from_parts(parts: &[String], target_norm: &str) -> Option<String> {
@@ -1,38 +1,55 @@
+mod alerts;
+mod beta;
+mod dividends;
+mod exchange;
 mod info;
 mod isin;
 mod model;
+mod option_chain;
 mod options;
-mod quote;
+pub(crate) mod quote;
+mod summary;
 
-pub use model::{FastInfo, Info, OptionChain, OptionContract};
+pub use alerts::Alert;
+pub use dividends::DividendsBuilder;
+pub use model::{
+    Beta, BetaSource, Dividend, DividendConversion, DividendYieldPoint, FastInfo, Freshness, Info,
+    IvSurface, KeyStatistics, MarketCapPoint, OptionChain, OptionContract, SummaryDetail,
+};
+pub use option_chain::OptionChainBuilder;
 
+use crate::analysis::{EpsTrendRow, PriceTargetConsensus, RecommendationConsensus};
 use crate::core::{Action, Candle, HistoryMeta, Interval, Quote, Range};
-use crate::fundamentals::{Calendar, ShareCount};
+use crate::esg::InvolvementFlags;
+use crate::fundamentals::{Calendar, EarningsEvent, ShareCount};
 use crate::holders::{
-    InsiderRosterHolder, InsiderTransaction, InstitutionalHolder, MajorHolder,
+    Holders, InsiderRosterHolder, InsiderTransaction, InstitutionalHolder, MajorHolder,
     NetSharePurchaseActivity,
 };
 use crate::news::NewsArticle;
+use crate::quote::{FullQuote, PricePreference, QuoteDetails};
+use crate::stream::{
+    ChangeThreshold, Overflow, QuoteUpdate, StreamBuilder, StreamHandle, StreamMethod,
+};
 use crate::{
     EsgBuilder,
     core::client::RetryConfig,
-    core::conversions::{
-        datetime_to_i64, exchange_to_string, market_state_to_string, money_to_currency_str,
-        money_to_f64,
-    },
+    core::conversions::{datetime_to_i64, f64_to_money_with_currency, money_to_f64},
     core::{CacheMode, YfClient, YfError},
     holders::HoldersBuilder,
-    news::NewsBuilder,
+    news::{NewsBuilder, NewsTab},
 };
 use crate::{
-    analysis::AnalysisBuilder, fundamentals::FundamentalsBuilder, history::HistoryBuilder,
+    analysis::AnalysisBuilder,
+    fundamentals::FundamentalsBuilder,
+    history::{AnnotatedCandle, HistoryBuilder, IntradayHistory, ReturnsSummary},
 };
 use paft::fundamentals::analysis::{
     Earnings, EarningsTrendRow, PriceTarget, RecommendationRow, RecommendationSummary,
     UpgradeDowngradeRow,
 };
 use paft::fundamentals::statements::{BalanceSheetRow, CashflowRow, IncomeStatementRow};
-use paft::money::Currency;
+use paft::money::{Currency, Money};
 use serde_json::Value;
 
 /// A high-level interface for a single ticker symbol, providing convenient access to all available data.
@@ -71,6 +88,11 @@ pub struct Ticker {
     #[doc(hidden)]
     cache_mode: CacheMode,
     retry_override: Option<RetryConfig>,
+    retry_null_price: bool,
+    chart_fallback: bool,
+    validate_prices: bool,
+    strict_symbol: bool,
+    provenance: bool,
 }
 
 impl Ticker {
@@ -83,9 +105,32 @@ impl Ticker {
             symbol: symbol.into(),
             cache_mode: CacheMode::Use,
             retry_override: None,
+            retry_null_price: false,
+            chart_fallback: false,
+            validate_prices: false,
+            strict_symbol: false,
+            provenance: false,
         }
     }
 
+    /// Creates a `Ticker` for the listing of `symbol` on a specific exchange.
+    ///
+    /// `exchange_suffix` is Yahoo's listing suffix, with or without the leading dot, so
+    /// `new_on_exchange(&client, "RY", "TO")` targets `RY.TO` on the Toronto Stock Exchange.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::InvalidParams` if the suffix is not a known Yahoo exchange suffix
+    /// or the symbol already carries one.
+    pub fn new_on_exchange(
+        client: &YfClient,
+        symbol: &str,
+        exchange_suffix: &str,
+    ) -> Result<Self, YfError> {
+        let symbol = exchange::symbol_on_exchange(symbol, exchange_suffix)?;
+        Ok(Self::new(client, symbol))
+    }
+
     /// Sets the cache mode for all subsequent API calls made by this `Ticker` instance.
     ///
     /// This allows you to override the client's default cache behavior for a specific ticker.
@@ -102,6 +147,62 @@ impl Ticker {
         self
     }
 
+    /// Sets whether [`quote`](Self::quote) refetches once when Yahoo momentarily returns a
+    /// node with neither a price nor a previous close. (Default: `false`)
+    #[must_use]
+    pub const fn retry_null_price(mut self, yes: bool) -> Self {
+        self.retry_null_price = yes;
+        self
+    }
+
+    /// Sets whether [`quote`](Self::quote) falls back to the v8 chart `meta` block when the v7
+    /// quote endpoint is still rate limited after retries. (Default: `false`)
+    ///
+    /// The fallback quote carries the price, previous close, currency and exchange only, and
+    /// goes through the same [`validate_prices`](Self::validate_prices) and
+    /// [`strict_symbol`](Self::strict_symbol) checks as a v7 quote.
+    #[must_use]
+    pub const fn chart_fallback(mut self, yes: bool) -> Self {
+        self.chart_fallback = yes;
+        self
+    }
+
+    /// Sets whether [`quote`](Self::quote) rejects obviously wrong prices with
+    /// `YfError::MissingData` instead of returning them. (Default: `false`)
+    ///
+    /// A price is rejected when it is negative, or when a pre/post-market price is more than
+    /// 50% away from the regular market price, guarding downstream calculations against
+    /// Yahoo glitches.
+    #[must_use]
+    pub const fn validate_prices(mut self, yes: bool) -> Self {
+        self.validate_prices = yes;
+        self
+    }
+
+    /// Sets whether [`quote`](Self::quote) fails with `YfError::SymbolMismatch` when Yahoo
+    /// answers with a different symbol than requested, e.g. after a rename or redirect, or
+    /// without any symbol. (Default: `false`)
+    ///
+    /// Symbols are compared case-insensitively, after applying
+    /// [`symbol_alias`](crate::YfClientBuilder::symbol_alias) mappings.
+    #[must_use]
+    pub const fn strict_symbol(mut self, yes: bool) -> Self {
+        self.strict_symbol = yes;
+        self
+    }
+
+    /// Sets whether [`fast_info`](Self::fast_info) records in
+    /// [`FastInfo::provenance`](crate::FastInfo::provenance) which Yahoo field each
+    /// fallback-resolved value came from. (Default: `false`)
+    ///
+    /// Useful when debugging data-quality issues, e.g. to tell a `last_price` that is really
+    /// the previous close from a live trade price.
+    #[must_use]
+    pub const fn provenance(mut self, yes: bool) -> Self {
+        self.provenance = yes;
+        self
+    }
+
     /// Fetches a comprehensive `Info` struct containing quote, profile, analysis, and ESG data.
     ///
     /// This method conveniently aggregates data from multiple endpoints into a single struct,
@@ -125,15 +226,105 @@ impl Ticker {
         .await
     }
 
+    /// Fetches the raw payloads behind [`info`](Self::info) as one JSON value.
+    ///
+    /// The v7 quote is placed under `"quote"` and all supported `quoteSummary` modules
+    /// under `"quoteSummary"`, keyed by module name, so fields from different sources never
+    /// collide. Useful for reading fields the typed `Info` does not expose yet. A failed
+    /// quote request yields `"quote": null`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the `quoteSummary` request fails or cannot be parsed.
+    pub async fn info_raw(&self) -> Result<Value, YfError> {
+        info::fetch_info_raw(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
     /* ---------------- Quotes ---------------- */
 
     /// Fetches a detailed quote for the ticker.
     ///
+    /// Prices are restated in the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) when one is set.
+    ///
     /// # Errors
     ///
     /// This method will return an error if the request fails or the response cannot be parsed.
     pub async fn quote(&self) -> Result<Quote, YfError> {
-        quote::fetch_quote(
+        let opts = quote::QuoteFetchOptions {
+            retry_null_price: self.retry_null_price,
+            chart_fallback: self.chart_fallback,
+            validate_prices: self.validate_prices,
+            strict_symbol: self.strict_symbol,
+            ..quote::QuoteFetchOptions::new(self.cache_mode, self.retry_override.as_ref())
+        };
+        let mut quote = quote::fetch_quote(&self.client, &self.symbol, &opts).await?;
+        crate::fx::to_display_currency(
+            &self.client,
+            std::slice::from_mut(&mut quote),
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await?;
+        Ok(quote)
+    }
+
+    /// Fetches a quote whose `price` follows `pref` during extended hours.
+    ///
+    /// With [`PricePreference::Extended`] the post-market price is reported while the market
+    /// state is `POST` and the pre-market price while it is `PRE`, so after-hours dashboards
+    /// do not show the stale regular close. [`quote`](Self::quote) behaves like
+    /// [`PricePreference::Regular`].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn quote_with_price_preference(
+        &self,
+        pref: PricePreference,
+    ) -> Result<Quote, YfError> {
+        quote::fetch_quote_with_preference(
+            &self.client,
+            &self.symbol,
+            pref,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
+    /// Fetches the quote along with fields outside the shared `Quote` model, such as the
+    /// instrument type and, for futures, contract symbol, expiration and open interest.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn quote_details(&self) -> Result<QuoteDetails, YfError> {
+        quote::fetch_quote_details(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
+    /// Fetches the regular, pre-market and post-market prices together, each with its
+    /// change, percentage change and update time.
+    ///
+    /// Sessions Yahoo does not report (e.g. pre-market for most non-US listings) are `None`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn full_quote(&self) -> Result<FullQuote, YfError> {
+        quote::fetch_full_quote(
             &self.client,
             &self.symbol,
             self.cache_mode,
@@ -164,36 +355,233 @@ impl Ticker {
         .await
     }
 
+    /// Fetches the previous close for the ticker.
+    ///
+    /// Yahoo exposes the previous close under several fields. The first one present wins:
+    /// `regularMarketPreviousClose` from the quote endpoint, then `previousClose` and
+    /// finally `chartPreviousClose` from the chart metadata. A quote request that is rate
+    /// limited (429) or failing with a server error (5xx) after retries falls through to the
+    /// chart metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::MissingData` if none of the candidate fields are present, or
+    /// another `YfError` if a request fails.
+    pub async fn previous_close(&self) -> Result<Money, YfError> {
+        quote::fetch_previous_close(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
     /// Fetches a "fast" info quote, containing the most essential price and market data.
     ///
+    /// Only a minimal set of v7 fields is requested (price, previous closes, currency,
+    /// market state and exchange name), keeping the payload small for frequent polling.
+    ///
+    /// In extended hours (`PRE`/`POST`) `previous_close` is the close of the last regular
+    /// session (or Yahoo's `preMarketPreviousClose`/`postMarketPreviousClose` when present),
+    /// so changes computed from it match the extended-hours session.
+    ///
+    /// [`FastInfo::freshness`] turns the market state and update time into a display badge.
+    /// Prices are restated in the client's
+    /// [`display_currency`](crate::YfClientBuilder::display_currency) when one is set.
+    ///
     /// # Errors
     ///
     /// This method will return an error if the request fails, the response cannot be parsed,
     /// or if the last/previous price is not available in the quote.
     pub async fn fast_info(&self) -> Result<FastInfo, YfError> {
-        let q = self.quote().await?;
-        let last = q
-            .price
-            .as_ref()
-            .map(money_to_f64)
-            .or_else(|| q.previous_close.as_ref().map(money_to_f64))
-            .ok_or_else(|| YfError::MissingData("quote missing last/previous price".into()))?;
-
-        // Extract currency from the price or previous_close Money objects
-        let currency = q
-            .price
-            .as_ref()
-            .and_then(money_to_currency_str)
-            .or_else(|| q.previous_close.as_ref().and_then(money_to_currency_str));
-
-        Ok(FastInfo {
-            symbol: q.symbol,
-            last_price: last,
-            previous_close: q.previous_close.as_ref().map(money_to_f64),
-            currency,
-            exchange: exchange_to_string(q.exchange),
-            market_state: market_state_to_string(q.market_state),
-        })
+        let mut info = quote::fetch_fast_info(
+            &self.client,
+            &self.symbol,
+            self.provenance,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await?;
+        crate::fx::fast_info_to_display_currency(
+            &self.client,
+            std::slice::from_mut(&mut info),
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await?;
+        Ok(info)
+    }
+
+    /// Fetches trading and valuation figures from Yahoo's `summaryDetail` module.
+    ///
+    /// This overlaps with the v7 quote but adds fields such as beta, payout ratio and the
+    /// dividend rate and yield.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the module is missing from the response.
+    pub async fn summary_detail(&self) -> Result<SummaryDetail, YfError> {
+        summary::fetch_summary_detail(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
+    /// Fetches share, short-interest and valuation statistics from Yahoo's
+    /// `defaultKeyStatistics` module.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the module is missing from the response.
+    pub async fn key_statistics(&self) -> Result<KeyStatistics, YfError> {
+        summary::fetch_key_statistics(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
+    /// Returns the beta against the market, preferring the value Yahoo reports.
+    ///
+    /// Yahoo's `beta` (from `summaryDetail` or `defaultKeyStatistics`) is used first, then
+    /// the fund-style `beta3Year`. If Yahoo reports neither and a `benchmark` symbol is given
+    /// (e.g. `^GSPC`), beta is computed from one year of daily adjusted closes of both symbols.
+    ///
+    /// Returns `None` when nothing is reported and either no benchmark was given or the
+    /// histories overlap on fewer than 21 days.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or a response cannot be parsed.
+    pub async fn beta(&self, benchmark: Option<&str>) -> Result<Option<Beta>, YfError> {
+        if let Some((value, source)) = beta::fetch_reported_beta(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await?
+        {
+            return Ok(Some(Beta { value, source }));
+        }
+        let Some(benchmark) = benchmark else {
+            return Ok(None);
+        };
+
+        let daily = |symbol: &str| {
+            HistoryBuilder::new(&self.client, symbol)
+                .range(Range::Y1)
+                .interval(Interval::D1)
+                .cache_mode(self.cache_mode)
+                .retry_policy(self.retry_override.clone())
+                .fetch()
+        };
+        let (asset, bench) =
+            futures::future::try_join(daily(&self.symbol), daily(benchmark)).await?;
+
+        Ok(
+            beta::compute_beta(&asset, &bench).map(|(value, periods)| Beta {
+                value,
+                source: BetaSource::Computed {
+                    benchmark: benchmark.to_string(),
+                    periods,
+                },
+            }),
+        )
+    }
+
+    /// Fetches quotes for every component of this index in batched requests of up to 100
+    /// symbols each.
+    ///
+    /// Components are resolved through Yahoo's `components` quoteSummary module, e.g. for
+    /// `^DJI`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::NotFound` if Yahoo has no component list for this symbol, or an error
+    /// if either request fails.
+    pub async fn constituents(&self) -> Result<Vec<Quote>, YfError> {
+        quote::fetch_constituents(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
+    /// Fetches the current price and returns the alerts that fire at it, in input order.
+    ///
+    /// The price is read the same way as [`fast_info`](Self::fast_info): the last trade, or
+    /// the previous close when no trade is available. Crossing alerts compare against the
+    /// `previous` price stored in the alert, typically the price seen on the last check.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the quote request fails or no price is available.
+    pub async fn check_alerts(&self, alerts: &[Alert]) -> Result<Vec<Alert>, YfError> {
+        let price = self.fast_info().await?.last_price;
+        Ok(alerts
+            .iter()
+            .filter(|a| a.is_triggered(price))
+            .copied()
+            .collect())
+    }
+
+    /// Starts a polling quote stream for this ticker that only emits on material moves.
+    ///
+    /// The first observation is always emitted; after that an update is sent only when the
+    /// price has moved by more than `threshold` since the last emitted update. Use
+    /// [`StreamBuilder`] directly for multi-symbol or WebSocket streams.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream cannot be started.
+    pub fn quotes_stream_changes(
+        &self,
+        threshold: ChangeThreshold,
+        interval: std::time::Duration,
+    ) -> Result<(StreamHandle, tokio::sync::mpsc::Receiver<QuoteUpdate>), YfError> {
+        StreamBuilder::new(&self.client)
+            .symbols([self.symbol.as_str()])
+            .method(StreamMethod::Polling)
+            .interval(interval)
+            .min_change(threshold)
+            .cache_mode(self.cache_mode)
+            .retry_policy(self.retry_override.clone())
+            .start()
+    }
+
+    /// Starts a polling quote stream for this ticker that holds at most `buffer` unread
+    /// updates, applying `overflow` when the consumer falls behind.
+    ///
+    /// With [`Overflow::Block`] polling pauses until the consumer catches up, so the crate
+    /// never fetches further ahead than the buffer allows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buffer` is zero or the stream cannot be started.
+    pub fn quote_stream_throttled(
+        &self,
+        interval: std::time::Duration,
+        buffer: usize,
+        overflow: Overflow,
+    ) -> Result<(StreamHandle, tokio::sync::mpsc::Receiver<QuoteUpdate>), YfError> {
+        StreamBuilder::new(&self.client)
+            .symbols([self.symbol.as_str()])
+            .method(StreamMethod::Polling)
+            .interval(interval)
+            .buffer(buffer)
+            .overflow(overflow)
+            .cache_mode(self.cache_mode)
+            .retry_policy(self.retry_override.clone())
+            .start()
     }
 
     /* ---------------- News convenience ---------------- */
@@ -215,6 +603,30 @@ impl Ticker {
         self.news_builder().fetch().await
     }
 
+    /// Returns links to earnings call transcripts found in the ticker's recent news.
+    ///
+    /// This scans the 50 most recent items across all news types and keeps those whose title or
+    /// link mentions a transcript, newest first as Yahoo orders them. Yahoo does not expose
+    /// transcripts directly, so the result is empty when none were published recently.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the news request fails or the response cannot be
+    /// parsed.
+    pub async fn earnings_call_transcripts(&self) -> Result<Vec<String>, YfError> {
+        let articles = self
+            .news_builder()
+            .tab(NewsTab::All)
+            .count(50)
+            .fetch()
+            .await?;
+        Ok(articles
+            .into_iter()
+            .filter(crate::news::is_earnings_transcript)
+            .filter_map(|a| a.link)
+            .collect())
+    }
+
     /* ---------------- History helpers ---------------- */
 
     /// Returns a `HistoryBuilder` to construct a detailed query for historical price data.
@@ -228,8 +640,10 @@ impl Ticker {
     /// Prices are automatically adjusted for splits and dividends. For more control, use [`history_builder`].
     ///
     /// # Arguments
-    /// * `range` - The relative time range for the data (e.g., `1y`, `6mo`). Defaults to `6mo` if `None`.
-    /// * `interval` - The time interval for each candle (e.g., `1d`, `1wk`). Defaults to `1d` if `None`.
+    /// * `range` - The relative time range for the data (e.g., `1y`, `6mo`). Defaults to the client's
+    ///   [`default_history`](crate::YfClientBuilder::default_history) range, or `6mo`, if `None`.
+    /// * `interval` - The time interval for each candle (e.g., `1d`, `1wk`). Defaults to the client's
+    ///   default interval, or `1d`, if `None`.
     /// * `prepost` - Whether to include pre-market and post-market data for intraday intervals.
     ///
     /// # Errors
@@ -241,20 +655,135 @@ impl Ticker {
         interval: Option<Interval>,
         prepost: bool,
     ) -> Result<Vec<Candle>, crate::core::YfError> {
+        self.default_history_builder(range, interval, prepost)
+            .fetch()
+            .await
+    }
+
+    /// Fetches historical price candles like [`history`](Self::history), each annotated with
+    /// the dividend or split that fell within its period.
+    ///
+    /// Useful for marking events on a price chart. See
+    /// [`HistoryResponseExt::annotated_candles`](crate::HistoryResponseExt::annotated_candles)
+    /// for how events are matched to candles.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn history_annotated(
+        &self,
+        range: Option<Range>,
+        interval: Option<Interval>,
+        prepost: bool,
+    ) -> Result<Vec<AnnotatedCandle>, YfError> {
+        self.default_history_builder(range, interval, prepost)
+            .fetch_annotated()
+            .await
+    }
+
+    /// Fetches intraday candles over the longest window Yahoo allows for `interval`, ending now.
+    ///
+    /// Yahoo silently truncates intraday requests that reach too far back, so the window is
+    /// picked from [`max_intraday_lookback`](crate::max_intraday_lookback) (e.g. 7 days for
+    /// `1m`, 60 days for `5m`) and returned alongside the candles. Prices are adjusted and
+    /// pre/post-market candles are excluded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::InvalidParams` if `interval` is not intraday, or an error if the
+    /// request fails or the response cannot be parsed.
+    pub async fn history_intraday(&self, interval: Interval) -> Result<IntradayHistory, YfError> {
+        let lookback = crate::history::max_intraday_lookback(interval).ok_or_else(|| {
+            YfError::InvalidParams(format!(
+                "{} is not an intraday interval",
+                crate::core::models::interval_as_str(interval)
+            ))
+        })?;
+        let end = self.client.now();
+        let start = end - lookback;
+
+        let candles = self
+            .history_builder()
+            .interval(interval)
+            .between(start, end)
+            .auto_adjust(true)
+            .actions(true)
+            .cache_mode(self.cache_mode)
+            .retry_policy(self.retry_override.clone())
+            .fetch()
+            .await?;
+
+        Ok(IntradayHistory {
+            interval,
+            start,
+            end,
+            candles,
+        })
+    }
+
+    fn default_history_builder(
+        &self,
+        range: Option<Range>,
+        interval: Option<Interval>,
+        prepost: bool,
+    ) -> HistoryBuilder {
+        let default = self.client.default_history();
         let mut hb = self.history_builder();
-        if let Some(r) = range {
+        if let Some(r) = range.or(default.map(|(r, _)| r)) {
             hb = hb.range(r);
         }
-        if let Some(i) = interval {
+        if let Some(i) = interval.or(default.map(|(_, i)| i)) {
             hb = hb.interval(i);
         }
-        hb = hb
-            .auto_adjust(true)
+        hb.auto_adjust(true)
             .prepost(prepost)
             .actions(true)
             .cache_mode(self.cache_mode)
-            .retry_policy(self.retry_override.clone());
-        hb.fetch().await
+            .retry_policy(self.retry_override.clone())
+    }
+
+    /// Fetches adjusted daily history over `range` (default `1y`) and summarizes it as
+    /// annualized return, volatility, max drawdown and Sharpe ratio.
+    ///
+    /// `risk_free_rate` is an annual rate as a fraction (e.g. `0.04`). See
+    /// [`ReturnsSummary`] for the exact definitions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::MissingData` if the window has fewer than three closes, or an error
+    /// if the history request fails.
+    pub async fn price_history_returns_summary(
+        &self,
+        range: Option<Range>,
+        risk_free_rate: f64,
+    ) -> Result<ReturnsSummary, YfError> {
+        let candles = self
+            .history(Some(range.unwrap_or(Range::Y1)), Some(Interval::D1), false)
+            .await?;
+        ReturnsSummary::from_candles(&candles, risk_free_rate)
+    }
+
+    /// Python-style alias for [`history`](Self::history), taking `period` and `interval` as
+    /// yfinance strings (e.g. `"1mo"`, `"1d"`).
+    ///
+    /// `ticker.download("1mo", "1d")` issues exactly the same request as
+    /// `ticker.history(Some(Range::M1), Some(Interval::D1), false)`, which remains the
+    /// canonical API; this exists so code ported from Python maps over mechanically.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::InvalidParams` for an unrecognized `period` or `interval`, or any
+    /// error from [`history`](Self::history).
+    pub async fn download(
+        &self,
+        period: &str,
+        interval: &str,
+    ) -> Result<Vec<Candle>, crate::core::YfError> {
+        let range = crate::core::models::parse_range(period)
+            .ok_or_else(|| YfError::InvalidParams(format!("unknown period: {period}")))?;
+        let interval = crate::core::models::parse_interval(interval)
+            .ok_or_else(|| YfError::InvalidParams(format!("unknown interval: {interval}")))?;
+        self.history(Some(range), Some(interval), false).await
     }
 
     /// Fetches all corporate actions (dividends and splits) for the given range.
@@ -279,7 +808,8 @@ impl Ticker {
 
     /// Fetches all dividend payments for the given range.
     ///
-    /// Returns a `Vec` of tuples containing `(timestamp, amount)`.
+    /// Returns a `Vec` of tuples containing `(timestamp, amount)`. Amounts are as Yahoo reports
+    /// them: already restated for any later splits, so they are comparable across splits.
     /// Defaults to the maximum available range if `None`.
     ///
     /// # Errors
@@ -298,6 +828,122 @@ impl Ticker {
             .collect())
     }
 
+    /// Fetches dividend payments restated in today's share terms, for the given range.
+    ///
+    /// Yahoo already divides each dividend by the ratio of all later splits (a dividend paid
+    /// before a 2:1 split arrives halved), so this returns the same amounts as
+    /// [`dividends`](Self::dividends). It exists to make that adjustment explicit for
+    /// dividend-growth analysis across splits. Returns `(timestamp, amount)` tuples; defaults
+    /// to the maximum available range if `None`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn adjusted_dividends(
+        &self,
+        range: Option<Range>,
+    ) -> Result<Vec<(i64, f64)>, YfError> {
+        self.dividends(range).await
+    }
+
+    /// Computes a trailing-twelve-month dividend yield series for the given range.
+    ///
+    /// For each daily candle, the dividends paid in the preceding 365 days are summed and
+    /// divided by that day's unadjusted close. Yahoo reports dividends split-adjusted, so each
+    /// amount is first restated per share as of the candle (undoing splits that happen after
+    /// it). Candles less than a year after the start of the range have no complete trailing
+    /// window and report `None`; the yield is also `None` when a dividend in the window is in a
+    /// different currency than the close. Defaults to a five-year range if `None`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn dividend_yield_history(
+        &self,
+        range: Option<Range>,
+    ) -> Result<Vec<DividendYieldPoint>, YfError> {
+        let resp = self
+            .history_builder()
+            .range(range.unwrap_or(Range::Y5))
+            .auto_adjust(false)
+            .actions(true)
+            .cache_mode(self.cache_mode)
+            .retry_policy(self.retry_override.clone())
+            .fetch_full()
+            .await?;
+
+        let splits: Vec<_> = resp
+            .actions
+            .iter()
+            .filter_map(|a| match *a {
+                Action::Split {
+                    ts,
+                    numerator,
+                    denominator,
+                } if numerator > 0 && denominator > 0 => {
+                    Some((ts, f64::from(numerator) / f64::from(denominator)))
+                }
+                _ => None,
+            })
+            .collect();
+        let dividends: Vec<_> = resp
+            .actions
+            .iter()
+            .filter_map(|a| match a {
+                Action::Dividend { ts, amount } => Some((*ts, amount)),
+                _ => None,
+            })
+            .collect();
+
+        let Some(first) = resp.candles.first().map(|c| c.ts) else {
+            return Ok(Vec::new());
+        };
+        let year = chrono::Duration::days(365);
+
+        Ok(resp
+            .candles
+            .into_iter()
+            .map(|c| {
+                // Splits after this candle are not yet reflected in its unadjusted close.
+                let factor: f64 = splits
+                    .iter()
+                    .filter(|(split_ts, _)| *split_ts > c.ts)
+                    .map(|(_, ratio)| ratio)
+                    .product();
+                let window: Vec<_> = dividends
+                    .iter()
+                    .filter(|(ts, _)| *ts > c.ts - year && *ts <= c.ts)
+                    .collect();
+                let ttm_dividends = (c.ts - year >= first).then(|| {
+                    window
+                        .iter()
+                        .map(|(_, amount)| money_to_f64(amount) * factor)
+                        .sum::<f64>()
+                });
+                let same_currency = window
+                    .iter()
+                    .all(|(_, amount)| amount.currency() == c.close.currency());
+                let close = money_to_f64(&c.close);
+                DividendYieldPoint {
+                    ts: c.ts,
+                    dividend_yield: ttm_dividends
+                        .filter(|_| same_currency && close > 0.0)
+                        .map(|d| d / close),
+                    ttm_dividends,
+                    close: c.close,
+                }
+            })
+            .collect())
+    }
+
+    /// Returns a [`DividendsBuilder`] for dividend history with optional currency conversion.
+    #[must_use]
+    pub fn dividends_builder(&self) -> DividendsBuilder {
+        DividendsBuilder::new(&self.client, &self.symbol)
+            .cache_mode(self.cache_mode)
+            .retry_policy(self.retry_override.clone())
+    }
+
     /// Fetches all stock splits for the given range.
     ///
     /// Returns a `Vec` of tuples containing `(timestamp, numerator, denominator)`.
@@ -412,6 +1058,36 @@ impl Ticker {
         .await
     }
 
+    /// Returns an [`OptionChainBuilder`] for an option chain with an optional strike filter.
+    #[must_use]
+    pub fn option_chain_builder(&self) -> OptionChainBuilder {
+        OptionChainBuilder::new(&self.client, &self.symbol)
+            .cache_mode(self.cache_mode)
+            .retry_policy(self.retry_override.clone())
+    }
+
+    /// Fetches every expiration's chain and assembles an implied volatility surface.
+    ///
+    /// The surface is a strike × expiration grid of call and put implied volatilities, with
+    /// each strike's moneyness measured against the underlying's `regularMarketPrice` from
+    /// the options response. Strikes missing for an expiration are `None`.
+    ///
+    /// This issues one request per expiration, a few at a time, so it can be slow for symbols
+    /// with many listed expirations.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if any request fails or a response cannot be parsed.
+    pub async fn options_implied_volatility_surface(&self) -> Result<IvSurface, YfError> {
+        options::iv_surface(
+            &self.client,
+            &self.symbol,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await
+    }
+
     /* ---------------- Holders convenience ---------------- */
 
     fn holders_builder(&self) -> HoldersBuilder {
@@ -420,6 +1096,17 @@ impl Ticker {
             .retry_policy(self.retry_override.clone())
     }
 
+    /// Fetches major, institutional and insider roster holders in one batched request.
+    ///
+    /// Sections that fail to parse are left empty and reported in [`Holders::errors`].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or returns no result.
+    pub async fn holders(&self) -> Result<Holders, YfError> {
+        self.holders_builder().all().await
+    }
+
     /// Fetches the major holders breakdown (e.g., % insiders, % institutions).
     ///
     /// # Errors
@@ -502,6 +1189,17 @@ impl Ticker {
         self.analysis_builder().recommendations_summary().await
     }
 
+    /// Collapses the analyst recommendation trend into a weighted consensus score
+    /// (`strongBuy = 1` … `strongSell = 5`) and whether it improved since the prior period.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails, the response cannot be parsed,
+    /// or Yahoo reports no recommendation periods.
+    pub async fn recommendation_consensus(&self) -> Result<RecommendationConsensus, YfError> {
+        self.analysis_builder().recommendation_consensus().await
+    }
+
     /// Fetches the history of analyst upgrades and downgrades.
     ///
     /// # Errors
@@ -528,6 +1226,24 @@ impl Ticker {
             .await
     }
 
+    /// Fetches the analyst price targets with the current price and the implied upside
+    /// percent to the mean target.
+    ///
+    /// Provide `Some(currency)` to override the inferred reporting currency; pass `None`
+    /// to use the cached profile-based heuristic.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn price_targets_consensus(
+        &self,
+        override_currency: Option<Currency>,
+    ) -> Result<PriceTargetConsensus, YfError> {
+        self.analysis_builder()
+            .price_targets_consensus(override_currency)
+            .await
+    }
+
     /// Fetches earnings trend data for the ticker.
     ///
     /// This includes earnings estimates, revenue estimates, EPS trends, and EPS revisions for various periods.
@@ -547,6 +1263,22 @@ impl Ticker {
             .await
     }
 
+    /// Fetches how the consensus EPS estimate has been revised over the last 7/30/60/90 days
+    /// for each estimate period.
+    ///
+    /// Provide `Some(currency)` to override the inferred reporting currency; pass `None`
+    /// to use the cached profile-based heuristic.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn eps_trend(
+        &self,
+        override_currency: Option<Currency>,
+    ) -> Result<Vec<EpsTrendRow>, YfError> {
+        self.analysis_builder().eps_trend(override_currency).await
+    }
+
     /* ---------------- ESG / Sustainability ---------------- */
 
     fn esg_builder(&self) -> EsgBuilder {
@@ -563,6 +1295,16 @@ impl Ticker {
     pub async fn sustainability(&self) -> Result<paft::fundamentals::esg::EsgSummary, YfError> {
         self.esg_builder().fetch().await
     }
+
+    /// Fetches the ESG product-involvement flags (alcohol, tobacco, gambling, controversial
+    /// weapons, ...) for socially-responsible-investing screens.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn sustainability_involvement(&self) -> Result<InvolvementFlags, YfError> {
+        self.esg_builder().involvement_flags().await
+    }
     /* ---------------- Fundamentals convenience ---------------- */
 
     fn fundamentals_builder(&self) -> FundamentalsBuilder {
@@ -673,6 +1415,18 @@ impl Ticker {
             .await
     }
 
+    /// Fetches the raw income statement, balance sheet and cash flow payloads in one request.
+    ///
+    /// Set `quarterly` to `true` for quarterly statements. See
+    /// [`FundamentalsBuilder::statements_raw`] for the layout of the returned value.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn financials_raw(&self, quarterly: bool) -> Result<Value, YfError> {
+        self.fundamentals_builder().statements_raw(quarterly).await
+    }
+
     /// Fetches earnings history and estimates.
     ///
     /// Provide `Some(currency)` to override the inferred reporting currency; pass `None`
@@ -696,6 +1450,45 @@ impl Ticker {
         self.fundamentals_builder().calendar().await
     }
 
+    /// Fetches the next ex-dividend date from Yahoo's `calendarEvents` module.
+    ///
+    /// Returns `None` for symbols that do not pay dividends or have no upcoming ex-date. See
+    /// [`FundamentalsBuilder::ex_dividend_date`] for which date is reported.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn ex_dividend_date(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>, YfError> {
+        self.fundamentals_builder().ex_dividend_date().await
+    }
+
+    /// Fetches the raw `calendarEvents` payload behind [`calendar`](Self::calendar).
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the module is missing.
+    pub async fn calendar_events_raw(&self) -> Result<Value, YfError> {
+        self.fundamentals_builder().calendar_events_raw().await
+    }
+
+    /// Fetches the full earnings picture: reported quarters with actual, estimate and
+    /// surprise, followed by the next scheduled report date, sorted chronologically.
+    ///
+    /// Provide `Some(currency)` to override the inferred reporting currency; pass `None`
+    /// to use the cached profile-based heuristic.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the request fails or the response cannot be parsed.
+    pub async fn earnings_schedule(
+        &self,
+        override_currency: Option<Currency>,
+    ) -> Result<Vec<EarningsEvent>, YfError> {
+        self.fundamentals_builder()
+            .earnings_schedule(override_currency)
+            .await
+    }
+
     /// Fetches historical annual shares outstanding.
     ///
     /// # Errors
@@ -713,4 +1506,92 @@ impl Ticker {
     pub async fn quarterly_shares(&self) -> Result<Vec<ShareCount>, YfError> {
         self.fundamentals_builder().shares(true).await
     }
+
+    /// Computes a historical market capitalization series for the given range.
+    ///
+    /// Each daily unadjusted close is multiplied by the shares outstanding from the most
+    /// recent report on or before that day (a step function between reporting dates). A split
+    /// between that report and the candle scales the reported count by its ratio, so the share
+    /// count always matches the unadjusted price. Quarterly share counts are used, falling back
+    /// to annual ones when no quarterly data is available. Candles that precede the first
+    /// report are skipped.
+    /// Defaults to a one-year range if `None`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if either request fails or a response cannot be parsed.
+    pub async fn historical_market_cap(
+        &self,
+        range: Option<Range>,
+    ) -> Result<Vec<MarketCapPoint>, YfError> {
+        let resp = self
+            .history_builder()
+            .range(range.unwrap_or(Range::Y1))
+            .auto_adjust(false)
+            .actions(true)
+            .cache_mode(self.cache_mode)
+            .retry_policy(self.retry_override.clone())
+            .fetch_full()
+            .await?;
+        let candles = resp.candles;
+        let splits: Vec<_> = resp
+            .actions
+            .iter()
+            .filter_map(|a| match *a {
+                Action::Split {
+                    ts,
+                    numerator,
+                    denominator,
+                } if numerator > 0 && denominator > 0 => {
+                    Some((ts, f64::from(numerator) / f64::from(denominator)))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let (Some(first), Some(last)) = (candles.first(), candles.last()) else {
+            return Ok(Vec::new());
+        };
+        // Look back far enough to find the report in effect at the first candle.
+        let start = first.ts - chrono::Duration::days(400);
+        let end = last.ts + chrono::Duration::days(1);
+
+        let fb = self.fundamentals_builder();
+        let mut shares = fb.shares_between(start, end, true).await?;
+        if shares.is_empty() {
+            shares = fb.shares_between(start, end, false).await?;
+        }
+        shares.sort_by_key(|s| s.date);
+
+        let mut out = Vec::with_capacity(candles.len());
+        let mut idx = 0usize;
+        let mut current: Option<&ShareCount> = None;
+        for c in candles {
+            while idx < shares.len() && shares[idx].date <= c.ts {
+                current = Some(&shares[idx]);
+                idx += 1;
+            }
+            let Some(report) = current else { continue };
+            // Splits effective after the report but by this candle are not in its count yet.
+            let factor: f64 = splits
+                .iter()
+                .filter(|(split_ts, _)| *split_ts > report.date && *split_ts <= c.ts)
+                .map(|(_, ratio)| ratio)
+                .product();
+            #[allow(
+                clippy::cast_precision_loss,
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss
+            )]
+            let n = (report.shares as f64 * factor).round() as u64;
+            #[allow(clippy::cast_precision_loss)]
+            let cap = money_to_f64(&c.close) * n as f64;
+            out.push(MarketCapPoint {
+                ts: c.ts,
+                shares: n,
+                market_cap: f64_to_money_with_currency(cap, c.close.currency().clone()),
+            });
+        }
+        Ok(out)
+    }
 }
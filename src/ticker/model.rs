@@ -1,5 +1,10 @@
+use chrono::{DateTime, Utc};
 use paft::fundamentals::profile::Address;
+use paft::money::Money;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::fx::RateBasis;
 
 // Re-export types from paft without using prelude
 pub use paft::market::options::{OptionChain, OptionContract};
@@ -19,6 +24,292 @@ pub struct FastInfo {
     pub market_state: Option<String>,
     /// Primary exchange name.
     pub exchange: Option<String>,
+    /// When the regular-market price was last updated (`regularMarketTime`).
+    pub regular_market_time: Option<DateTime<Utc>>,
+    /// The v7 field each fallback-resolved output was read from, keyed by output field name
+    /// (`last_price`, `previous_close`, `exchange`), e.g. `"last_price"` →
+    /// `"regularMarketPreviousClose"` when no trade price was reported.
+    ///
+    /// Only populated when requested with [`Ticker::provenance`](crate::Ticker::provenance);
+    /// `None` otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<BTreeMap<String, String>>,
+}
+
+impl FastInfo {
+    /// Returns `true` if the price is older than `max_age`, or if Yahoo did not report when
+    /// it was last updated.
+    #[must_use]
+    pub fn is_stale(&self, max_age: std::time::Duration) -> bool {
+        self.is_stale_at(max_age, Utc::now())
+    }
+
+    /// Like [`is_stale`](Self::is_stale), but measured against `now` instead of the clock.
+    #[must_use]
+    pub fn is_stale_at(&self, max_age: std::time::Duration, now: DateTime<Utc>) -> bool {
+        crate::quote::is_stale(self.regular_market_time, max_age, now)
+    }
+
+    /// Classifies how current `last_price` is, for badging prices in a UI.
+    ///
+    /// Outside the regular session (any `market_state` other than `REGULAR`) the price is
+    /// [`Freshness::Closed`]. During the session the age of `regular_market_time` decides
+    /// between [`Live`](Freshness::Live), [`Delayed`](Freshness::Delayed) (up to
+    /// [`Freshness::DELAYED_MAX_AGE`], covering exchanges Yahoo serves with a 15-minute delay)
+    /// and [`Stale`](Freshness::Stale). A missing update time during the session is `Stale`.
+    #[must_use]
+    pub fn freshness(&self) -> Freshness {
+        self.freshness_at(Utc::now())
+    }
+
+    /// Like [`freshness`](Self::freshness), but measured against `now` instead of the clock.
+    #[must_use]
+    pub fn freshness_at(&self, now: DateTime<Utc>) -> Freshness {
+        Freshness::classify(self.market_state.as_deref(), self.regular_market_time, now)
+    }
+}
+
+/// How current a quoted price is, derived from `marketState` and `regularMarketTime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Freshness {
+    /// The market is open and the price was updated within [`Freshness::LIVE_MAX_AGE`].
+    Live,
+    /// The market is open and the price is within Yahoo's usual exchange delay.
+    Delayed,
+    /// The market is open but the price has not been updated recently.
+    Stale,
+    /// The regular session is not trading (pre/post-market, closed, or holiday).
+    Closed,
+}
+
+impl Freshness {
+    /// Maximum age of a regular-session price that is still considered live.
+    pub const LIVE_MAX_AGE: std::time::Duration = std::time::Duration::from_secs(2 * 60);
+    /// Maximum age of a regular-session price that is considered delayed rather than stale.
+    pub const DELAYED_MAX_AGE: std::time::Duration = std::time::Duration::from_secs(20 * 60);
+
+    pub(crate) fn classify(
+        market_state: Option<&str>,
+        updated: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        if market_state.is_some_and(|s| !s.eq_ignore_ascii_case("REGULAR")) {
+            return Self::Closed;
+        }
+        let Some(updated) = updated else {
+            return Self::Stale;
+        };
+        let age = (now - updated).to_std().unwrap_or_default();
+        if age <= Self::LIVE_MAX_AGE {
+            Self::Live
+        } else if age <= Self::DELAYED_MAX_AGE {
+            Self::Delayed
+        } else {
+            Self::Stale
+        }
+    }
+}
+
+/// Where a [`Beta`] value comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BetaSource {
+    /// Yahoo's reported `beta` (`summaryDetail`, else `defaultKeyStatistics`).
+    Reported,
+    /// Yahoo's reported three-year `beta3Year`, typically for funds.
+    Reported3Year,
+    /// Computed from one year of daily returns against `benchmark`.
+    Computed {
+        /// The benchmark symbol, e.g. `^GSPC`.
+        benchmark: String,
+        /// Number of aligned daily returns the value is based on.
+        periods: usize,
+    },
+}
+
+/// A beta against the market, with where it came from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Beta {
+    /// The beta value.
+    pub value: f64,
+    /// Whether Yahoo reported it or it was computed.
+    pub source: BetaSource,
+}
+
+/// Trading and valuation figures from Yahoo's `summaryDetail` module.
+///
+/// Prices and market cap are in the instrument's trading `currency`. Yields and ratios are
+/// plain fractions as Yahoo reports them (e.g. `0.0044` for a 0.44% dividend yield).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummaryDetail {
+    /// ISO currency code the monetary fields are quoted in.
+    pub currency: Option<String>,
+    /// Previous session's closing price.
+    pub previous_close: Option<Money>,
+    /// Today's opening price.
+    pub open: Option<Money>,
+    /// Today's low.
+    pub day_low: Option<Money>,
+    /// Today's high.
+    pub day_high: Option<Money>,
+    /// 52-week low.
+    pub fifty_two_week_low: Option<Money>,
+    /// 52-week high.
+    pub fifty_two_week_high: Option<Money>,
+    /// 50-day moving average of the close.
+    pub fifty_day_average: Option<Money>,
+    /// 200-day moving average of the close.
+    pub two_hundred_day_average: Option<Money>,
+    /// Market capitalization.
+    pub market_cap: Option<Money>,
+    /// Annual dividend per share.
+    pub dividend_rate: Option<Money>,
+    /// Annual dividend yield, as a fraction.
+    pub dividend_yield: Option<f64>,
+    /// Share of earnings paid out as dividends, as a fraction.
+    pub payout_ratio: Option<f64>,
+    /// Most recent ex-dividend date.
+    pub ex_dividend_date: Option<DateTime<Utc>>,
+    /// Beta against the market.
+    pub beta: Option<f64>,
+    /// Trailing price-to-earnings ratio.
+    pub trailing_pe: Option<f64>,
+    /// Forward price-to-earnings ratio.
+    pub forward_pe: Option<f64>,
+    /// Today's traded volume.
+    pub volume: Option<u64>,
+    /// Average daily volume (3 months).
+    pub average_volume: Option<u64>,
+    /// Average daily volume (10 days).
+    pub average_volume_10d: Option<u64>,
+}
+
+/// Share, short-interest and valuation statistics from Yahoo's `defaultKeyStatistics` module.
+///
+/// The module carries no currency, so per-share and enterprise values are plain numbers in the
+/// instrument's reporting currency. Percentages are fractions as Yahoo reports them (e.g.
+/// `0.61` for 61% institutional ownership).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyStatistics {
+    /// Shares outstanding.
+    pub shares_outstanding: Option<u64>,
+    /// Shares available for public trading.
+    pub float_shares: Option<u64>,
+    /// Shares sold short.
+    pub shares_short: Option<u64>,
+    /// Days to cover the short interest at the average daily volume.
+    pub short_ratio: Option<f64>,
+    /// Short interest as a fraction of the float.
+    pub short_percent_of_float: Option<f64>,
+    /// Fraction of shares held by insiders.
+    pub held_percent_insiders: Option<f64>,
+    /// Fraction of shares held by institutions.
+    pub held_percent_institutions: Option<f64>,
+    /// Enterprise value.
+    pub enterprise_value: Option<f64>,
+    /// Enterprise value divided by revenue.
+    pub enterprise_to_revenue: Option<f64>,
+    /// Enterprise value divided by EBITDA.
+    pub enterprise_to_ebitda: Option<f64>,
+    /// Forward price-to-earnings ratio.
+    pub forward_pe: Option<f64>,
+    /// Price/earnings-to-growth ratio.
+    pub peg_ratio: Option<f64>,
+    /// Price-to-book ratio.
+    pub price_to_book: Option<f64>,
+    /// Book value per share.
+    pub book_value: Option<f64>,
+    /// Trailing twelve-month earnings per share.
+    pub trailing_eps: Option<f64>,
+    /// Forward earnings per share estimate.
+    pub forward_eps: Option<f64>,
+    /// Net profit margin, as a fraction.
+    pub profit_margins: Option<f64>,
+    /// Beta against the market.
+    pub beta: Option<f64>,
+    /// Price change over the last 52 weeks, as a fraction.
+    pub fifty_two_week_change: Option<f64>,
+    /// S&P 500 change over the last 52 weeks, as a fraction.
+    pub sp500_fifty_two_week_change: Option<f64>,
+    /// Most recent split ratio as Yahoo formats it (e.g. `"4:1"`).
+    pub last_split_factor: Option<String>,
+    /// Date of the most recent split.
+    pub last_split_date: Option<DateTime<Utc>>,
+    /// End of the most recently reported fiscal quarter.
+    pub most_recent_quarter: Option<DateTime<Utc>>,
+    /// End of the last fiscal year.
+    pub last_fiscal_year_end: Option<DateTime<Utc>>,
+}
+
+/// A single point of a historical market capitalization series.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketCapPoint {
+    /// Timestamp of the underlying price candle.
+    pub ts: DateTime<Utc>,
+    /// Shares outstanding in effect at `ts`: the most recent report on or before it, scaled
+    /// by any split between that report and `ts`.
+    pub shares: u64,
+    /// Unadjusted close multiplied by `shares`.
+    pub market_cap: Money,
+}
+
+/// Implied volatility across every listed expiration, laid out as a strike × expiration grid.
+///
+/// `call_iv[e][k]` and `put_iv[e][k]` hold the implied volatility (a fraction, e.g. `0.25`)
+/// for `expirations[e]` and `strikes[k]`. A strike not listed for an expiration, or listed
+/// without an implied volatility, is `None`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IvSurface {
+    /// Price of the underlying the moneyness values are measured against.
+    pub underlying_price: Option<f64>,
+    /// Expiration dates, ascending.
+    pub expirations: Vec<DateTime<Utc>>,
+    /// Union of strikes across all expirations, ascending.
+    pub strikes: Vec<f64>,
+    /// `strike / underlying_price` for each entry of `strikes`; `None` without an underlying price.
+    pub moneyness: Vec<Option<f64>>,
+    /// Call implied volatility, indexed `[expiration][strike]`.
+    pub call_iv: Vec<Vec<Option<f64>>>,
+    /// Put implied volatility, indexed `[expiration][strike]`.
+    pub put_iv: Vec<Vec<Option<f64>>>,
+}
+
+/// A point of a trailing-twelve-month dividend yield series.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DividendYieldPoint {
+    /// Timestamp of the underlying price candle.
+    pub ts: DateTime<Utc>,
+    /// Unadjusted close at `ts`.
+    pub close: Money,
+    /// Dividends paid in the 365 days up to and including `ts`, per share as of `ts`.
+    /// `None` while the series covers less than a full year before `ts`.
+    pub ttm_dividends: Option<f64>,
+    /// `ttm_dividends / close` as a fraction (e.g. `0.02` for 2%); `None` when
+    /// `ttm_dividends` is, the close is not positive, or a dividend in the window is in a
+    /// different currency than the close.
+    pub dividend_yield: Option<f64>,
+}
+
+/// A dividend payment, optionally converted into another currency.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dividend {
+    /// Timestamp of the dividend event.
+    pub ts: DateTime<Utc>,
+    /// The dividend amount, in the target currency when a conversion was requested.
+    pub amount: Money,
+    /// Details of the currency conversion, if one was applied.
+    pub conversion: Option<DividendConversion>,
+}
+
+/// How a dividend amount was converted into the target currency.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DividendConversion {
+    /// The amount as reported, in the security's trading currency.
+    pub original: Money,
+    /// The exchange rate applied (units of target currency per unit of original).
+    pub rate: f64,
+    /// Which rate was used. A dividend that predates the available FX history falls back
+    /// to [`RateBasis::Latest`] even when the event date was requested.
+    pub basis: RateBasis,
 }
 
 /// Comprehensive info structure containing quote, profile, analysis, and ESG data
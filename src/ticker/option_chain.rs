@@ -0,0 +1,92 @@
+use super::model::{OptionChain, OptionContract};
+use super::options;
+use crate::core::conversions::money_to_f64;
+use crate::core::{CacheMode, RetryConfig, YfClient, YfError};
+
+/// A builder for fetching a ticker's option chain, optionally narrowed to a strike band.
+///
+/// Obtain one via [`Ticker::option_chain_builder`](crate::Ticker::option_chain_builder).
+#[derive(Debug, Clone)]
+pub struct OptionChainBuilder {
+    client: YfClient,
+    symbol: String,
+    date: Option<i64>,
+    strike_range: Option<(f64, f64)>,
+    cache_mode: CacheMode,
+    retry_override: Option<RetryConfig>,
+}
+
+impl OptionChainBuilder {
+    pub(crate) fn new(client: &YfClient, symbol: impl Into<String>) -> Self {
+        Self {
+            client: client.clone(),
+            symbol: symbol.into(),
+            date: None,
+            strike_range: None,
+            cache_mode: CacheMode::Use,
+            retry_override: None,
+        }
+    }
+
+    /// Sets the expiration date (Unix timestamp) to fetch. Defaults to the nearest expiration.
+    #[must_use]
+    pub const fn expiration(mut self, date: i64) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Keeps only contracts whose strike lies within `min..=max` (both bounds inclusive).
+    ///
+    /// Yahoo returns the whole chain regardless, so the filtering happens after the fetch.
+    #[must_use]
+    pub const fn strike_range(mut self, min: f64, max: f64) -> Self {
+        self.strike_range = Some((min, max));
+        self
+    }
+
+    /// Sets the cache mode for this specific API call.
+    #[must_use]
+    pub const fn cache_mode(mut self, mode: CacheMode) -> Self {
+        self.cache_mode = mode;
+        self
+    }
+
+    /// Overrides the default retry policy for this specific API call.
+    #[must_use]
+    pub fn retry_policy(mut self, cfg: Option<RetryConfig>) -> Self {
+        self.retry_override = cfg;
+        self
+    }
+
+    /// Fetches the option chain (calls and puts), applying the strike range if set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `YfError::InvalidParams` if the strike range has `min > max`, or any error from
+    /// the underlying options request.
+    pub async fn fetch(self) -> Result<OptionChain, YfError> {
+        if let Some((min, max)) = self.strike_range
+            && min > max
+        {
+            return Err(YfError::InvalidParams(format!(
+                "strike_range min ({min}) must not exceed max ({max})"
+            )));
+        }
+
+        let mut chain = options::option_chain(
+            &self.client,
+            &self.symbol,
+            self.date,
+            self.cache_mode,
+            self.retry_override.as_ref(),
+        )
+        .await?;
+
+        if let Some((min, max)) = self.strike_range {
+            let in_band = |c: &OptionContract| (min..=max).contains(&money_to_f64(&c.strike));
+            chain.calls.retain(in_band);
+            chain.puts.retain(in_band);
+        }
+        Ok(chain)
+    }
+}
@@ -1,19 +1,24 @@
 use std::str::FromStr;
 
+use futures::{StreamExt, TryStreamExt, stream};
+
 use serde::Deserialize;
 use url::Url;
 
 use crate::{
     YfClient, YfError,
     core::{
-        client::{CacheMode, RetryConfig},
+        client::{CacheMode, Endpoint, RetryConfig},
         conversions::{f64_to_money_with_currency, i64_to_datetime},
         net,
     },
 };
 use paft::money::Currency;
 
-use super::model::{OptionChain, OptionContract};
+use super::model::{IvSurface, OptionChain, OptionContract};
+
+/// Maximum number of expirations `iv_surface` keeps in flight at once.
+const IV_SURFACE_CONCURRENCY: usize = 4;
 
 /* ---------------- Public: expirations + chain ---------------- */
 
@@ -25,13 +30,7 @@ pub async fn expiration_dates(
 ) -> Result<Vec<i64>, YfError> {
     let (body, _used_url) =
         fetch_options_raw(client, symbol, None, cache_mode, retry_override).await?;
-    let env: OptEnvelope = serde_json::from_str(&body).map_err(YfError::Json)?;
-
-    let first = env
-        .option_chain
-        .and_then(|oc| oc.result)
-        .and_then(|mut v| v.pop())
-        .ok_or_else(|| YfError::MissingData("empty options result".into()))?;
+    let first = first_result(&body)?;
 
     Ok(first.expiration_dates.unwrap_or_default())
 }
@@ -45,13 +44,7 @@ pub async fn option_chain(
 ) -> Result<OptionChain, YfError> {
     let (body, used_url) =
         fetch_options_raw(client, symbol, date, cache_mode, retry_override).await?;
-    let env: OptEnvelope = serde_json::from_str(&body).map_err(YfError::Json)?;
-
-    let first = env
-        .option_chain
-        .and_then(|oc| oc.result)
-        .and_then(|mut v| v.pop())
-        .ok_or_else(|| YfError::MissingData("empty options result".into()))?;
+    let first = first_result(&body)?;
 
     let currency_from_response = currency_from_result(&first);
 
@@ -78,7 +71,12 @@ pub async fn option_chain(
     let currency = if let Some(currency) = currency_from_response {
         currency
     } else {
-        let quote = super::quote::fetch_quote(client, symbol, cache_mode, retry_override).await?;
+        let quote = super::quote::fetch_quote(
+            client,
+            symbol,
+            &super::quote::QuoteFetchOptions::new(cache_mode, retry_override),
+        )
+        .await?;
         quote
             .price
             .as_ref()
@@ -120,6 +118,83 @@ pub async fn option_chain(
     })
 }
 
+pub async fn iv_surface(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<IvSurface, YfError> {
+    let (body, _used_url) =
+        fetch_options_raw(client, symbol, None, cache_mode, retry_override).await?;
+    let first = first_result(&body)?;
+    let underlying_price = first.quote.as_ref().and_then(|q| q.regular_market_price);
+    let mut dates = first.expiration_dates.unwrap_or_default();
+    dates.sort_unstable();
+    dates.dedup();
+
+    let bodies: Vec<_> = stream::iter(
+        dates
+            .iter()
+            .map(|&d| fetch_options_raw(client, symbol, Some(d), cache_mode, retry_override)),
+    )
+    .buffered(IV_SURFACE_CONCURRENCY)
+    .try_collect()
+    .await?;
+
+    // (strike, iv) pairs per expiration, for calls and puts.
+    type Side = Vec<(f64, Option<f64>)>;
+    let mut per_date: Vec<(Side, Side)> = Vec::with_capacity(dates.len());
+    for (body, _) in &bodies {
+        let od = first_result(body)?.options.and_then(|mut v| v.pop());
+        let side = |nodes: Option<Vec<OptContractNode>>| -> Side {
+            nodes
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|c| Some((c.strike?, c.implied_volatility)))
+                .collect()
+        };
+        per_date.push(od.map_or_else(Default::default, |od| (side(od.calls), side(od.puts))));
+    }
+
+    let mut strikes: Vec<f64> = per_date
+        .iter()
+        .flat_map(|(c, p)| c.iter().chain(p).map(|(k, _)| *k))
+        .collect();
+    strikes.sort_by(f64::total_cmp);
+    strikes.dedup();
+
+    let grid = |side: &Side| -> Vec<Option<f64>> {
+        strikes
+            .iter()
+            .map(|k| side.iter().find(|(s, _)| s == k).and_then(|(_, iv)| *iv))
+            .collect()
+    };
+    let call_iv = per_date.iter().map(|(c, _)| grid(c)).collect();
+    let put_iv = per_date.iter().map(|(_, p)| grid(p)).collect();
+
+    let moneyness = strikes
+        .iter()
+        .map(|k| underlying_price.filter(|u| *u > 0.0).map(|u| k / u))
+        .collect();
+
+    Ok(IvSurface {
+        underlying_price,
+        expirations: dates.into_iter().map(i64_to_datetime).collect(),
+        strikes,
+        moneyness,
+        call_iv,
+        put_iv,
+    })
+}
+
+fn first_result(body: &str) -> Result<OptResultNode, YfError> {
+    let env: OptEnvelope = serde_json::from_str(body).map_err(YfError::Json)?;
+    env.option_chain
+        .and_then(|oc| oc.result)
+        .and_then(|mut v| v.pop())
+        .ok_or_else(|| YfError::MissingData("empty options result".into()))
+}
+
 /* ---------------- Internal: raw fetch with auth fallback ---------------- */
 
 async fn fetch_options_raw(
@@ -130,9 +205,7 @@ async fn fetch_options_raw(
     retry_override: Option<&RetryConfig>,
 ) -> Result<(String, Url), YfError> {
     let http = client.http().clone();
-    let base = client.base_options_v7();
-
-    let mut url = base.join(symbol)?;
+    let mut url = client.symbol_url(Endpoint::Options, symbol)?;
     {
         let mut qp = url.query_pairs_mut();
         if let Some(d) = date {
@@ -160,28 +233,16 @@ async fn fetch_options_raw(
 
     let code = resp.status().as_u16();
     if code != 401 && code != 403 {
-        let url_s = url.to_string();
-        return Err(match code {
-            404 => YfError::NotFound { url: url_s },
-            429 => YfError::RateLimited { url: url_s },
-            500..=599 => YfError::ServerError {
-                status: code,
-                url: url_s,
-            },
-            _ => YfError::Status {
-                status: code,
-                url: url_s,
-            },
-        });
+        return Err(YfError::from_response(&resp, url.as_str()));
     }
 
     client.ensure_credentials().await?;
-    let crumb = client.crumb().await.ok_or_else(|| YfError::Status {
-        status: code,
-        url: url.to_string(),
-    })?;
+    let crumb = client
+        .crumb()
+        .await
+        .ok_or_else(|| YfError::from_response(&resp, url.as_str()))?;
 
-    let mut url2 = base.join(symbol)?;
+    let mut url2 = client.symbol_url(Endpoint::Options, symbol)?;
     {
         let mut qp = url2.query_pairs_mut();
         if let Some(d) = date {
@@ -194,20 +255,7 @@ async fn fetch_options_raw(
     resp = client.send_with_retry(req2, retry_override).await?;
 
     if !resp.status().is_success() {
-        let code = resp.status().as_u16();
-        let url_s = url2.to_string();
-        return Err(match code {
-            404 => YfError::NotFound { url: url_s },
-            429 => YfError::RateLimited { url: url_s },
-            500..=599 => YfError::ServerError {
-                status: code,
-                url: url_s,
-            },
-            _ => YfError::Status {
-                status: code,
-                url: url_s,
-            },
-        });
+        return Err(YfError::from_response(&resp, url2.as_str()));
     }
 
     let fixture_key = date.map_or_else(|| symbol.to_string(), |d| format!("{symbol}_{d}"));
@@ -244,6 +292,8 @@ struct OptResultNode {
 #[derive(Deserialize)]
 struct OptQuoteNode {
     currency: Option<String>,
+    #[serde(rename = "regularMarketPrice")]
+    regular_market_price: Option<f64>,
 }
 
 #[derive(Deserialize)]
@@ -1,29 +1,300 @@
 use crate::core::{
     YfClient, YfError,
-    client::{CacheMode, RetryConfig},
+    client::{CacheMode, Endpoint, RetryConfig},
+    conversions::{
+        exchange_to_string, f64_to_money_with_currency_str, i64_to_datetime,
+        market_state_to_string, money_to_currency_str, money_to_f64,
+    },
     models::Quote,
-    quotes,
+    quotes::{self, V7Request},
+    quotesummary,
 };
+use crate::quote::{FullQuote, PricePreference, QuoteDetails};
+use crate::ticker::FastInfo;
+use paft::money::Money;
+use serde::Deserialize;
 use serde_json::Value;
+use std::collections::BTreeMap;
 
+/// The reduced v7 field set requested by `Ticker::fast_info`.
+pub const FAST_INFO_FIELDS: &[&str] = &[
+    "regularMarketPrice",
+    "regularMarketPreviousClose",
+    "preMarketPreviousClose",
+    "postMarketPreviousClose",
+    "currency",
+    "marketState",
+    "fullExchangeName",
+    "regularMarketTime",
+];
+
+/// Options for [`fetch_quote`].
+#[derive(Clone, Copy)]
+pub struct QuoteFetchOptions<'a> {
+    /// Restricts the v7 response to these fields; `None` returns Yahoo's default set.
+    pub(crate) fields: Option<&'a [&'a str]>,
+    /// Refetches once, bypassing the cached copy, when both the price and the previous
+    /// close are null. An empty result is not retried: it means Yahoo does not know the
+    /// symbol.
+    pub(crate) retry_null_price: bool,
+    /// Answers a v7 request that is still rate limited after retries from the v8 chart
+    /// `meta` block instead (see [`chart_meta_node`]).
+    pub(crate) chart_fallback: bool,
+    /// Rejects implausible prices with `YfError::MissingData` instead of returning them
+    /// (see `V7QuoteNode::check_prices`).
+    pub(crate) validate_prices: bool,
+    /// Rejects a result whose symbol is missing or differs (case-insensitively) from the
+    /// requested one with `YfError::SymbolMismatch`.
+    pub(crate) strict_symbol: bool,
+    pub(crate) cache_mode: CacheMode,
+    pub(crate) retry_override: Option<&'a RetryConfig>,
+}
+
+impl<'a> QuoteFetchOptions<'a> {
+    /// Plain options: default fields, no retry, fallback or validation.
+    pub(crate) const fn new(
+        cache_mode: CacheMode,
+        retry_override: Option<&'a RetryConfig>,
+    ) -> Self {
+        Self {
+            fields: None,
+            retry_null_price: false,
+            chart_fallback: false,
+            validate_prices: false,
+            strict_symbol: false,
+            cache_mode,
+            retry_override,
+        }
+    }
+}
+
+/// Fetches a single v7 quote, applying the checks selected in `opts`.
+///
+/// The chart fallback result goes through the same symbol and price checks as a v7 result.
 pub async fn fetch_quote(
     client: &YfClient,
     symbol: &str,
-    cache_mode: CacheMode,
-    retry_override: Option<&RetryConfig>,
+    opts: &QuoteFetchOptions<'_>,
 ) -> Result<Quote, YfError> {
     let symbols = [symbol];
-    let mut results =
-        quotes::fetch_v7_quotes(client, &symbols, None, cache_mode, retry_override).await?;
+    let retry_override = opts.retry_override;
+    let fetch_one = |mode: CacheMode| async move {
+        quotes::fetch_v7_quotes(
+            client,
+            &symbols,
+            &V7Request::new(mode, retry_override).fields(opts.fields),
+        )
+        .await?
+        .pop()
+        .ok_or_else(|| YfError::MissingData(format!("no quote result found for symbol {symbol}")))
+    };
 
-    let result = results.pop().ok_or_else(|| {
-        YfError::MissingData(format!("no quote result found for symbol {symbol}"))
-    })?;
+    let (mut result, from_chart) = match fetch_one(opts.cache_mode).await {
+        Err(YfError::RateLimited { .. }) if opts.chart_fallback => (
+            chart_meta_node(client, symbol, opts.cache_mode, retry_override).await?,
+            true,
+        ),
+        other => (other?, false),
+    };
+    if opts.retry_null_price
+        && !from_chart
+        && result.regular_market_price.is_none()
+        && result.regular_market_previous_close.is_none()
+    {
+        let cfg = retry_override.unwrap_or_else(|| client.retry_config());
+        tokio::time::sleep(cfg.backoff_delay(0)).await;
+        let mode = match opts.cache_mode {
+            CacheMode::Use => CacheMode::Refresh,
+            other => other,
+        };
+        result = fetch_one(mode).await?;
+    }
+
+    if opts.strict_symbol {
+        let returned = result.symbol.as_deref().unwrap_or_default();
+        if !returned.eq_ignore_ascii_case(symbol) {
+            return Err(YfError::SymbolMismatch {
+                requested: symbol.to_string(),
+                returned: returned.to_string(),
+            });
+        }
+    }
+    if opts.validate_prices {
+        result.check_prices()?;
+    }
+    if from_chart && result.symbol.is_none() {
+        result.symbol = Some(symbol.to_string());
+    }
 
     // Use the same currency-aware conversion as the batch quotes API
     Ok(result.into())
 }
 
+/// Builds a minimal v7 node from the v8 chart `meta` block: symbol, price, previous close,
+/// currency and exchange, so it shares the v7 checks and conversion. Short name and market
+/// state are not available there and stay `None`; so does the symbol if the chart reported
+/// none.
+async fn chart_meta_node(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<quotes::V7QuoteNode, YfError> {
+    let meta = crate::history::fetch_chart_meta(client, symbol, cache_mode, retry_override)
+        .await?
+        .ok_or_else(|| YfError::MissingData(format!("no chart meta for {symbol}")))?;
+    let previous_close = meta.last_close();
+    let symbol = meta.symbol.map(|mut s| {
+        client.unalias_symbol(&mut s);
+        s
+    });
+
+    Ok(quotes::V7QuoteNode {
+        symbol,
+        regular_market_price: meta.regular_market_price,
+        regular_market_previous_close: previous_close,
+        currency: meta.currency,
+        full_exchange_name: meta.full_exchange_name,
+        exchange: meta.exchange_name,
+        ..Default::default()
+    })
+}
+
+pub async fn fetch_quote_with_preference(
+    client: &YfClient,
+    symbol: &str,
+    pref: PricePreference,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Quote, YfError> {
+    let symbols = [symbol];
+    let mut node = quotes::fetch_v7_quotes(
+        client,
+        &symbols,
+        &V7Request::new(cache_mode, retry_override),
+    )
+    .await?
+    .pop()
+    .ok_or_else(|| YfError::MissingData(format!("no quote result found for symbol {symbol}")))?;
+
+    node.apply_price_preference(pref);
+    Ok(node.into())
+}
+
+/// Fetches the reduced quote behind `Ticker::fast_info`.
+///
+/// With `provenance`, `FastInfo::provenance` records which v7 field each fallback-resolved
+/// output was read from.
+pub async fn fetch_fast_info(
+    client: &YfClient,
+    symbol: &str,
+    provenance: bool,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<FastInfo, YfError> {
+    let symbols = [symbol];
+    let node = quotes::fetch_v7_quotes(
+        client,
+        &symbols,
+        &V7Request::new(cache_mode, retry_override).fields(Some(FAST_INFO_FIELDS)),
+    )
+    .await?
+    .pop()
+    .ok_or_else(|| YfError::MissingData(format!("no quote result found for symbol {symbol}")))?;
+
+    fast_info_from_node(node, provenance)
+}
+
+/// Reduces a v7 node to [`FastInfo`], failing if it carries neither a price nor a previous
+/// close.
+pub(crate) fn fast_info_from_node(
+    node: quotes::V7QuoteNode,
+    provenance: bool,
+) -> Result<FastInfo, YfError> {
+    let previous_close = node.session_previous_close();
+    let regular_market_time = node.regular_market_time.map(i64_to_datetime);
+    let sources = provenance.then(|| {
+        let last = if node.regular_market_price.is_some() {
+            Some("regularMarketPrice")
+        } else {
+            node.regular_market_previous_close
+                .map(|_| "regularMarketPreviousClose")
+        };
+        [
+            ("last_price", last),
+            ("previous_close", previous_close.map(|(_, src)| src)),
+            ("exchange", node.exchange_source()),
+        ]
+        .into_iter()
+        .filter_map(|(field, src)| Some((field.to_string(), src?.to_string())))
+        .collect::<BTreeMap<_, _>>()
+    });
+    let q = Quote::from(node);
+
+    let last = q
+        .price
+        .as_ref()
+        .map(money_to_f64)
+        .or_else(|| q.previous_close.as_ref().map(money_to_f64))
+        .ok_or_else(|| YfError::MissingData("quote missing last/previous price".into()))?;
+
+    // Extract currency from the price or previous_close Money objects
+    let currency = q
+        .price
+        .as_ref()
+        .and_then(money_to_currency_str)
+        .or_else(|| q.previous_close.as_ref().and_then(money_to_currency_str));
+
+    Ok(FastInfo {
+        symbol: q.symbol,
+        last_price: last,
+        previous_close: previous_close.map(|(v, _)| v),
+        currency,
+        exchange: exchange_to_string(q.exchange),
+        market_state: market_state_to_string(q.market_state),
+        regular_market_time,
+        provenance: sources,
+    })
+}
+
+pub async fn fetch_quote_details(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<QuoteDetails, YfError> {
+    let symbols = [symbol];
+    let mut results = quotes::fetch_v7_quotes(
+        client,
+        &symbols,
+        &V7Request::new(cache_mode, retry_override),
+    )
+    .await?;
+
+    results
+        .pop()
+        .map(Into::into)
+        .ok_or_else(|| YfError::MissingData(format!("no quote result found for symbol {symbol}")))
+}
+
+pub async fn fetch_full_quote(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<FullQuote, YfError> {
+    let symbols = [symbol];
+    quotes::fetch_v7_quotes(
+        client,
+        &symbols,
+        &V7Request::new(cache_mode, retry_override),
+    )
+    .await?
+    .pop()
+    .map(Into::into)
+    .ok_or_else(|| YfError::MissingData(format!("no quote result found for symbol {symbol}")))
+}
+
 pub async fn fetch_quote_raw(
     client: &YfClient,
     symbol: &str,
@@ -32,10 +303,121 @@ pub async fn fetch_quote_raw(
     retry_override: Option<&RetryConfig>,
 ) -> Result<Value, YfError> {
     let symbols = [symbol];
-    let mut results =
-        quotes::fetch_v7_quotes_raw(client, &symbols, fields, cache_mode, retry_override).await?;
+    let mut results = quotes::fetch_v7_quotes_raw(
+        client,
+        &symbols,
+        &V7Request::new(cache_mode, retry_override).fields(fields),
+    )
+    .await?;
 
     results
         .pop()
         .ok_or_else(|| YfError::MissingData(format!("no quote result found for symbol {symbol}")))
 }
+
+/// Resolves the previous close, trying in order:
+/// 1. `regularMarketPreviousClose` from the v7 quote,
+/// 2. `previousClose` from the chart metadata,
+/// 3. `chartPreviousClose` from the chart metadata.
+///
+/// A v7 request that is still rate limited or failing with a 5xx after retries moves on to
+/// the chart metadata instead of failing; other v7 errors are returned as-is.
+pub async fn fetch_previous_close(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Money, YfError> {
+    let symbols = [symbol];
+    let quote = match quotes::fetch_v7_quotes(
+        client,
+        &symbols,
+        &V7Request::new(cache_mode, retry_override),
+    )
+    .await
+    {
+        Ok(mut quotes) => quotes.pop(),
+        Err(YfError::RateLimited { .. } | YfError::ServerError { .. }) => None,
+        Err(e) => return Err(e),
+    };
+
+    if let Some(q) = &quote
+        && let Some(pc) = q.regular_market_previous_close
+    {
+        return Ok(f64_to_money_with_currency_str(pc, q.currency.as_deref()));
+    }
+
+    let meta = crate::history::fetch_chart_meta(client, symbol, cache_mode, retry_override)
+        .await?
+        .ok_or_else(|| YfError::MissingData(format!("no previous close for {symbol}")))?;
+
+    let currency = meta
+        .currency
+        .as_deref()
+        .or_else(|| quote.as_ref().and_then(|q| q.currency.as_deref()));
+
+    meta.last_close()
+        .map(|pc| f64_to_money_with_currency_str(pc, currency))
+        .ok_or_else(|| YfError::MissingData(format!("no previous close for {symbol}")))
+}
+
+#[derive(Deserialize)]
+struct ComponentsResult {
+    components: Option<ComponentsNode>,
+}
+
+#[derive(Deserialize)]
+struct ComponentsNode {
+    #[serde(default)]
+    components: Vec<String>,
+}
+
+/// Most symbols `fetch_constituents` puts in one v7 request, keeping the URL short enough
+/// for large indices such as the S&P 500.
+const CONSTITUENTS_PER_REQUEST: usize = 100;
+
+/// Resolves an index's component symbols via the `components` quoteSummary module and
+/// fetches their quotes in v7 batches of at most [`CONSTITUENTS_PER_REQUEST`] symbols.
+pub async fn fetch_constituents(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<Vec<Quote>, YfError> {
+    let not_found = || YfError::NotFound {
+        url: client
+            .symbol_url(Endpoint::QuoteSummary, symbol)
+            .map_or_else(|_| symbol.to_string(), |u| u.to_string()),
+    };
+
+    let res: ComponentsResult = match quotesummary::fetch_module_result(
+        client,
+        symbol,
+        "components",
+        "constituents",
+        cache_mode,
+        retry_override,
+    )
+    .await
+    {
+        Ok(r) => r,
+        // Yahoo answers non-index symbols with an API error or an empty result.
+        Err(YfError::Api { .. } | YfError::MissingData(_)) => return Err(not_found()),
+        Err(e) => return Err(e),
+    };
+
+    let members = res.components.map(|c| c.components).unwrap_or_default();
+    if members.is_empty() {
+        return Err(not_found());
+    }
+
+    let symbols: Vec<&str> = members.iter().map(String::as_str).collect();
+    let req = V7Request::new(cache_mode, retry_override);
+    let batches = futures::future::try_join_all(
+        symbols
+            .chunks(CONSTITUENTS_PER_REQUEST)
+            .map(|batch| quotes::fetch_v7_quotes(client, batch, &req)),
+    )
+    .await?;
+    Ok(batches.into_iter().flatten().map(Quote::from).collect())
+}
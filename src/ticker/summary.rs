@@ -0,0 +1,178 @@
+use serde::Deserialize;
+
+use crate::core::{
+    YfClient, YfError,
+    client::{CacheMode, RetryConfig},
+    conversions::f64_to_money_with_currency_str,
+    quotesummary,
+    wire::{RawNumU64, YahooDate, YahooNumber, from_yahoo_date},
+};
+use crate::ticker::{KeyStatistics, SummaryDetail};
+
+/// Fetches the `summaryDetail` quoteSummary module and maps it onto [`SummaryDetail`].
+pub(super) async fn fetch_summary_detail(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<SummaryDetail, YfError> {
+    let root: V10Result = quotesummary::fetch_module_result(
+        client,
+        symbol,
+        "summaryDetail",
+        "summary",
+        cache_mode,
+        retry_override,
+    )
+    .await?;
+
+    let sd = root
+        .summary_detail
+        .ok_or_else(|| YfError::MissingData("summaryDetail module missing from response".into()))?;
+
+    let currency = sd.currency;
+    let money = |v: YahooNumber| {
+        v.get()
+            .map(|x| f64_to_money_with_currency_str(x, currency.as_deref()))
+    };
+
+    Ok(SummaryDetail {
+        previous_close: money(sd.previous_close),
+        open: money(sd.open),
+        day_low: money(sd.day_low),
+        day_high: money(sd.day_high),
+        fifty_two_week_low: money(sd.fifty_two_week_low),
+        fifty_two_week_high: money(sd.fifty_two_week_high),
+        fifty_day_average: money(sd.fifty_day_average),
+        two_hundred_day_average: money(sd.two_hundred_day_average),
+        market_cap: money(sd.market_cap),
+        dividend_rate: money(sd.dividend_rate),
+        dividend_yield: sd.dividend_yield.get(),
+        payout_ratio: sd.payout_ratio.get(),
+        ex_dividend_date: from_yahoo_date(sd.ex_dividend_date),
+        beta: sd.beta.get(),
+        trailing_pe: sd.trailing_pe.get(),
+        forward_pe: sd.forward_pe.get(),
+        volume: sd.volume.and_then(|v| v.raw),
+        average_volume: sd.average_volume.and_then(|v| v.raw),
+        average_volume_10d: sd.average_daily_volume_10_day.and_then(|v| v.raw),
+        currency,
+    })
+}
+
+/// Fetches the `defaultKeyStatistics` quoteSummary module and maps it onto [`KeyStatistics`].
+pub(super) async fn fetch_key_statistics(
+    client: &YfClient,
+    symbol: &str,
+    cache_mode: CacheMode,
+    retry_override: Option<&RetryConfig>,
+) -> Result<KeyStatistics, YfError> {
+    let root: V10Result = quotesummary::fetch_module_result(
+        client,
+        symbol,
+        "defaultKeyStatistics",
+        "summary",
+        cache_mode,
+        retry_override,
+    )
+    .await?;
+
+    let ks = root.default_key_statistics.ok_or_else(|| {
+        YfError::MissingData("defaultKeyStatistics module missing from response".into())
+    })?;
+
+    Ok(KeyStatistics {
+        shares_outstanding: ks.shares_outstanding.and_then(|v| v.raw),
+        float_shares: ks.float_shares.and_then(|v| v.raw),
+        shares_short: ks.shares_short.and_then(|v| v.raw),
+        short_ratio: ks.short_ratio.get(),
+        short_percent_of_float: ks.short_percent_of_float.get(),
+        held_percent_insiders: ks.held_percent_insiders.get(),
+        held_percent_institutions: ks.held_percent_institutions.get(),
+        enterprise_value: ks.enterprise_value.get(),
+        enterprise_to_revenue: ks.enterprise_to_revenue.get(),
+        enterprise_to_ebitda: ks.enterprise_to_ebitda.get(),
+        forward_pe: ks.forward_pe.get(),
+        peg_ratio: ks.peg_ratio.get(),
+        price_to_book: ks.price_to_book.get(),
+        book_value: ks.book_value.get(),
+        trailing_eps: ks.trailing_eps.get(),
+        forward_eps: ks.forward_eps.get(),
+        profit_margins: ks.profit_margins.get(),
+        beta: ks.beta.get(),
+        fifty_two_week_change: ks.fifty_two_week_change.get(),
+        sp500_fifty_two_week_change: ks.sp500_fifty_two_week_change.get(),
+        last_split_factor: ks.last_split_factor.filter(|s| !s.is_empty()),
+        last_split_date: from_yahoo_date(ks.last_split_date),
+        most_recent_quarter: from_yahoo_date(ks.most_recent_quarter),
+        last_fiscal_year_end: from_yahoo_date(ks.last_fiscal_year_end),
+    })
+}
+
+/* --- wire --- */
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct V10Result {
+    summary_detail: Option<SummaryDetailNode>,
+    default_key_statistics: Option<KeyStatisticsNode>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct SummaryDetailNode {
+    currency: Option<String>,
+    previous_close: YahooNumber,
+    open: YahooNumber,
+    day_low: YahooNumber,
+    day_high: YahooNumber,
+    fifty_two_week_low: YahooNumber,
+    fifty_two_week_high: YahooNumber,
+    fifty_day_average: YahooNumber,
+    two_hundred_day_average: YahooNumber,
+    market_cap: YahooNumber,
+    dividend_rate: YahooNumber,
+    dividend_yield: YahooNumber,
+    payout_ratio: YahooNumber,
+    ex_dividend_date: Option<YahooDate>,
+    beta: YahooNumber,
+    #[serde(rename = "trailingPE")]
+    trailing_pe: YahooNumber,
+    #[serde(rename = "forwardPE")]
+    forward_pe: YahooNumber,
+    volume: Option<RawNumU64>,
+    average_volume: Option<RawNumU64>,
+    average_daily_volume_10_day: Option<RawNumU64>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct KeyStatisticsNode {
+    shares_outstanding: Option<RawNumU64>,
+    float_shares: Option<RawNumU64>,
+    shares_short: Option<RawNumU64>,
+    short_ratio: YahooNumber,
+    short_percent_of_float: YahooNumber,
+    held_percent_insiders: YahooNumber,
+    held_percent_institutions: YahooNumber,
+    enterprise_value: YahooNumber,
+    enterprise_to_revenue: YahooNumber,
+    enterprise_to_ebitda: YahooNumber,
+    #[serde(rename = "forwardPE")]
+    forward_pe: YahooNumber,
+    peg_ratio: YahooNumber,
+    price_to_book: YahooNumber,
+    book_value: YahooNumber,
+    trailing_eps: YahooNumber,
+    forward_eps: YahooNumber,
+    profit_margins: YahooNumber,
+    beta: YahooNumber,
+    #[serde(rename = "52WeekChange")]
+    fifty_two_week_change: YahooNumber,
+    #[serde(rename = "SandP52WeekChange")]
+    sp500_fifty_two_week_change: YahooNumber,
+    last_split_factor: Option<String>,
+    last_split_date: Option<YahooDate>,
+    most_recent_quarter: Option<YahooDate>,
+    last_fiscal_year_end: Option<YahooDate>,
+}
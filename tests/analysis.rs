@@ -9,10 +9,16 @@ mod analysis_offline;
 mod analysis_retry_synth;
 #[path = "analysis/earnings_trend.rs"]
 mod earnings_trend;
+#[path = "analysis/eps_trend.rs"]
+mod eps_trend;
 #[path = "analysis/price_target.rs"]
 mod price_target;
+#[path = "analysis/price_target_consensus.rs"]
+mod price_target_consensus;
 #[path = "analysis/price_target_live.rs"]
 mod price_target_live;
+#[path = "analysis/recommendation_consensus.rs"]
+mod recommendation_consensus;
 #[path = "analysis/sorted_upgrades.rs"]
 mod sorted_upgrades;
 #[path = "analysis/yahoo_error_passthrough.rs"]
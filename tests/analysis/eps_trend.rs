@@ -0,0 +1,71 @@
+use httpmock::{Method::GET, MockServer};
+use paft::money::{Currency, IsoCurrency};
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Ticker, YfClient};
+
+#[tokio::test]
+async fn eps_trend_parses_current_quarter_revisions() {
+    let server = MockServer::start();
+
+    let body = r#"{
+      "quoteSummary": {
+        "result": [{
+          "earningsTrend": {
+            "trend": [
+              {
+                "period": "0q",
+                "epsTrend": {
+                  "current": {"raw": 1.62},
+                  "7daysAgo": {"raw": 1.60},
+                  "30daysAgo": {"raw": 1.58},
+                  "60daysAgo": {"raw": 1.55},
+                  "90daysAgo": {"raw": 1.50}
+                }
+              },
+              { "period": "+1q" }
+            ]
+          }
+        }],
+        "error": null
+      }
+    }"#;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", "earningsTrend");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let rows = Ticker::new(&client, "AAPL")
+        .eps_trend(Some(Currency::Iso(IsoCurrency::USD)))
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(rows.len(), 1, "periods without epsTrend are skipped");
+
+    let q = &rows[0];
+    assert_eq!(q.period.to_string(), "0Q");
+    let v = |m: &Option<paft::money::Money>| m.as_ref().map(money_to_f64);
+    assert_eq!(v(&q.current), Some(1.62));
+    assert_eq!(v(&q.seven_days_ago), Some(1.60));
+    assert_eq!(v(&q.thirty_days_ago), Some(1.58));
+    assert_eq!(v(&q.sixty_days_ago), Some(1.55));
+    assert_eq!(v(&q.ninety_days_ago), Some(1.50));
+    assert_eq!(
+        q.current.as_ref().unwrap().currency(),
+        &Currency::Iso(IsoCurrency::USD)
+    );
+}
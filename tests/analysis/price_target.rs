@@ -72,6 +72,57 @@ async fn offline_price_target_happy() {
     assert_eq!(pt.number_of_analysts, Some(31));
 }
 
+#[tokio::test]
+async fn price_target_accepts_bare_and_fmt_only_numbers() {
+    let server = MockServer::start();
+    let sym = "AAPL";
+
+    let body = r#"{
+      "quoteSummary": {
+        "result": [{
+          "financialData": {
+            "targetMeanPrice": 200.0,
+            "targetHighPrice": { "fmt": "250.00" },
+            "targetLowPrice":  {},
+            "numberOfAnalystOpinions": { "fmt": "31" }
+          }
+        }],
+        "error": null
+      }
+    }"#;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v10/finance/quoteSummary/{sym}"))
+            .query_param("modules", "financialData");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._api_preference(ApiPreference::ApiOnly)
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let pt = Ticker::new(&client, sym)
+        .analyst_price_target(None)
+        .await
+        .unwrap();
+
+    mock.assert();
+
+    let usd = |v: f64| f64_to_money_with_currency(v, Currency::Iso(IsoCurrency::USD));
+    assert_eq!(pt.mean, Some(usd(200.0)));
+    assert_eq!(pt.high, Some(usd(250.0)));
+    assert_eq!(pt.low, None);
+    assert_eq!(pt.number_of_analysts, Some(31));
+}
+
 #[tokio::test]
 async fn price_target_invalid_crumb_then_retry_succeeds() {
     let server = MockServer::start();
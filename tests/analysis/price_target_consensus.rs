@@ -0,0 +1,82 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use paft::money::{Currency, IsoCurrency};
+use url::Url;
+use yfinance_rs::analysis::{PriceTarget, PriceTargetConsensus};
+use yfinance_rs::core::conversions::*;
+use yfinance_rs::{ApiPreference, Ticker, YfClient};
+
+#[tokio::test]
+async fn price_targets_consensus_computes_upside() {
+    let server = MockServer::start();
+    let sym = "AAPL";
+
+    let body = r#"{
+      "quoteSummary": {
+        "result": [{
+          "financialData": {
+            "currentPrice": { "raw": 160.0 },
+            "targetMeanPrice": { "raw": 200.0 },
+            "targetHighPrice": { "raw": 250.0 },
+            "targetLowPrice":  { "raw": 150.0 },
+            "numberOfAnalystOpinions": { "raw": 31 }
+          }
+        }],
+        "error": null
+      }
+    }"#;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v10/finance/quoteSummary/{sym}"))
+            .query_param("modules", "financialData");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._api_preference(ApiPreference::ApiOnly)
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let c = Ticker::new(&client, sym)
+        .price_targets_consensus(Some(Currency::Iso(IsoCurrency::USD)))
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(
+        c.current_price,
+        Some(f64_to_money_with_currency(
+            160.0,
+            Currency::Iso(IsoCurrency::USD)
+        ))
+    );
+    assert_eq!(c.targets.number_of_analysts, Some(31));
+    assert!((c.upside_percent.unwrap() - 25.0).abs() < 1e-9);
+}
+
+#[test]
+fn upside_is_none_without_a_positive_current_price() {
+    let usd = |v| f64_to_money_with_currency(v, Currency::Iso(IsoCurrency::USD));
+    let targets = PriceTarget {
+        mean: Some(usd(200.0)),
+        high: None,
+        low: None,
+        number_of_analysts: None,
+    };
+
+    assert_eq!(
+        PriceTargetConsensus::new(targets.clone(), None).upside_percent,
+        None
+    );
+    assert_eq!(
+        PriceTargetConsensus::new(targets, Some(usd(0.0))).upside_percent,
+        None
+    );
+}
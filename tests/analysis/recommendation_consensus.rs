@@ -0,0 +1,70 @@
+use httpmock::{Method::GET, MockServer};
+use url::Url;
+use yfinance_rs::analysis::{ConsensusTrend, RecommendationConsensus, RecommendationRow};
+use yfinance_rs::{Ticker, YfClient};
+
+#[tokio::test]
+async fn consensus_scores_current_period_and_direction() {
+    let server = MockServer::start();
+
+    let body = r#"{
+      "quoteSummary": {
+        "result": [{
+          "recommendationTrend": {
+            "trend": [
+              {"period": "0m", "strongBuy": 10, "buy": 20, "hold": 10, "sell": 0, "strongSell": 0},
+              {"period": "-1m", "strongBuy": 5, "buy": 20, "hold": 15, "sell": 0, "strongSell": 0}
+            ]
+          }
+        }],
+        "error": null
+      }
+    }"#;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", "recommendationTrend");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let c = Ticker::new(&client, "AAPL")
+        .recommendation_consensus()
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(c.analysts, 40);
+    assert_eq!(c.score, Some(2.0));
+    assert_eq!(c.previous_score, Some(2.25));
+    assert_eq!(c.trend, ConsensusTrend::Improving);
+}
+
+#[test]
+fn zero_total_periods_have_no_score_or_direction() {
+    let rows: Vec<RecommendationRow> = serde_json::from_str(
+        r#"[
+          {"period": "0m", "strong_buy": 0, "buy": 0, "hold": 0, "sell": 0, "strong_sell": 0},
+          {"period": "-1m", "strong_buy": 1, "buy": 0, "hold": 0, "sell": 0, "strong_sell": 1}
+        ]"#,
+    )
+    .unwrap();
+
+    let c = RecommendationConsensus::from_rows(&rows).unwrap();
+    assert_eq!(c.score, None);
+    assert_eq!(c.analysts, 0);
+    assert_eq!(c.previous_score, Some(3.0));
+    assert_eq!(c.trend, ConsensusTrend::Unknown);
+
+    assert!(RecommendationConsensus::from_rows(&[]).is_none());
+}
@@ -35,7 +35,7 @@ async fn analysis_other_yahoo_errors_are_surfaced_without_retry() {
     api_err.assert();
 
     match err {
-        YfError::Api(s) => assert!(
+        YfError::Api { message: s, .. } => assert!(
             s.to_ascii_lowercase().contains("yahoo error:") && s.contains("Something broke"),
             "expected yahoo error to be surfaced; got {s}"
         ),
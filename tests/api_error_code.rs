@@ -0,0 +1,68 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Range, Ticker, YahooErrorCode, YfClient, YfError};
+
+#[tokio::test]
+async fn chart_invalid_crumb_maps_to_error_code() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"chart":{"result":null,"error":{"code":"Unauthorized","description":"Invalid Crumb"}}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let err = Ticker::new(&client, "AAPL")
+        .history(Some(Range::D5), None, false)
+        .await
+        .unwrap_err();
+
+    mock.assert();
+    match err {
+        YfError::Api { code, message } => {
+            assert_eq!(code, YahooErrorCode::InvalidCrumb);
+            assert!(message.contains("Invalid Crumb"), "{message}");
+        }
+        other => panic!("expected Api error, got {other:?}"),
+    }
+}
+
+#[test]
+fn known_codes_are_mapped() {
+    let cases = [
+        (
+            "Not Found",
+            "No data found, symbol may be delisted",
+            YahooErrorCode::NotFound,
+        ),
+        (
+            "Unauthorized",
+            "User is unable to access this feature",
+            YahooErrorCode::Unauthorized,
+        ),
+        (
+            "Unauthorized",
+            "Invalid Crumb",
+            YahooErrorCode::InvalidCrumb,
+        ),
+        ("Bad Request", "Invalid input", YahooErrorCode::BadRequest),
+        (
+            "internal-error",
+            "Internal error",
+            YahooErrorCode::InternalError,
+        ),
+    ];
+    for (code, description, expected) in cases {
+        assert_eq!(YahooErrorCode::from_yahoo(code, description), expected);
+    }
+    assert_eq!(
+        YahooErrorCode::from_yahoo("Gone Fishing", "?"),
+        YahooErrorCode::Other("Gone Fishing".into())
+    );
+}
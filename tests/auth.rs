@@ -2,5 +2,14 @@ mod common;
 
 #[path = "auth/crumb_retry_synthetic.rs"]
 mod auth_and_retry_synth;
+#[cfg(feature = "fault-injection")]
+#[path = "auth/bootstrap_retry.rs"]
+mod bootstrap_retry;
+#[path = "auth/consent_wall.rs"]
+mod consent_wall;
+#[path = "auth/crumb_encoding.rs"]
+mod crumb_encoding;
 #[path = "auth/negative_cookie_crumb.rs"]
 mod negative_cookie_crumb;
+#[path = "auth/v7_unauthorized_retry.rs"]
+mod v7_unauthorized_retry;
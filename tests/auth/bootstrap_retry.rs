@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use crate::common;
+use httpmock::Method::GET;
+use url::Url;
+use yfinance_rs::core::client::Backoff;
+use yfinance_rs::{FaultConfig, RetryConfig, Ticker, YfClient, YfError};
+
+fn client(server: &httpmock::MockServer, auth_retry: Option<RetryConfig>) -> YfClient {
+    let mut builder = YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        .cookie_url(Url::parse(&format!("{}/consent", server.base_url())).unwrap())
+        .crumb_url(Url::parse(&format!("{}/v1/test/getcrumb", server.base_url())).unwrap())
+        .retry_enabled(false)
+        .fault_injection(FaultConfig::new().status("/v1/test/getcrumb", 503).times(1));
+    if let Some(cfg) = auth_retry {
+        builder = builder.auth_retry_config(cfg);
+    }
+    builder.build().unwrap()
+}
+
+#[tokio::test]
+async fn crumb_bootstrap_retries_with_auth_policy() {
+    let server = common::setup_server();
+    let (cookie, crumb) = common::mock_cookie_crumb(&server);
+    let api = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("crumb", "crumb-value");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteSummary":{"result":[{"calendarEvents":{"exDividendDate":{"raw":1758499200}}}],"error":null}}"#);
+    });
+
+    let auth_retry = RetryConfig {
+        max_retries: 2,
+        backoff: Backoff::Fixed(Duration::from_millis(1)),
+        ..RetryConfig::default()
+    };
+    let client = client(&server, Some(auth_retry));
+    Ticker::new(&client, "AAPL")
+        .calendar_events_raw()
+        .await
+        .unwrap();
+
+    cookie.assert_hits(1);
+    // The first crumb request is answered with a synthetic 503; the retry reaches the server.
+    crumb.assert_hits(1);
+    api.assert_hits(1);
+    assert_eq!(client.metrics().retries, 1);
+}
+
+#[tokio::test]
+async fn crumb_bootstrap_follows_data_policy_by_default() {
+    let server = common::setup_server();
+    let (_cookie, crumb) = common::mock_cookie_crumb(&server);
+
+    let err = Ticker::new(&client(&server, None), "AAPL")
+        .calendar_events_raw()
+        .await
+        .unwrap_err();
+
+    crumb.assert_hits(0);
+    assert!(
+        matches!(err, YfError::ServerError { status: 503, .. }),
+        "unexpected error: {err:?}"
+    );
+}
@@ -0,0 +1,80 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Ticker, YfClient, YfError};
+
+const CONSENT_HTML: &str = r#"<!DOCTYPE html><html><head><title>Yahoo is part of the Yahoo family of brands</title></head>
+<body><form method="post" action="/v2/collectConsent"><button name="agree">Accept all</button></form>
+<p>We and our partners use cookies. Review your consent choices.</p></body></html>"#;
+
+fn client_for(server: &MockServer) -> YfClient {
+    YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        .cookie_url(Url::parse(&format!("{}/cookie", server.base_url())).unwrap())
+        .crumb_url(Url::parse(&format!("{}/v1/test/getcrumb", server.base_url())).unwrap())
+        .build()
+        .unwrap()
+}
+
+fn assert_consent_error(err: YfError) {
+    match err {
+        YfError::Auth(msg) => assert!(msg.contains("consent page"), "unexpected message: {msg}"),
+        other => panic!("expected Auth error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn consent_page_from_cookie_endpoint_is_an_auth_error() {
+    let server = MockServer::start();
+    let cookie = server.mock(|when, then| {
+        when.method(GET).path("/cookie");
+        then.status(200)
+            .header("content-type", "text/html")
+            .body(CONSENT_HTML);
+    });
+    let crumb = server.mock(|when, then| {
+        when.method(GET).path("/v1/test/getcrumb");
+        then.status(200).body("crumb-value");
+    });
+
+    let err = Ticker::new(&client_for(&server), "AAPL")
+        .summary_detail()
+        .await
+        .unwrap_err();
+
+    cookie.assert();
+    assert_eq!(crumb.hits(), 0);
+    assert_consent_error(err);
+}
+
+#[tokio::test]
+async fn consent_page_from_crumb_endpoint_is_an_auth_error() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/cookie");
+        then.status(200).header("set-cookie", "A=B; Path=/");
+    });
+    let crumb = server.mock(|when, then| {
+        when.method(GET).path("/v1/test/getcrumb");
+        then.status(200)
+            .header("content-type", "text/html")
+            .body(CONSENT_HTML);
+    });
+    let api = server.mock(|when, then| {
+        when.method(GET).path("/v10/finance/quoteSummary/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteSummary":{"result":[],"error":null}}"#);
+    });
+
+    let err = Ticker::new(&client_for(&server), "AAPL")
+        .summary_detail()
+        .await
+        .unwrap_err();
+
+    crumb.assert();
+    assert_eq!(api.hits(), 0);
+    assert_consent_error(err);
+}
@@ -0,0 +1,99 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Ticker, YfClient, YfError};
+
+const CRUMB: &str = "abc/def+ghi=";
+
+fn client_for(server: &MockServer) -> YfClient {
+    server.mock(|when, then| {
+        when.method(GET).path("/consent");
+        then.status(200).header("set-cookie", "A=B; Path=/");
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/test/getcrumb");
+        then.status(200).body(CRUMB);
+    });
+
+    YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .cookie_url(Url::parse(&format!("{}/consent", server.base_url())).unwrap())
+        .crumb_url(Url::parse(&format!("{}/v1/test/getcrumb", server.base_url())).unwrap())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn crumb_with_reserved_characters_round_trips() {
+    let server = MockServer::start();
+
+    // Registered first so it wins over the unauthenticated fallback below. An unencoded `+`
+    // would be decoded as a space and `=` would split the pair, so this only matches when the
+    // crumb was percent-encoded on the way out.
+    let authed = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("crumb", CRUMB);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD"}],"error":null}}"#);
+    });
+    let unauthed = server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(401);
+    });
+
+    let client = client_for(&server);
+    let q = Ticker::new(&client, "AAPL").quote().await.unwrap();
+
+    unauthed.assert();
+    authed.assert();
+    assert_eq!(q.symbol.as_str(), "AAPL");
+}
+
+#[tokio::test]
+async fn crumb_is_redacted_in_status_errors() {
+    let server = MockServer::start();
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("crumb", CRUMB);
+        then.status(404);
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(401);
+    });
+
+    let client = client_for(&server);
+    let err = Ticker::new(&client, "AAPL").quote().await.unwrap_err();
+
+    match err {
+        YfError::NotFound { url } => {
+            assert!(url.contains("crumb=***"), "got {url}");
+            assert!(!url.contains("abc"), "crumb leaked: {url}");
+            assert!(url.contains("symbols=AAPL"), "got {url}");
+        }
+        other => panic!("expected NotFound, got {other:?}"),
+    }
+}
+
+#[test]
+fn from_status_redacts_crumb() {
+    let err = YfError::from_status(
+        403,
+        "https://query1.finance.yahoo.com/v7/finance/quote?symbols=AAPL&crumb=abc%2Fdef%2Bghi%3D",
+    );
+    match &err {
+        YfError::Status { status, url } => {
+            assert_eq!(*status, 403);
+            assert_eq!(
+                url,
+                "https://query1.finance.yahoo.com/v7/finance/quote?symbols=AAPL&crumb=***"
+            );
+        }
+        other => panic!("expected Status, got {other:?}"),
+    }
+    assert!(!err.to_string().contains("abc"));
+}
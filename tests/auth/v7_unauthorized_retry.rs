@@ -0,0 +1,49 @@
+use httpmock::Method::GET;
+use url::Url;
+use yfinance_rs::{Ticker, YfClient};
+
+use crate::common;
+
+#[tokio::test]
+async fn v7_quote_401_fetches_crumb_and_retries_once() {
+    let server = common::setup_server();
+    let (cookie, crumb) = common::mock_cookie_crumb(&server);
+
+    // Registered first so it takes precedence over the crumb-less 401 below.
+    let authed = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL")
+            .query_param("crumb", "crumb-value");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD"}],"error":null}}"#);
+    });
+    let unauthorized = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(401);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .cookie_url(Url::parse(&format!("{}/consent", server.base_url())).unwrap())
+        .crumb_url(Url::parse(&format!("{}/v1/test/getcrumb", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+    let ticker = Ticker::new(&client, "AAPL");
+
+    let q = ticker.quote().await.unwrap();
+    assert_eq!(q.symbol.as_str(), "AAPL");
+    unauthorized.assert_hits(1);
+    authed.assert_hits(1);
+    cookie.assert_hits(1);
+    crumb.assert_hits(1);
+
+    // A second call reuses the stored crumb instead of acquiring a new one.
+    ticker.quote().await.unwrap();
+    authed.assert_hits(2);
+    cookie.assert_hits(1);
+    crumb.assert_hits(1);
+}
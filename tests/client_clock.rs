@@ -0,0 +1,61 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Clock, Ticker, YfClient};
+
+// A clock that only moves when the test advances it.
+#[derive(Debug, Clone)]
+struct ManualClock(Arc<Mutex<DateTime<Utc>>>);
+
+impl ManualClock {
+    fn advance(&self, by: TimeDelta) {
+        *self.0.lock().unwrap() += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
+#[tokio::test]
+async fn cache_entry_expires_exactly_at_ttl_on_injected_clock() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":200.0,"currency":"USD"}],"error":null}}"#);
+    });
+
+    let clock = ManualClock(Arc::new(Mutex::new(
+        DateTime::from_timestamp(1_704_205_800, 0).unwrap(),
+    )));
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .cache_ttl(Duration::from_secs(60))
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+    let ticker = Ticker::new(&client, "AAPL");
+
+    ticker.quote().await.unwrap();
+    mock.assert_hits(1);
+
+    // Still fresh at exactly the TTL.
+    clock.advance(TimeDelta::seconds(60));
+    ticker.quote().await.unwrap();
+    mock.assert_hits(1);
+
+    // One second past the TTL the entry has expired.
+    clock.advance(TimeDelta::seconds(1));
+    ticker.quote().await.unwrap();
+    mock.assert_hits(2);
+}
@@ -0,0 +1,153 @@
+use std::net::SocketAddr;
+
+use url::Url;
+use yfinance_rs::{SearchBuilder, Ticker, YahooHost, YfClient, YfError};
+
+// Both Yahoo API hosts resolve to a closed local port, so every request fails
+// fast with a connection error that still carries the URL that was built.
+fn client(host: Option<YahooHost>) -> YfClient {
+    let closed: SocketAddr = "127.0.0.1:9".parse().unwrap();
+    let http = reqwest::Client::builder()
+        .resolve(YahooHost::Query1.as_str(), closed)
+        .resolve(YahooHost::Query2.as_str(), closed)
+        .build()
+        .unwrap();
+    let mut builder = YfClient::builder()
+        .custom_client(http)
+        .retry_enabled(false)
+        ._preauth("cookie", "crumb");
+    if let Some(host) = host {
+        builder = builder.host(host);
+    }
+    builder.build().unwrap()
+}
+
+async fn failed_url(client: &YfClient) -> Url {
+    let err = Ticker::new(client, "AAPL").quote().await.unwrap_err();
+    match err {
+        YfError::Http(e) => e.url().cloned().expect("request url"),
+        other => panic!("expected a connection error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn host_query2_is_used_for_default_urls() {
+    let url = failed_url(&client(Some(YahooHost::Query2))).await;
+    assert_eq!(url.host_str(), Some("query2.finance.yahoo.com"));
+    assert!(url.path().starts_with("/v7/finance/quote"), "url: {url}");
+}
+
+#[tokio::test]
+async fn host_defaults_to_query1() {
+    let url = failed_url(&client(None)).await;
+    assert_eq!(url.host_str(), Some("query1.finance.yahoo.com"));
+
+    let url = failed_url(&client(Some(YahooHost::Query1))).await;
+    assert_eq!(url.host_str(), Some("query1.finance.yahoo.com"));
+}
+
+#[tokio::test]
+async fn host_applies_to_search() {
+    for (host, expected) in [
+        (YahooHost::Query1, "query1.finance.yahoo.com"),
+        (YahooHost::Query2, "query2.finance.yahoo.com"),
+    ] {
+        let err = SearchBuilder::new(&client(Some(host)), "apple")
+            .fetch()
+            .await
+            .unwrap_err();
+        let url = match err {
+            YfError::Http(e) => e.url().cloned().expect("request url"),
+            other => panic!("expected a connection error, got {other:?}"),
+        };
+        assert_eq!(url.host_str(), Some(expected));
+        assert!(url.path().starts_with("/v1/finance/search"), "url: {url}");
+    }
+}
+
+#[tokio::test]
+async fn explicit_base_url_overrides_host() {
+    let closed: SocketAddr = "127.0.0.1:9".parse().unwrap();
+    let http = reqwest::Client::builder()
+        .resolve("custom.example.com", closed)
+        .build()
+        .unwrap();
+    let client = YfClient::builder()
+        .custom_client(http)
+        .retry_enabled(false)
+        ._preauth("cookie", "crumb")
+        .host(YahooHost::Query2)
+        .base_quote_v7(Url::parse("https://custom.example.com/v7/finance/quote").unwrap())
+        .build()
+        .unwrap();
+    let url = failed_url(&client).await;
+    assert_eq!(url.host_str(), Some("custom.example.com"));
+}
+
+// Both Yahoo API hosts resolve to the mock server, which tells them apart by `Host`.
+fn failover_client(server: &httpmock::MockServer, failover: bool) -> YfClient {
+    let addr = *server.address();
+    let http = reqwest::Client::builder()
+        .resolve(YahooHost::Query1.as_str(), addr)
+        .resolve(YahooHost::Query2.as_str(), addr)
+        .build()
+        .unwrap();
+    let base = format!(
+        "http://{}:{}/v7/finance/quote",
+        YahooHost::Query1.as_str(),
+        addr.port()
+    );
+    YfClient::builder()
+        .custom_client(http)
+        .retry_enabled(false)
+        .base_quote_v7(Url::parse(&base).unwrap())
+        .host_failover(failover)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn host_failover_moves_to_query2_when_query1_fails() {
+    let server = httpmock::MockServer::start();
+    let port = server.address().port();
+    let query1 = server.mock(|when, then| {
+        when.path("/v7/finance/quote")
+            .header("host", format!("{}:{port}", YahooHost::Query1.as_str()));
+        then.status(503);
+    });
+    let query2 = server.mock(|when, then| {
+        when.path("/v7/finance/quote")
+            .query_param("symbols", "AAPL")
+            .header("host", format!("{}:{port}", YahooHost::Query2.as_str()));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":190.5,"currency":"USD"}],"error":null}}"#);
+    });
+
+    let quote = Ticker::new(&failover_client(&server, true), "AAPL")
+        .quote()
+        .await
+        .unwrap();
+    assert_eq!(quote.symbol, "AAPL");
+    query1.assert_hits(1);
+    query2.assert_hits(1);
+}
+
+#[tokio::test]
+async fn host_failover_is_off_by_default() {
+    let server = httpmock::MockServer::start();
+    let query1 = server.mock(|when, then| {
+        when.path("/v7/finance/quote");
+        then.status(503);
+    });
+
+    let err = Ticker::new(&failover_client(&server, false), "AAPL")
+        .quote()
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, YfError::ServerError { status: 503, .. }),
+        "{err:?}"
+    );
+    query1.assert_hits(1);
+}
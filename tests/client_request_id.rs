@@ -0,0 +1,98 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Ticker, YfClient, YfError};
+
+const QUOTE: &str = r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":190.5,"currency":"USD"}],"error":null}}"#;
+
+#[tokio::test]
+async fn request_id_header_is_sent_with_each_request() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .header("X-Request-Id", "req-1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(QUOTE);
+    });
+
+    let counter = AtomicUsize::new(0);
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .request_id_provider(move || format!("req-{}", counter.fetch_add(1, Ordering::SeqCst) + 1))
+        .build()
+        .unwrap();
+
+    Ticker::new(&client, "AAPL").quote().await.unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn request_id_header_name_is_configurable() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .header("X-Correlation-Id", "abc");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(QUOTE);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .request_id_provider(|| "abc".to_string())
+        .request_id_header("X-Correlation-Id")
+        .build()
+        .unwrap();
+
+    Ticker::new(&client, "AAPL").quote().await.unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn status_errors_carry_the_request_id() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/FAIL")
+            .header("X-Request-Id", "req-503");
+        then.status(503).body("unavailable");
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .retry_enabled(false)
+        .request_id_provider(|| "req-503".to_string())
+        .build()
+        .unwrap();
+
+    let err = yfinance_rs::HistoryBuilder::new(&client, "FAIL")
+        .fetch()
+        .await
+        .unwrap_err();
+    mock.assert();
+
+    match &err {
+        YfError::ServerError { status, url } => {
+            assert_eq!(*status, 503);
+            assert!(url.contains("/v8/finance/chart/FAIL"), "got {url}");
+            assert!(url.ends_with("(X-Request-Id: req-503)"), "got {url}");
+        }
+        other => panic!("expected ServerError, got {other:?}"),
+    }
+    assert!(err.to_string().contains("req-503"), "got {err}");
+}
+
+#[test]
+fn invalid_request_id_header_is_rejected() {
+    let err = YfClient::builder()
+        .request_id_provider(|| "abc".to_string())
+        .request_id_header("not a header")
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, YfError::InvalidParams(_)), "got {err:?}");
+}
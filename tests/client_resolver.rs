@@ -0,0 +1,49 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Endpoint, PathResolver, Ticker, YfClient, YfError};
+
+// Lays symbols out as `{base}/{endpoint}/{symbol}.json`, as a static mirror might.
+#[derive(Debug)]
+struct MirrorResolver {
+    root: Url,
+}
+
+impl PathResolver for MirrorResolver {
+    fn resolve(&self, endpoint: Endpoint, _base: &Url, symbol: &str) -> Result<Url, YfError> {
+        let dir = match endpoint {
+            Endpoint::Chart => "chart",
+            _ => "other",
+        };
+        Ok(self.root.join(&format!("mirror/{dir}/{symbol}.json"))?)
+    }
+}
+
+#[tokio::test]
+async fn custom_resolver_controls_chart_path() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/mirror/chart/AAPL.json")
+            .query_param("interval", "1d");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"chart":{"result":[{"meta":{"currency":"USD"},"timestamp":[1704205800],"indicators":{"quote":[{"open":[1.0],"high":[1.0],"low":[1.0],"close":[1.0],"volume":[10]}],"adjclose":[{"adjclose":[1.0]}]}}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .path_resolver(MirrorResolver {
+            root: Url::parse(&format!("{}/", server.base_url())).unwrap(),
+        })
+        .build()
+        .unwrap();
+
+    let candles = Ticker::new(&client, "AAPL")
+        .history_builder()
+        .fetch()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(candles.len(), 1);
+}
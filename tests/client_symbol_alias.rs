@@ -0,0 +1,155 @@
+mod common;
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{QuotesBuilder, Range, Ticker, YfClient};
+
+const QUOTE: &str = r#"{"quoteResponse":{"result":[{"symbol":"GOOGL","regularMarketPrice":170.25,"currency":"USD"},{"symbol":"MSFT","regularMarketPrice":410.0,"currency":"USD"}],"error":null}}"#;
+
+const CHART: &str = r#"{"chart":{"result":[{"meta":{"currency":"USD","symbol":"GOOGL","timezone":"America/New_York","gmtoffset":-18000},"timestamp":[1704067200],"indicators":{"quote":[{"open":[140.0],"high":[141.0],"low":[139.0],"close":[140.5],"volume":[1000]}],"adjclose":[{"adjclose":[140.5]}]}}],"error":null}}"#;
+
+fn client(server: &MockServer) -> YfClient {
+    crate::common::builder_for(server)
+        .symbol_alias("GOOG_MAIN", "GOOGL")
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn alias_is_translated_in_and_out_of_quotes() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "GOOGL,MSFT");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(QUOTE);
+    });
+
+    let quotes = QuotesBuilder::new(client(&server))
+        .symbols(["GOOG_MAIN", "MSFT"])
+        .fetch()
+        .await
+        .unwrap();
+    mock.assert();
+
+    let symbols: Vec<&str> = quotes.iter().map(|q| q.symbol.as_str()).collect();
+    assert_eq!(symbols, ["GOOG_MAIN", "MSFT"]);
+}
+
+#[tokio::test]
+async fn alias_is_used_for_path_based_endpoints() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/GOOGL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CHART);
+    });
+
+    let candles = Ticker::new(&client(&server), "GOOG_MAIN")
+        .history(Some(Range::D5), None, false)
+        .await
+        .unwrap();
+    mock.assert();
+    assert_eq!(candles.len(), 1);
+}
+
+#[tokio::test]
+async fn alias_is_used_in_timeseries_query() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/ws/fundamentals-timeseries/v1/finance/timeseries/GOOGL")
+            .query_param("symbol", "GOOGL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"timeseries":{"result":[{
+                  "meta":{"symbol":["GOOGL"],"type":["quarterlyBasicAverageShares"]},
+                  "timestamp":[1704067200],
+                  "quarterlyBasicAverageShares":[{"reportedValue":{"raw":1000}}]
+                }]}}"#,
+            );
+    });
+    let client = YfClient::builder()
+        .base_timeseries(
+            Url::parse(&format!(
+                "{}/ws/fundamentals-timeseries/v1/finance/timeseries/",
+                server.base_url()
+            ))
+            .unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .symbol_alias("GOOG_MAIN", "GOOGL")
+        .build()
+        .unwrap();
+
+    let shares = Ticker::new(&client, "GOOG_MAIN")
+        .quarterly_shares()
+        .await
+        .unwrap();
+    mock.assert();
+    assert_eq!(shares.len(), 1);
+}
+
+#[tokio::test]
+async fn alias_is_translated_in_and_out_of_websocket_streams() {
+    use base64::Engine as _;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("ws://{}/", listener.local_addr().unwrap())).unwrap();
+    let (frame_tx, mut frames) = tokio::sync::mpsc::channel::<String>(8);
+    tokio::spawn(async move {
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+        if let Some(Ok(Message::Text(text))) = ws.next().await {
+            frame_tx.send(text.to_string()).await.unwrap();
+        }
+        // A PricingData message with only `id = "GOOGL"` set (field 1, length-delimited).
+        let mut pricing = vec![0x0a, 5];
+        pricing.extend_from_slice(b"GOOGL");
+        let b64 = base64::engine::general_purpose::STANDARD.encode(&pricing);
+        ws.send(Message::Text(b64.into())).await.unwrap();
+        while let Some(Ok(Message::Text(text))) = ws.next().await {
+            frame_tx.send(text.to_string()).await.unwrap();
+        }
+    });
+
+    let client = YfClient::builder()
+        .base_stream(url)
+        .symbol_alias("GOOG_MAIN", "GOOGL")
+        .build()
+        .unwrap();
+    let (handle, mut rx) = yfinance_rs::StreamBuilder::new(&client)
+        .symbols(["GOOG_MAIN"])
+        .method(yfinance_rs::StreamMethod::Websocket)
+        .start()
+        .unwrap();
+
+    let wait = std::time::Duration::from_secs(3);
+    let subscribe = tokio::time::timeout(wait, frames.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(subscribe, r#"{"subscribe":["GOOGL"]}"#);
+
+    let update = tokio::time::timeout(wait, rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(update.symbol, "GOOG_MAIN");
+
+    assert!(handle.unsubscribe(["GOOG_MAIN"]));
+    let unsubscribe = tokio::time::timeout(wait, frames.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unsubscribe, r#"{"unsubscribe":["GOOGL"]}"#);
+
+    handle.stop().await;
+}
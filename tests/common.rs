@@ -5,6 +5,8 @@ use std::{
     fs,
     path::{Path, PathBuf},
 };
+use url::Url;
+use yfinance_rs::{YfClient, YfClientBuilder};
 
 #[must_use]
 pub fn setup_server() -> MockServer {
@@ -56,6 +58,34 @@ pub fn mock_cookie_crumb(server: &'_ MockServer) -> (Mock<'_>, Mock<'_>) {
     (cookie_mock, crumb_mock)
 }
 
+/// Returns a pre-authenticated client builder whose Yahoo endpoints all point at `server`.
+///
+/// # Panics
+///
+/// Panics if the server's base URL cannot be parsed.
+#[must_use]
+pub fn builder_for(server: &MockServer) -> YfClientBuilder {
+    let url = |path: &str| Url::parse(&format!("{}{path}", server.base_url())).unwrap();
+    YfClient::builder()
+        .base_chart(url("/v8/finance/chart/"))
+        .base_quote_v7(url("/v7/finance/quote"))
+        .base_quote_api(url("/v10/finance/quoteSummary/"))
+        .base_options_v7(url("/v7/finance/options/"))
+        .base_spark(url("/v8/finance/spark"))
+        .base_news(url(""))
+        ._preauth("cookie", "crumb")
+}
+
+/// Builds the client returned by [`builder_for`].
+///
+/// # Panics
+///
+/// Panics if the client cannot be built.
+#[must_use]
+pub fn client_for(server: &MockServer) -> YfClient {
+    builder_for(server).build().unwrap()
+}
+
 #[must_use]
 pub fn mock_history_chart<'a>(server: &'a MockServer, symbol: &'a str) -> Mock<'a> {
     server.mock(|when, then| {
@@ -1,5 +1,7 @@
 mod common;
 
+#[path = "currency/display_currency.rs"]
+mod display_currency;
 #[path = "currency/inference_live.rs"]
 mod inference_live;
 #[path = "currency/inference_offline.rs"]
@@ -0,0 +1,167 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use paft::money::{Currency, IsoCurrency};
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Clock, QuotesBuilder, Ticker, YfClientBuilder};
+
+const QUOTE: &str = r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":200.0,"regularMarketPreviousClose":190.0,"currency":"USD"}],"error":null}}"#;
+const MSFT_QUOTE: &str = r#"{"quoteResponse":{"result":[{"symbol":"MSFT","regularMarketPrice":400.0,"currency":"USD"}],"error":null}}"#;
+
+const USDEUR: &str = r#"{"chart":{"result":[{"meta":{"currency":"EUR","symbol":"USDEUR=X"},"timestamp":[1704067200,1704153600],"indicators":{"quote":[{"open":[0.9,0.92],"high":[0.9,0.92],"low":[0.9,0.92],"close":[0.9,0.92],"volume":[0,0]}]}}],"error":null}}"#;
+
+#[tokio::test]
+async fn quote_is_restated_in_display_currency() {
+    let server = MockServer::start();
+    let quote = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(QUOTE);
+    });
+    let fx = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/USDEUR=X");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(USDEUR);
+    });
+
+    let msft = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "MSFT");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(MSFT_QUOTE);
+    });
+
+    let client = builder_for(&server)
+        .cache_ttl(Duration::from_secs(60))
+        .build()
+        .unwrap();
+    let ticker = Ticker::new(&client, "AAPL");
+
+    let q = ticker.quote().await.unwrap();
+    let price = q.price.expect("price");
+    assert_eq!(price.currency(), &Currency::Iso(IsoCurrency::EUR));
+    assert!((money_to_f64(&price) - 184.0).abs() < 1e-9);
+    let prev = q.previous_close.expect("previous close");
+    assert!((money_to_f64(&prev) - 174.8).abs() < 1e-9);
+
+    // The rate is kept on the client, so another USD quote costs no extra FX request.
+    let q = Ticker::new(&client, "MSFT").quote().await.unwrap();
+    assert!((money_to_f64(&q.price.unwrap()) - 368.0).abs() < 1e-9);
+    quote.assert_hits(1);
+    msft.assert_hits(1);
+    fx.assert_hits(1);
+}
+
+fn builder_for(server: &MockServer) -> YfClientBuilder {
+    crate::common::builder_for(server).display_currency(Currency::Iso(IsoCurrency::EUR))
+}
+
+fn mock_quote_and_fx(server: &MockServer) -> (httpmock::Mock<'_>, httpmock::Mock<'_>) {
+    let quote = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(QUOTE);
+    });
+    let fx = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/USDEUR=X");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(USDEUR);
+    });
+    (quote, fx)
+}
+
+// A clock that only moves when the test advances it.
+#[derive(Debug, Clone)]
+struct ManualClock(Arc<Mutex<DateTime<Utc>>>);
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
+#[tokio::test]
+async fn display_rate_expires_with_the_cache_ttl() {
+    let server = MockServer::start();
+    let (quote, fx) = mock_quote_and_fx(&server);
+    let clock = ManualClock(Arc::new(Mutex::new(
+        DateTime::from_timestamp(1_704_205_800, 0).unwrap(),
+    )));
+    let client = builder_for(&server)
+        .cache_ttl(Duration::from_secs(60))
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+    let ticker = Ticker::new(&client, "AAPL");
+
+    ticker.quote().await.unwrap();
+    *clock.0.lock().unwrap() += TimeDelta::seconds(61);
+    ticker.quote().await.unwrap();
+
+    quote.assert_hits(2);
+    fx.assert_hits(2);
+}
+
+#[tokio::test]
+async fn display_rate_is_not_kept_without_a_cache() {
+    let server = MockServer::start();
+    let (quote, fx) = mock_quote_and_fx(&server);
+    let client = builder_for(&server).build().unwrap();
+    let ticker = Ticker::new(&client, "AAPL");
+
+    ticker.quote().await.unwrap();
+    ticker.quote().await.unwrap();
+
+    quote.assert_hits(2);
+    fx.assert_hits(2);
+}
+
+#[tokio::test]
+async fn quotes_builder_outputs_are_restated_in_display_currency() {
+    let server = MockServer::start();
+    let (_quote, _fx) = mock_quote_and_fx(&server);
+    let client = builder_for(&server).build().unwrap();
+    let eur = Currency::Iso(IsoCurrency::EUR);
+
+    let details = QuotesBuilder::new(client.clone())
+        .symbols(["AAPL"])
+        .fetch_details()
+        .await
+        .unwrap();
+    let price = details[0].quote.price.clone().unwrap();
+    assert_eq!(price.currency(), &eur);
+    assert!((money_to_f64(&price) - 184.0).abs() < 1e-9);
+
+    let (quotes, failures) = QuotesBuilder::new(client.clone())
+        .symbols(["AAPL"])
+        .fetch_partial()
+        .await
+        .unwrap();
+    assert!(failures.is_empty());
+    let price = quotes[0].price.clone().unwrap();
+    assert_eq!(price.currency(), &eur);
+    assert!((money_to_f64(&price) - 184.0).abs() < 1e-9);
+
+    let (infos, dropped) = QuotesBuilder::new(client)
+        .symbols(["AAPL"])
+        .fetch_fast_info()
+        .await
+        .unwrap();
+    assert!(dropped.is_empty());
+    assert_eq!(infos[0].currency.as_deref(), Some("EUR"));
+    assert!((infos[0].last_price - 184.0).abs() < 1e-9);
+    assert!((infos[0].previous_close.unwrap() - 174.8).abs() < 1e-9);
+}
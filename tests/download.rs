@@ -1,5 +1,7 @@
 mod common;
 
+#[path = "download/aligned.rs"]
+mod download_aligned;
 #[path = "download/back_adjust.rs"]
 mod download_back_adjust;
 #[path = "download/keepna_rounding.rs"]
@@ -10,3 +12,5 @@ mod download_live;
 mod download_offline;
 #[path = "download/repair.rs"]
 mod download_repair;
+#[path = "download/stream.rs"]
+mod download_stream;
@@ -0,0 +1,82 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{DownloadBuilder, YfError};
+
+fn chart(timestamps: &[i64], closes: &[f64]) -> String {
+    let ts = serde_json::to_string(timestamps).unwrap();
+    let px = serde_json::to_string(closes).unwrap();
+    let vol = serde_json::to_string(&vec![1000; closes.len()]).unwrap();
+    format!(
+        r#"{{"chart":{{"result":[{{"timestamp":{ts},"indicators":{{"quote":[{{"open":{px},"high":{px},"low":{px},"close":{px},"volume":{vol}}}],"adjclose":[{{"adjclose":{px}}}]}}}}],"error":null}}}}"#
+    )
+}
+
+#[tokio::test]
+async fn download_aligns_symbols_on_union_of_timestamps() {
+    let server = MockServer::start();
+    let aapl = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chart(&[10, 20, 30], &[1.0, 2.0, 3.0]));
+    });
+    let msft = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/MSFT");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chart(&[20, 40], &[5.0, 6.0]));
+    });
+
+    let res = DownloadBuilder::new(&crate::common::client_for(&server))
+        .symbols(["MSFT", "AAPL"])
+        .concurrency(1)
+        .run()
+        .await
+        .unwrap();
+
+    aapl.assert();
+    msft.assert();
+
+    let aligned = res.aligned();
+    let secs: Vec<i64> = aligned.timestamps.iter().map(|t| t.timestamp()).collect();
+    assert_eq!(secs, vec![10, 20, 30, 40]);
+    assert_eq!(aligned.symbols, vec!["AAPL", "MSFT"]);
+
+    let msft_col = aligned.column("MSFT").unwrap();
+    let present: Vec<bool> = msft_col.iter().map(Option::is_some).collect();
+    assert_eq!(present, vec![false, true, false, true]);
+    assert!(aligned.column("TSLA").is_none());
+
+    let wide = aligned.wide_closes();
+    assert_eq!(wide.len(), 4);
+    let row = |i: usize| -> Vec<Option<f64>> {
+        wide[i]
+            .1
+            .iter()
+            .map(|m| m.as_ref().map(money_to_f64))
+            .collect()
+    };
+    assert_eq!(row(0), vec![Some(1.0), None]);
+    assert_eq!(row(1), vec![Some(2.0), Some(5.0)]);
+    assert_eq!(row(3), vec![None, Some(6.0)]);
+}
+
+#[tokio::test]
+async fn download_rejects_zero_concurrency() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200).body(chart(&[10], &[1.0]));
+    });
+
+    let err = DownloadBuilder::new(&crate::common::client_for(&server))
+        .symbols(["AAPL"])
+        .concurrency(0)
+        .run()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, YfError::InvalidParams(_)), "got {err:?}");
+    mock.assert_hits(0);
+}
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use futures::StreamExt;
+use httpmock::Method::GET;
+use url::Url;
+
+use crate::common;
+use yfinance_rs::core::{Interval, Range};
+use yfinance_rs::{DownloadBuilder, YfClient};
+
+#[tokio::test]
+async fn download_stream_yields_every_symbol() {
+    let server = common::setup_server();
+
+    let mocks: Vec<_> = ["AAPL", "MSFT", "TSLA"]
+        .into_iter()
+        .map(|sym| {
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path(format!("/v8/finance/chart/{sym}"))
+                    .query_param("range", "6mo")
+                    .query_param("interval", "1d");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(common::fixture("history_chart", "AAPL", "json"));
+            })
+        })
+        .collect();
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let items: Vec<_> = DownloadBuilder::new(&client)
+        .symbols(["AAPL", "MSFT", "TSLA"])
+        .range(Range::M6)
+        .interval(Interval::D1)
+        .stream()
+        .collect()
+        .await;
+
+    for m in &mocks {
+        m.assert();
+    }
+
+    assert_eq!(items.len(), 3);
+    let seen: HashSet<_> = items.iter().map(|(sym, _)| sym.as_str()).collect();
+    assert_eq!(seen, HashSet::from(["AAPL", "MSFT", "TSLA"]));
+    for (sym, res) in items {
+        let candles = res.unwrap_or_else(|e| panic!("{sym} failed: {e}"));
+        assert!(!candles.is_empty(), "{sym} returned no candles");
+    }
+}
+
+#[tokio::test]
+async fn download_stream_reports_per_symbol_failures() {
+    let server = common::setup_server();
+
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(common::fixture("history_chart", "AAPL", "json"));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/NOPE");
+        then.status(404);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .retry_enabled(false)
+        .build()
+        .unwrap();
+
+    let items: Vec<_> = DownloadBuilder::new(&client)
+        .symbols(["AAPL", "NOPE"])
+        .stream()
+        .collect()
+        .await;
+
+    assert_eq!(items.len(), 2);
+    for (sym, res) in items {
+        match sym.as_str() {
+            "AAPL" => assert!(res.is_ok()),
+            "NOPE" => assert!(res.is_err()),
+            other => panic!("unexpected symbol {other}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn download_stream_honors_concurrency_limit() {
+    let server = common::setup_server();
+
+    // The slow symbol is listed first; with one fetch at a time the fast one cannot overtake it.
+    let slow = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(std::time::Duration::from_millis(300))
+            .body(common::fixture("history_chart", "AAPL", "json"));
+    });
+    let fast = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/MSFT");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(common::fixture("history_chart", "AAPL", "json"));
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let order: Vec<String> = DownloadBuilder::new(&client)
+        .symbols(["AAPL", "MSFT"])
+        .concurrency(1)
+        .stream()
+        .map(|(sym, res)| {
+            res.unwrap_or_else(|e| panic!("{sym} failed: {e}"));
+            sym
+        })
+        .collect()
+        .await;
+
+    slow.assert();
+    fast.assert();
+    assert_eq!(order, vec!["AAPL", "MSFT"]);
+}
+
+#[tokio::test]
+async fn download_stream_rejects_zero_concurrency_per_symbol() {
+    let server = common::setup_server();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .body(common::fixture("history_chart", "AAPL", "json"));
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let items: Vec<_> = DownloadBuilder::new(&client)
+        .symbols(["AAPL"])
+        .concurrency(0)
+        .stream()
+        .collect()
+        .await;
+
+    mock.assert_hits(0);
+    assert_eq!(items.len(), 1);
+    assert!(matches!(
+        items[0].1,
+        Err(yfinance_rs::YfError::InvalidParams(_))
+    ));
+}
@@ -1,5 +1,7 @@
 mod common;
 
+#[path = "esg/involvement_flags.rs"]
+mod esg_involvement_flags;
 #[path = "esg/live.rs"]
 mod esg_live;
 #[path = "esg/live_involvement.rs"]
@@ -0,0 +1,38 @@
+use httpmock::{Method::GET, MockServer};
+use url::Url;
+use yfinance_rs::{Ticker, YfClient};
+
+#[tokio::test]
+async fn involvement_flags_parse_as_optional_booleans() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/MO")
+            .query_param("modules", "esgScores");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteSummary":{"result":[{"esgScores":{
+                "environmentScore":{"raw":2.1},
+                "tobacco":true,
+                "gambling":false
+            }}],"error":null}}"#,
+            );
+    });
+    let client = YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let flags = Ticker::new(&client, "MO")
+        .sustainability_involvement()
+        .await
+        .unwrap();
+
+    assert_eq!(flags.tobacco, Some(true));
+    assert_eq!(flags.gambling, Some(false));
+    assert_eq!(flags.alcoholic, None);
+}
@@ -1,5 +1,9 @@
 mod common;
 
+#[path = "fundamentals/earnings_schedule.rs"]
+mod earnings_schedule;
+#[path = "fundamentals/ex_dividend_date.rs"]
+mod ex_dividend_date;
 #[path = "fundamentals/fcf_fallback.rs"]
 mod fcf_fallback;
 #[path = "fundamentals/live.rs"]
@@ -8,3 +12,7 @@ mod fundamentals_live;
 mod fundamentals_offline;
 #[path = "fundamentals/retry_synthetic.rs"]
 mod fundamentals_retry_synth;
+#[path = "fundamentals/periods.rs"]
+mod periods;
+#[path = "fundamentals/statements_raw.rs"]
+mod statements_raw;
@@ -0,0 +1,109 @@
+use httpmock::{Method::GET, MockServer};
+use paft::money::{Currency, IsoCurrency};
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Ticker, YfClient};
+
+// Quarter ends: 2024-06-30, 2024-09-30, 2024-12-31 (deliberately out of order).
+const HISTORY: &str = r#""earningsHistory": {
+  "history": [
+    {"quarter":{"raw":1727654400},"epsActual":{"raw":1.64},"epsEstimate":{"raw":1.60},"surprisePercent":{"raw":0.025}},
+    {"quarter":{"raw":1719705600},"epsActual":{"raw":1.40},"epsEstimate":{"raw":1.35},"surprisePercent":{"raw":0.037}},
+    {"quarter":{"raw":1735603200},"epsActual":{"raw":2.40},"epsEstimate":{"raw":2.35},"surprisePercent":{"raw":0.021}}
+  ]
+}"#;
+
+fn body(earnings_dates: &str, is_estimate: bool) -> String {
+    format!(
+        r#"{{"quoteSummary":{{"result":[{{
+          {HISTORY},
+          "calendarEvents": {{"earnings": {{
+            "earningsDate": {earnings_dates},
+            "earningsAverage": {{"raw": 1.62}},
+            "isEarningsDateEstimate": {is_estimate}
+          }}}}
+        }}],"error":null}}}}"#
+    )
+}
+
+async fn schedule_for(
+    server: &MockServer,
+    earnings_dates: &str,
+    is_estimate: bool,
+) -> Vec<yfinance_rs::fundamentals::EarningsEvent> {
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", "earningsHistory,calendarEvents");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body(earnings_dates, is_estimate));
+    });
+
+    let client = YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let out = Ticker::new(&client, "AAPL")
+        .earnings_schedule(Some(Currency::Iso(IsoCurrency::USD)))
+        .await
+        .unwrap();
+    mock.assert();
+    out
+}
+
+#[tokio::test]
+async fn earnings_schedule_merges_history_and_next_date() {
+    let server = MockServer::start();
+    // 2025-05-01: more than a quarter after the last reported quarter end.
+    let events = schedule_for(&server, r#"[{"raw":1746057600},{"raw":1746403200}]"#, false).await;
+
+    assert_eq!(events.len(), 4, "one upcoming entry per window");
+    assert!(events.windows(2).all(|w| w[0].date < w[1].date));
+
+    assert!(
+        events[..3]
+            .iter()
+            .all(|e| !e.upcoming && e.eps_actual.is_some())
+    );
+    assert!((money_to_f64(events[2].eps_actual.as_ref().unwrap()) - 2.40).abs() < 1e-9);
+    assert_eq!(events[2].surprise_percent, Some(0.021));
+
+    let next = &events[3];
+    assert!(next.upcoming);
+    assert_eq!(next.date.timestamp(), 1_746_057_600);
+    assert!(next.eps_actual.is_none());
+    assert!(!next.date_is_estimate);
+    assert!((money_to_f64(next.eps_estimate.as_ref().unwrap()) - 1.62).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn earnings_schedule_does_not_duplicate_reported_quarter() {
+    let server = MockServer::start();
+    // 2025-01-30: the report for the 2024-12-31 quarter already in the history.
+    let events = schedule_for(&server, r#"[{"raw":1738195200}]"#, false).await;
+
+    assert_eq!(events.len(), 3);
+    assert!(events.iter().all(|e| !e.upcoming));
+    assert_eq!(events.last().unwrap().date.timestamp(), 1_735_603_200);
+}
+
+#[tokio::test]
+async fn earnings_schedule_flags_estimated_next_date() {
+    let server = MockServer::start();
+    let events = schedule_for(&server, r#"[{"raw":1746057600}]"#, true).await;
+
+    let next = events.last().unwrap();
+    assert!(next.upcoming);
+    assert!(next.date_is_estimate);
+    assert!(
+        events
+            .iter()
+            .filter(|e| !e.upcoming)
+            .all(|e| !e.date_is_estimate)
+    );
+}
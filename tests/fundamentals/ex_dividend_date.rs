@@ -0,0 +1,100 @@
+use chrono::{DateTime, TimeZone, Utc};
+use httpmock::{Method::GET, MockServer};
+use yfinance_rs::{Clock, Ticker, YfClient};
+
+#[derive(Debug)]
+struct FixedClock(DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+fn client_at(server: &MockServer, now: DateTime<Utc>) -> YfClient {
+    crate::common::builder_for(server)
+        .clock(FixedClock(now))
+        .build()
+        .unwrap()
+}
+
+/// A week before the KO ex-date used below (2025-09-22).
+fn client(server: &MockServer) -> YfClient {
+    client_at(server, Utc.with_ymd_and_hms(2025, 9, 15, 0, 0, 0).unwrap())
+}
+
+fn mock_ko(server: &MockServer) -> httpmock::Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/KO")
+            .query_param("modules", "calendarEvents");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteSummary":{"result":[{"calendarEvents":{
+                  "earnings":{"earningsDate":[{"raw":1761768000,"fmt":"2025-10-29"}]},
+                  "exDividendDate":{"raw":1758499200,"fmt":"2025-09-22"},
+                  "dividendDate":{"raw":1759104000,"fmt":"2025-09-29"}
+                }}],"error":null}}"#,
+            );
+    })
+}
+
+#[tokio::test]
+async fn ex_dividend_date_parses_calendar_events() {
+    let server = MockServer::start();
+    let mock = mock_ko(&server);
+
+    let ticker = Ticker::new(&client(&server), "KO");
+    let date = ticker.ex_dividend_date().await.unwrap();
+    assert_eq!(date.map(|d| d.timestamp()), Some(1_758_499_200));
+
+    let raw = ticker.calendar_events_raw().await.unwrap();
+    assert_eq!(raw["dividendDate"]["fmt"], "2025-09-29");
+    mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn ex_dividend_date_is_none_for_non_payers() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AMZN")
+            .query_param("modules", "calendarEvents");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteSummary":{"result":[{"calendarEvents":{
+                  "earnings":{"earningsDate":[{"raw":1761768000,"fmt":"2025-10-29"}]}
+                }}],"error":null}}"#,
+            );
+    });
+
+    let date = Ticker::new(&client(&server), "AMZN")
+        .ex_dividend_date()
+        .await
+        .unwrap();
+    assert_eq!(date, None);
+}
+
+#[tokio::test]
+async fn ex_dividend_date_skips_a_date_that_has_passed() {
+    let server = MockServer::start();
+    let mock = mock_ko(&server);
+
+    // On the ex-date itself it is still the next one.
+    let on_the_day = client_at(
+        &server,
+        Utc.with_ymd_and_hms(2025, 9, 22, 15, 0, 0).unwrap(),
+    );
+    let date = Ticker::new(&on_the_day, "KO")
+        .ex_dividend_date()
+        .await
+        .unwrap();
+    assert_eq!(date.map(|d| d.timestamp()), Some(1_758_499_200));
+
+    let after = client_at(&server, Utc.with_ymd_and_hms(2025, 9, 23, 0, 0, 0).unwrap());
+    let date = Ticker::new(&after, "KO").ex_dividend_date().await.unwrap();
+    assert_eq!(date, None);
+    mock.assert_hits(2);
+}
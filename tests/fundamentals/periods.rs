@@ -0,0 +1,67 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use paft::money::{Currency, IsoCurrency};
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{FundamentalsBuilder, YfClient};
+
+#[tokio::test]
+async fn periods_beyond_default_use_timeseries_endpoint() {
+    let server = MockServer::start();
+    let sym = "MSFT";
+
+    // Ten annual reports, a year apart.
+    let ts: Vec<i64> = (0..10).map(|i| 1_435_622_400 + i * 31_622_400).collect();
+    let revenue: Vec<String> = (0..10)
+        .map(|i| format!(r#"{{"reportedValue":{{"raw":{}.0}}}}"#, 100 + i))
+        .collect();
+    let body = format!(
+        r#"{{"timeseries":{{"result":[{{"meta":{{"type":["annualTotalRevenue"]}},"timestamp":{ts:?},"annualTotalRevenue":[{}]}}],"error":null}}}}"#,
+        revenue.join(",")
+    );
+
+    let timeseries = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!(
+                "/ws/fundamentals-timeseries/v1/finance/timeseries/{sym}"
+            ))
+            .query_param_exists("type");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+    let summary = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v10/finance/quoteSummary/{sym}"));
+        then.status(500);
+    });
+
+    let client = YfClient::builder()
+        .base_timeseries(
+            Url::parse(&format!(
+                "{}/ws/fundamentals-timeseries/v1/finance/timeseries/",
+                server.base_url()
+            ))
+            .unwrap(),
+        )
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let rows = FundamentalsBuilder::new(&client, sym)
+        .periods(10)
+        .income_statement(false, Some(Currency::Iso(IsoCurrency::USD)))
+        .await
+        .unwrap();
+
+    timeseries.assert();
+    summary.assert_hits(0);
+
+    assert_eq!(rows.len(), 10);
+    // Most recent period first.
+    let latest = rows[0].total_revenue.as_ref().expect("revenue");
+    assert!((money_to_f64(latest) - 109.0).abs() < 1e-9);
+}
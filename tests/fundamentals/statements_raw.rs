@@ -0,0 +1,58 @@
+use httpmock::{Method::GET, MockServer};
+use url::Url;
+use yfinance_rs::{Ticker, YfClient};
+
+#[tokio::test]
+async fn financials_raw_returns_all_three_statements() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param(
+                "modules",
+                "incomeStatementHistoryQuarterly,balanceSheetHistoryQuarterly,cashflowStatementHistoryQuarterly",
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteSummary":{"result":[{
+                  "incomeStatementHistoryQuarterly":{"incomeStatementHistory":[
+                    {"endDate":{"raw":1719705600},"totalRevenue":{"raw":85777000000},"researchDevelopment":{"raw":8006000000}}
+                  ]},
+                  "balanceSheetHistoryQuarterly":{"balanceSheetStatements":[
+                    {"endDate":{"raw":1719705600},"totalAssets":{"raw":331612000000}}
+                  ]},
+                  "cashflowStatementHistoryQuarterly":{"cashflowStatements":[
+                    {"endDate":{"raw":1719705600},"totalCashFromOperatingActivities":{"raw":28858000000}}
+                  ]}
+                }],"error":null}}"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let raw = Ticker::new(&client, "AAPL")
+        .financials_raw(true)
+        .await
+        .unwrap();
+    mock.assert();
+
+    for key in [
+        "incomeStatementHistoryQuarterly",
+        "balanceSheetHistoryQuarterly",
+        "cashflowStatementHistoryQuarterly",
+    ] {
+        assert!(raw.get(key).is_some(), "missing {key}");
+    }
+    assert_eq!(
+        raw["incomeStatementHistoryQuarterly"]["incomeStatementHistory"][0]["researchDevelopment"]
+            ["raw"],
+        8_006_000_000_i64
+    );
+}
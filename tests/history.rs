@@ -2,6 +2,8 @@ mod common;
 
 #[path = "history/adjust_from_splits_only.rs"]
 mod adjust_from_splits_only;
+#[path = "history/between_dates.rs"]
+mod between_dates;
 #[path = "history/adjust.rs"]
 mod history_adjust;
 #[path = "history/intervals.rs"]
@@ -32,3 +34,39 @@ mod retry_synthetic;
 
 #[path = "history/caching_synthetic.rs"]
 mod caching_synthetic;
+
+#[path = "history/vwap.rs"]
+mod vwap;
+
+#[path = "history/conditional_cache.rs"]
+mod conditional_cache;
+
+#[path = "history/currency_convert.rs"]
+mod currency_convert;
+
+#[path = "history/limit.rs"]
+mod limit;
+
+#[path = "history/returns_summary.rs"]
+mod returns_summary;
+
+#[path = "history/extra_param.rs"]
+mod extra_param;
+
+#[path = "history/minor_units.rs"]
+mod minor_units;
+
+#[path = "history/annotated.rs"]
+mod annotated;
+#[path = "history/columns.rs"]
+mod columns;
+#[path = "history/currency_change.rs"]
+mod currency_change;
+#[path = "history/gaps.rs"]
+mod gaps;
+#[path = "history/stitch.rs"]
+mod stitch;
+
+#[cfg(feature = "arrow")]
+#[path = "history/arrow.rs"]
+mod arrow;
@@ -0,0 +1,118 @@
+use httpmock::{Method::GET, MockServer};
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Split, Ticker, YfClient};
+
+#[tokio::test]
+async fn history_annotated_attaches_dividend_and_split_to_their_candles() {
+    let server = MockServer::start();
+
+    // Mon 8, Tue 9 and Wed 10 Jan 2024 at the NYSE open. A dividend goes ex on the 8th and a
+    // 2-for-1 split takes effect before the open on the 10th.
+    let body = r#"{
+      "chart":{"result":[{"meta":{"currency":"USD","timezone":"America/New_York"},
+        "timestamp":[1704724200,1704810600,1704897000],
+        "indicators":{"quote":[{
+          "open":[100.0,101.0,51.0],
+          "high":[100.0,101.0,51.0],
+          "low":[100.0,101.0,51.0],
+          "close":[100.0,101.0,51.0],
+          "volume":[10,10,10]
+        }]},
+        "events":{
+          "dividends":{"1704724200":{"date":1704724200,"amount":0.24}},
+          "splits":{"1704895200":{"date":1704895200,"numerator":2,"denominator":1}}
+        }}],"error":null}
+    }"#;
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+    let candles = Ticker::new(&client, "AAPL")
+        .history_annotated(None, None, false)
+        .await
+        .unwrap();
+
+    assert_eq!(candles.len(), 3);
+
+    let div = candles[0].dividend.as_ref().expect("dividend on the 8th");
+    assert!((money_to_f64(div) - 0.24).abs() < 1e-9);
+    assert_eq!(candles[0].split, None);
+
+    assert_eq!(candles[1].dividend, None);
+    assert_eq!(candles[1].split, None);
+
+    assert_eq!(candles[2].dividend, None);
+    assert_eq!(
+        candles[2].split,
+        Some(Split {
+            numerator: 2,
+            denominator: 1
+        })
+    );
+}
+
+#[tokio::test]
+async fn weekly_candles_collect_the_events_of_their_week() {
+    let server = MockServer::start();
+
+    // Weekly candles for the weeks of Mon 1 and Mon 8 Jan 2024. Two dividends fall mid-week
+    // in the first week, a split in the second, and a dividend before the first candle.
+    let body = r#"{
+      "chart":{"result":[{"meta":{"currency":"USD","timezone":"America/New_York"},
+        "timestamp":[1704119400,1704724200],
+        "indicators":{"quote":[{
+          "open":[100.0,101.0],
+          "high":[100.0,101.0],
+          "low":[100.0,101.0],
+          "close":[100.0,101.0],
+          "volume":[10,10]
+        }]},
+        "events":{
+          "dividends":{
+            "1703860200":{"date":1703860200,"amount":9.0},
+            "1704292200":{"date":1704292200,"amount":0.25},
+            "1704378600":{"date":1704378600,"amount":0.5}
+          },
+          "splits":{"1704983400":{"date":1704983400,"numerator":3,"denominator":1}}
+        }}],"error":null}
+    }"#;
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+    let candles = Ticker::new(&client, "AAPL")
+        .history_annotated(None, None, false)
+        .await
+        .unwrap();
+
+    assert_eq!(candles.len(), 2);
+    let div = candles[0]
+        .dividend
+        .as_ref()
+        .expect("dividends in the first week");
+    assert!((money_to_f64(div) - 0.75).abs() < 1e-9);
+    assert_eq!(candles[0].split, None);
+    assert_eq!(candles[1].dividend, None);
+    assert_eq!(
+        candles[1].split,
+        Some(Split {
+            numerator: 3,
+            denominator: 1
+        })
+    );
+}
@@ -0,0 +1,65 @@
+use arrow::datatypes::{DataType, TimeUnit};
+use httpmock::{Method::GET, MockServer};
+use url::Url;
+use yfinance_rs::{HistoryBuilder, HistoryResponse, ToRecordBatch, YfClient};
+
+#[tokio::test]
+async fn to_record_batch_has_one_row_per_candle_and_the_expected_schema() {
+    let server = MockServer::start();
+
+    let body = r#"{
+      "chart":{"result":[{"meta":{"currency":"USD","timezone":"America/New_York"},
+        "timestamp":[1704724200,1704810600,1704897000],
+        "indicators":{"quote":[{
+          "open":[10.0,11.0,12.0],
+          "high":[10.5,11.5,12.5],
+          "low":[9.5,10.5,11.5],
+          "close":[10.2,11.2,12.2],
+          "volume":[100,null,300]
+        }]}}],"error":null}
+    }"#;
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+    let resp = HistoryBuilder::new(&client, "AAPL")
+        .fetch_full()
+        .await
+        .unwrap();
+
+    let batch = resp.to_record_batch().unwrap();
+    assert_eq!(batch.num_rows(), resp.candles.len());
+    assert_eq!(batch.schema(), HistoryResponse::arrow_schema());
+
+    let schema = batch.schema();
+    let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "ts",
+            "open",
+            "high",
+            "low",
+            "close",
+            "volume",
+            "unadjusted_close"
+        ]
+    );
+    assert_eq!(
+        schema.field(0).data_type(),
+        &DataType::Timestamp(TimeUnit::Second, Some("UTC".into()))
+    );
+    assert_eq!(schema.field(4).data_type(), &DataType::Float64);
+    assert!(schema.field(5).is_nullable());
+
+    let mut parquet = Vec::new();
+    resp.write_parquet(&mut parquet).unwrap();
+    assert!(parquet.starts_with(b"PAR1"));
+}
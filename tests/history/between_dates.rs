@@ -0,0 +1,51 @@
+use crate::common;
+use chrono::NaiveDate;
+use httpmock::Method::GET;
+use url::Url;
+use yfinance_rs::{HistoryBuilder, YfClient, YfError};
+
+#[tokio::test]
+async fn between_naive_dates_converts_to_epoch_seconds() {
+    let server = common::setup_server();
+
+    // 2024-01-01T00:00:00Z and 2024-02-01T00:00:00Z
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/AAPL")
+            .query_param("period1", "1704067200")
+            .query_param("period2", "1706745600");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(common::fixture("history_chart", "AAPL", "json"));
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let _ = HistoryBuilder::new(&client, "AAPL")
+        .between(
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+        )
+        .fetch()
+        .await
+        .unwrap();
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn between_rejects_start_not_before_end() {
+    let client = YfClient::default();
+    let day = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+
+    let err = HistoryBuilder::new(&client, "AAPL")
+        .between(day, day)
+        .fetch()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, YfError::InvalidDates));
+}
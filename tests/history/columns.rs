@@ -0,0 +1,130 @@
+use httpmock::{Method::GET, MockServer};
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{ColumnSet, HistoryBuilder, YfClient, YfError};
+
+const CHART: &str = r#"{"chart":{"result":[{
+  "meta":{"currency":"USD","symbol":"AAPL","timezone":"America/New_York","gmtoffset":-18000},
+  "timestamp":[1704205800,1704292200,1704378600],
+  "indicators":{
+    "quote":[{"open":[187.15,184.22,182.15],"high":[188.44,185.88,183.09],"low":[183.89,183.43,180.88],"close":[185.64,184.25,181.91],"volume":[82488700,58414500,71983600]}],
+    "adjclose":[{"adjclose":[184.94,183.56,null]}]
+  }
+}],"error":null}}"#;
+
+#[tokio::test]
+async fn adj_close_only_leaves_raw_columns_out() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CHART);
+    });
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let cols = HistoryBuilder::new(&client, "AAPL")
+        .columns(ColumnSet::ADJ_CLOSE)
+        .keepna(true)
+        .fetch_columns()
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(
+        cols.ts.iter().map(|t| t.timestamp()).collect::<Vec<_>>(),
+        [1_704_205_800, 1_704_292_200, 1_704_378_600]
+    );
+    assert_eq!(cols.adj_close, Some(vec![Some(184.94), Some(183.56), None]));
+    assert_eq!(cols.currency.as_deref(), Some("USD"));
+    assert!(cols.open.is_none());
+    assert!(cols.high.is_none());
+    assert!(cols.low.is_none());
+    assert!(cols.close.is_none());
+    assert!(cols.volume.is_none());
+}
+
+fn mock_chart(server: &MockServer) -> httpmock::Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CHART);
+    })
+}
+
+#[tokio::test]
+async fn rows_with_missing_prices_are_dropped_unless_keepna() {
+    let server = MockServer::start();
+    let mock = mock_chart(&server);
+    let client = crate::common::client_for(&server);
+
+    let cols = HistoryBuilder::new(&client, "AAPL")
+        .columns(ColumnSet::ADJ_CLOSE)
+        .fetch_columns()
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(cols.ts.len(), 2);
+    assert_eq!(cols.adj_close, Some(vec![Some(184.94), Some(183.56)]));
+}
+
+#[tokio::test]
+async fn auto_adjust_scales_price_columns_like_fetch() {
+    let server = MockServer::start();
+    let mock = mock_chart(&server);
+    let client = crate::common::client_for(&server);
+
+    let close_only = ColumnSet {
+        open: false,
+        high: false,
+        low: false,
+        close: true,
+        adj_close: false,
+        volume: false,
+    };
+    let adjusted = HistoryBuilder::new(&client, "AAPL")
+        .columns(close_only)
+        .fetch_columns()
+        .await
+        .unwrap();
+    let candles = HistoryBuilder::new(&client, "AAPL").fetch().await.unwrap();
+    let raw = HistoryBuilder::new(&client, "AAPL")
+        .columns(close_only)
+        .auto_adjust(false)
+        .fetch_columns()
+        .await
+        .unwrap();
+    mock.assert_hits(3);
+
+    assert!(adjusted.adj_close.is_none());
+    let closes: Vec<f64> = adjusted.close.unwrap().into_iter().flatten().collect();
+    let expected: Vec<f64> = candles.iter().map(|c| money_to_f64(&c.close)).collect();
+    assert_eq!(closes.len(), expected.len());
+    for (got, want) in closes.iter().zip(&expected) {
+        assert!((got - want).abs() < 1e-9, "{got} != {want}");
+    }
+    assert_eq!(
+        raw.close,
+        Some(vec![Some(185.64), Some(184.25), Some(181.91)])
+    );
+}
+
+#[tokio::test]
+async fn convert_to_is_rejected() {
+    let server = MockServer::start();
+    let mock = mock_chart(&server);
+
+    let err = HistoryBuilder::new(&crate::common::client_for(&server), "AAPL")
+        .convert_to("EUR")
+        .fetch_columns()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, YfError::InvalidParams(_)), "got {err:?}");
+    mock.assert_hits(0);
+}
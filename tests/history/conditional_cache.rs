@@ -0,0 +1,44 @@
+use httpmock::{Method::GET, MockServer};
+use std::time::Duration;
+use url::Url;
+use yfinance_rs::{HistoryBuilder, YfClient};
+
+#[tokio::test]
+async fn expired_entry_is_revalidated_and_304_serves_cached_body() {
+    let server = MockServer::start();
+    let sym = "ETAG";
+
+    let mut first = server.mock(|when, then| {
+        when.method(GET).path(format!("/v8/finance/chart/{sym}"));
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("ETag", "\"v1\"")
+            .header("Last-Modified", "Wed, 01 Jan 2025 00:00:00 GMT")
+            .body(crate::common::fixture("history_chart", "AAPL", "json"));
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .cache_ttl(Duration::from_millis(50))
+        .build()
+        .unwrap();
+    let builder = HistoryBuilder::new(&client, sym);
+
+    let bars1 = builder.clone().fetch().await.unwrap();
+    first.assert();
+    first.delete();
+
+    let not_modified = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v8/finance/chart/{sym}"))
+            .header("If-None-Match", "\"v1\"")
+            .header("If-Modified-Since", "Wed, 01 Jan 2025 00:00:00 GMT");
+        then.status(304);
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let bars2 = builder.clone().fetch().await.unwrap();
+    not_modified.assert();
+    assert_eq!(bars1, bars2);
+}
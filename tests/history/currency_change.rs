@@ -0,0 +1,52 @@
+use httpmock::{Method::GET, MockServer};
+use yfinance_rs::{HistoryBuilder, YfClient, YfError};
+
+fn block(currency: &str, ts: i64) -> String {
+    format!(
+        r#"{{"meta":{{"currency":"{currency}","symbol":"XYZ"}},"timestamp":[{ts}],"indicators":{{"quote":[{{"open":[1.0],"high":[1.0],"low":[1.0],"close":[1.0],"volume":[10]}}]}}}}"#
+    )
+}
+
+fn client_for(server: &MockServer, body: String) -> YfClient {
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/XYZ");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+    crate::common::client_for(server)
+}
+
+#[tokio::test]
+async fn currency_change_across_stitched_blocks_is_rejected() {
+    let server = MockServer::start();
+    let body = format!(
+        r#"{{"chart":{{"result":[{},{}],"error":null}}}}"#,
+        block("USD", 1_704_205_800),
+        block("GBP", 1_704_292_200)
+    );
+    let client = client_for(&server, body);
+
+    let err = HistoryBuilder::new(&client, "XYZ")
+        .fetch()
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, YfError::MissingData(msg) if msg.contains("USD -> GBP")),
+        "got {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn consistent_currency_across_blocks_is_accepted() {
+    let server = MockServer::start();
+    let body = format!(
+        r#"{{"chart":{{"result":[{},{}],"error":null}}}}"#,
+        block("USD", 1_704_205_800),
+        block("USD", 1_704_292_200)
+    );
+    let client = client_for(&server, body);
+
+    let candles = HistoryBuilder::new(&client, "XYZ").fetch().await.unwrap();
+    assert_eq!(candles.len(), 1);
+}
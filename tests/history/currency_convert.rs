@@ -0,0 +1,88 @@
+use httpmock::{Method::GET, MockServer};
+use paft::money::{Currency, IsoCurrency};
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{HistoryBuilder, YfError};
+
+// 2024-01-02 and 2024-01-03, 14:30 UTC.
+const TS: [i64; 2] = [1_704_205_800, 1_704_292_200];
+
+fn mock_usd_series(server: &MockServer) -> httpmock::Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/TEST");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(format!(
+                r#"{{"chart":{{"result":[{{
+                  "meta":{{"currency":"USD","symbol":"TEST"}},
+                  "timestamp":{TS:?},
+                  "indicators":{{"quote":[{{
+                    "open":[100.0,110.0],"high":[102.0,112.0],"low":[98.0,108.0],
+                    "close":[101.0,111.0],"volume":[1000,2000]
+                  }}]}}
+                }}],"error":null}}}}"#
+            ));
+    })
+}
+
+#[tokio::test]
+async fn converts_usd_candles_to_eur_at_daily_close() {
+    let server = MockServer::start();
+    let chart = mock_usd_series(&server);
+    // Daily FX closes at midnight UTC of each trading day.
+    let fx = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/USDEUR=X")
+            .query_param("interval", "1d")
+            .query_param_exists("period1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"chart":{"result":[{
+                  "meta":{"currency":"EUR","symbol":"USDEUR=X"},
+                  "timestamp":[1704153600,1704240000],
+                  "indicators":{"quote":[{
+                    "open":[0.9,0.95],"high":[0.9,0.95],"low":[0.9,0.95],
+                    "close":[0.9,0.95],"volume":[0,0]
+                  }]}
+                }],"error":null}}"#,
+            );
+    });
+
+    let resp = HistoryBuilder::new(&crate::common::client_for(&server), "TEST")
+        .convert_to("EUR")
+        .fetch_full()
+        .await
+        .unwrap();
+    chart.assert();
+    fx.assert();
+
+    let eur = Currency::Iso(IsoCurrency::EUR);
+    let c = &resp.candles;
+    assert_eq!(c.len(), 2);
+    assert!(c.iter().all(|c| c.close.currency() == &eur));
+
+    assert!((money_to_f64(&c[0].open) - 90.0).abs() < 1e-9);
+    assert!((money_to_f64(&c[0].high) - 91.8).abs() < 1e-9);
+    assert!((money_to_f64(&c[0].low) - 88.2).abs() < 1e-9);
+    assert!((money_to_f64(&c[0].close) - 90.9).abs() < 1e-9);
+    assert!((money_to_f64(&c[1].close) - 105.45).abs() < 1e-9);
+    assert_eq!(c[1].volume, Some(2000));
+
+    let raw = resp.unadjusted_close.unwrap();
+    assert!((money_to_f64(&raw[1]) - 105.45).abs() < 1e-9);
+    assert_eq!(raw[1].currency(), &eur);
+}
+
+#[tokio::test]
+async fn rejects_blank_target_currency() {
+    let server = MockServer::start();
+    let _chart = mock_usd_series(&server);
+
+    let err = HistoryBuilder::new(&crate::common::client_for(&server), "TEST")
+        .convert_to("  ")
+        .fetch()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, YfError::InvalidParams(_)), "got {err:?}");
+}
@@ -0,0 +1,43 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Ticker, YfClient};
+
+// Each key must appear exactly once, so an extra param cannot shadow the crate's own.
+fn single_valued(req: &httpmock::prelude::HttpMockRequest) -> bool {
+    let params = req.query_params.clone().unwrap_or_default();
+    params
+        .iter()
+        .all(|(k, _)| params.iter().filter(|(other, _)| other == k).count() == 1)
+}
+
+#[tokio::test]
+async fn extra_param_is_appended_to_chart_url() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/AAPL")
+            .query_param("interval", "1d")
+            .query_param("lang", "en-US")
+            .matches(single_valued);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"chart":{"result":[{"meta":{"currency":"USD"},"timestamp":[1704205800],"indicators":{"quote":[{"open":[1.0],"high":[1.0],"low":[1.0],"close":[1.0],"volume":[10]}],"adjclose":[{"adjclose":[1.0]}]}}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let candles = Ticker::new(&client, "AAPL")
+        .history_builder()
+        .extra_param("lang", "en-US")
+        .extra_param("interval", "1wk")
+        .fetch()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(candles.len(), 1);
+}
@@ -0,0 +1,42 @@
+use chrono::NaiveDate;
+use httpmock::{Method::GET, MockServer};
+use url::Url;
+use yfinance_rs::{HistoryBuilder, HistoryResponseExt, YfClient};
+
+#[tokio::test]
+async fn gaps_reports_a_missing_weekday_but_not_the_weekend() {
+    let server = MockServer::start();
+
+    // Mon 8, Tue 9, Thu 11, Fri 12 and Mon 15 Jan 2024 at the NYSE open: Wed 10 is missing.
+    let body = r#"{
+      "chart":{"result":[{"meta":{"currency":"USD","timezone":"America/New_York"},
+        "timestamp":[1704724200,1704810600,1704983400,1705069800,1705329000],
+        "indicators":{"quote":[{
+          "open":[1.0,1.0,1.0,1.0,1.0],
+          "high":[1.0,1.0,1.0,1.0,1.0],
+          "low":[1.0,1.0,1.0,1.0,1.0],
+          "close":[1.0,1.0,1.0,1.0,1.0],
+          "volume":[10,10,10,10,10]
+        }]}}],"error":null}
+    }"#;
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+    let resp = HistoryBuilder::new(&client, "AAPL")
+        .fetch_full()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        resp.gaps(),
+        vec![NaiveDate::from_ymd_opt(2024, 1, 10).unwrap()]
+    );
+}
@@ -0,0 +1,48 @@
+use httpmock::{Method::GET, MockServer};
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{HistoryBuilder, YfClient};
+
+#[tokio::test]
+async fn limit_keeps_only_the_most_recent_candles() {
+    let server = MockServer::start();
+
+    let ts: Vec<i64> = (0..10).map(|i| 1_704_205_800 + i * 86_400).collect();
+    let closes: Vec<f64> = (0..10).map(|i| 100.0 + f64::from(i)).collect();
+    let body = format!(
+        r#"{{"chart":{{"result":[{{
+          "meta":{{"currency":"USD","symbol":"TEN"}},
+          "timestamp":{ts:?},
+          "indicators":{{"quote":[{{"open":{closes:?},"high":{closes:?},"low":{closes:?},"close":{closes:?},"volume":{vol:?}}}]}},
+          "events":{{"dividends":{{"{div_ts}":{{"date":{div_ts},"amount":0.5}}}}}}
+        }}],"error":null}}}}"#,
+        vol = vec![1_000; 10],
+        div_ts = ts[2],
+    );
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/TEN");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let resp = HistoryBuilder::new(&client, "TEN")
+        .auto_adjust(false)
+        .limit(3)
+        .fetch_full()
+        .await
+        .unwrap();
+    mock.assert();
+
+    let kept: Vec<i64> = resp.candles.iter().map(|c| c.ts.timestamp()).collect();
+    assert_eq!(kept, ts[7..]);
+    assert!((money_to_f64(&resp.candles[2].close) - 109.0).abs() < 1e-9);
+    assert_eq!(resp.unadjusted_close.unwrap().len(), 3);
+    // The dividend on the third bar falls before the kept window.
+    assert!(resp.actions.is_empty());
+}
@@ -0,0 +1,33 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Ticker, YfClient};
+
+#[tokio::test]
+async fn chart_pence_are_restated_in_pounds_when_enabled() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/VOD.L");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"chart":{"result":[{"meta":{"currency":"GBp"},"timestamp":[1704205800],"indicators":{"quote":[{"open":[7000.0],"high":[7300.0],"low":[6900.0],"close":[7250.0],"volume":[10]}],"adjclose":[{"adjclose":[7250.0]}]}}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .normalize_minor_units(true)
+        .build()
+        .unwrap();
+
+    let candles = Ticker::new(&client, "VOD.L")
+        .history_builder()
+        .fetch()
+        .await
+        .unwrap();
+
+    let c = &candles[0];
+    assert!((money_to_f64(&c.open) - 70.0).abs() < 1e-9);
+    assert!((money_to_f64(&c.close) - 72.5).abs() < 1e-9);
+    assert_eq!(c.close.currency().to_string(), "GBP");
+}
@@ -50,3 +50,40 @@ async fn history_retries_on_persistent_5xx() {
         other => panic!("Expected a ServerError after all retries failed, got {other:?}"),
     }
 }
+
+#[test]
+fn seeded_jitter_produces_reproducible_delays() {
+    let cfg = RetryConfig {
+        backoff: Backoff::Exponential {
+            base: Duration::from_millis(100),
+            factor: 2.0,
+            max: Duration::from_secs(10),
+            jitter: true,
+        },
+        jitter_seed: Some(42),
+        ..RetryConfig::default()
+    };
+
+    let delays: Vec<Duration> = (0..4).map(|a| cfg.backoff_delay(a)).collect();
+    let again: Vec<Duration> = (0..4).map(|a| cfg.backoff_delay(a)).collect();
+    assert_eq!(delays, again);
+    let micros: Vec<u128> = delays.iter().map(Duration::as_micros).collect();
+    assert_eq!(micros, [124_156, 131_982, 311_440, 675_352]);
+
+    for (attempt, d) in (0u32..).zip(&delays) {
+        let nominal = Duration::from_millis(100 * 2u64.pow(attempt));
+        assert!(
+            *d >= nominal / 2 && *d < nominal * 3 / 2,
+            "{d:?} vs {nominal:?}"
+        );
+    }
+
+    let other = RetryConfig {
+        jitter_seed: Some(7),
+        ..cfg.clone()
+    };
+    assert_ne!(
+        delays,
+        (0..4).map(|a| other.backoff_delay(a)).collect::<Vec<_>>()
+    );
+}
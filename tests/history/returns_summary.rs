@@ -0,0 +1,75 @@
+use httpmock::{Method::GET, MockServer};
+use yfinance_rs::{ReturnsSummary, Ticker, YfError};
+
+fn chart(closes: &[f64]) -> String {
+    let ts: Vec<String> = (0..closes.len())
+        .map(|i| (1_704_067_200 + 86_400 * i).to_string())
+        .collect();
+    let c: Vec<String> = closes.iter().map(f64::to_string).collect();
+    format!(
+        r#"{{"chart":{{"result":[{{"timestamp":[{ts}],
+          "indicators":{{"quote":[{{"open":[{c}],"high":[{c}],"low":[{c}],"close":[{c}],"volume":[]}}]}}}}],"error":null}}}}"#,
+        ts = ts.join(","),
+        c = c.join(","),
+    )
+}
+
+#[tokio::test]
+async fn returns_summary_matches_hand_computed_series() {
+    let server = MockServer::start();
+
+    // Closes 100 → 110 → 99 → 108.9: log returns ln(1.1), ln(0.9), ln(1.1).
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/AAPL")
+            .query_param("range", "1y")
+            .query_param("interval", "1d");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chart(&[100.0, 110.0, 99.0, 108.9]));
+    });
+
+    let s = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .price_history_returns_summary(None, 0.04)
+        .await
+        .unwrap();
+    mock.assert();
+
+    let (up, down) = (1.1_f64.ln(), 0.9_f64.ln());
+    let mean = (2.0 * up + down) / 3.0;
+    let var = (2.0 * (up - mean).powi(2) + (down - mean).powi(2)) / 2.0;
+    let vol = var.sqrt() * 252_f64.sqrt();
+    let ann = (mean * 252.0).exp() - 1.0;
+
+    assert_eq!(s.periods, 3);
+    assert!(
+        (s.volatility - vol).abs() < 1e-9,
+        "volatility {}",
+        s.volatility
+    );
+    assert!((s.volatility - 1.839_18).abs() < 1e-4);
+    assert!((s.annualized_return - ann).abs() < 1e-6 * ann);
+    // Peak 110, trough 99.
+    assert!((s.max_drawdown - 0.1).abs() < 1e-12);
+    let sharpe = s.sharpe.unwrap();
+    assert!((sharpe - (ann - 0.04) / vol).abs() < 1e-6 * sharpe);
+}
+
+#[tokio::test]
+async fn returns_summary_needs_three_closes() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chart(&[100.0, 101.0]));
+    });
+
+    let err = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .price_history_returns_summary(None, 0.0)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, YfError::MissingData(_)), "got {err:?}");
+
+    assert!(ReturnsSummary::from_candles(&[], 0.0).is_err());
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use paft::money::{Currency, IsoCurrency};
+use yfinance_rs::core::conversions::{f64_to_money_with_currency, money_to_f64};
+use yfinance_rs::{Action, Candle, HistoryResponse, HistoryResponseExt};
+
+fn ts(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap()
+}
+
+fn candle(secs: i64, close: f64) -> Candle {
+    let px = f64_to_money_with_currency(close, Currency::Iso(IsoCurrency::USD));
+    Candle {
+        ts: ts(secs),
+        open: px.clone(),
+        high: px.clone(),
+        low: px.clone(),
+        close: px,
+        volume: Some(100),
+    }
+}
+
+fn series(points: &[(i64, f64)]) -> HistoryResponse {
+    HistoryResponse {
+        candles: points.iter().map(|&(t, c)| candle(t, c)).collect(),
+        actions: Vec::new(),
+        adjusted: true,
+        meta: None,
+        unadjusted_close: None,
+    }
+}
+
+#[test]
+fn stitch_overlapping_series_keeps_latest_values_sorted() {
+    let mut older = series(&[(100, 1.0), (200, 2.0), (300, 3.0)]);
+    // Overlaps at 200 and 300 with revised values, and arrives partly out of order.
+    let newer = series(&[(400, 4.0), (200, 2.5), (300, 3.5)]);
+
+    older.stitch(newer);
+
+    let got: Vec<(i64, f64)> = older
+        .candles
+        .iter()
+        .map(|c| (c.ts.timestamp(), money_to_f64(&c.close)))
+        .collect();
+    assert_eq!(got, vec![(100, 1.0), (200, 2.5), (300, 3.5), (400, 4.0)]);
+}
+
+#[test]
+fn dedup_keeps_unadjusted_close_and_actions_aligned() {
+    let mut resp = series(&[(100, 1.0), (200, 2.0), (100, 1.5)]);
+    let usd = |v| f64_to_money_with_currency(v, Currency::Iso(IsoCurrency::USD));
+    resp.unadjusted_close = Some(vec![usd(10.0), usd(20.0), usd(15.0)]);
+    let div = |amount| Action::Dividend {
+        ts: ts(200),
+        amount: usd(amount),
+    };
+    resp.actions = vec![div(0.5), div(0.6)];
+
+    resp.dedup_timestamps();
+
+    let raw: Vec<f64> = resp
+        .unadjusted_close
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(money_to_f64)
+        .collect();
+    assert_eq!(raw, vec![15.0, 20.0]);
+    assert_eq!(money_to_f64(&resp.candles[0].close), 1.5);
+    assert_eq!(resp.actions, vec![div(0.6)]);
+}
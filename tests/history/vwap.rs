@@ -0,0 +1,67 @@
+use httpmock::{Method::GET, MockServer};
+use yfinance_rs::{HistoryBuilder, HistoryResponseExt};
+
+#[tokio::test]
+async fn vwap_matches_hand_computed_value() {
+    let server = MockServer::start();
+
+    // Typical prices: (11+9+10)/3 = 10, (22+18+20)/3 = 20, (33+27+30)/3 = 30
+    // VWAP = (10*100 + 20*200 + 30*700) / 1000 = 26.0
+    let body = r#"{
+      "chart":{"result":[{"timestamp":[1704067200,1704067260,1704067320],
+        "indicators":{"quote":[{
+          "open":[10.0,20.0,30.0],
+          "high":[11.0,22.0,33.0],
+          "low":[9.0,18.0,27.0],
+          "close":[10.0,20.0,30.0],
+          "volume":[100,200,700]
+        }]}}],"error":null}
+    }"#;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let resp = HistoryBuilder::new(&crate::common::client_for(&server), "AAPL")
+        .fetch_full()
+        .await
+        .unwrap();
+    mock.assert();
+
+    let vwap = resp.vwap().expect("vwap with non-zero volume");
+    assert!((vwap - 26.0).abs() < 1e-9, "got {vwap}");
+}
+
+#[tokio::test]
+async fn vwap_is_none_without_volume() {
+    let server = MockServer::start();
+
+    let body = r#"{
+      "chart":{"result":[{"timestamp":[1704067200,1704067260],
+        "indicators":{"quote":[{
+          "open":[10.0,20.0],
+          "high":[11.0,22.0],
+          "low":[9.0,18.0],
+          "close":[10.0,20.0],
+          "volume":[0,null]
+        }]}}],"error":null}
+    }"#;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let resp = HistoryBuilder::new(&crate::common::client_for(&server), "AAPL")
+        .fetch_full()
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(resp.vwap(), None);
+}
@@ -1,5 +1,7 @@
 mod common;
 
+#[path = "holders/combined.rs"]
+mod holders_combined;
 #[path = "holders/live.rs"]
 mod holders_live;
 #[path = "holders/offline.rs"]
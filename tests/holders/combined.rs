@@ -0,0 +1,68 @@
+use httpmock::{Method::GET, MockServer};
+use yfinance_rs::Ticker;
+
+const MODULES: &str = "institutionOwnership,fundOwnership,majorHoldersBreakdown,insiderTransactions,insiderHolders,netSharePurchaseActivity";
+
+#[tokio::test]
+async fn holders_populates_all_sections_from_one_request() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", MODULES);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(crate::common::fixture(
+                "holders_api_institutionOwnership-fundOwnership-majorHoldersBreakdown-insiderTransactions-insiderHolders-netSharePurchaseActivity",
+                "AAPL",
+                "json",
+            ));
+    });
+
+    let holders = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .holders()
+        .await
+        .unwrap();
+
+    mock.assert_hits(1);
+    assert!(holders.errors.is_empty(), "errors: {:?}", holders.errors);
+    assert!(!holders.major.is_empty(), "major holders missing");
+    assert!(
+        !holders.institutional.is_empty(),
+        "institutional holders missing"
+    );
+    assert!(
+        holders
+            .insider_roster
+            .iter()
+            .any(|h| h.name.to_lowercase().contains("cook"))
+    );
+}
+
+#[tokio::test]
+async fn holders_isolates_a_malformed_section() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", MODULES);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteSummary":{"result":[{
+                "majorHoldersBreakdown":{"insidersPercentHeld":{"raw":0.02},"institutionsPercentHeld":{"raw":0.61}},
+                "institutionOwnership":{"ownershipList":"not a list"},
+                "insiderHolders":{"holders":[{"name":"COOK TIMOTHY D","relation":"Chief Executive Officer"}]}
+            }],"error":null}}"#);
+    });
+
+    let holders = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .holders()
+        .await
+        .unwrap();
+
+    assert_eq!(holders.major.len(), 2);
+    assert!(holders.institutional.is_empty());
+    assert_eq!(holders.insider_roster.len(), 1);
+    assert_eq!(holders.errors.len(), 1);
+    assert_eq!(holders.errors[0].0, "institutionOwnership");
+}
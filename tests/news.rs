@@ -4,3 +4,5 @@ mod common;
 mod live;
 #[path = "news/offline.rs"]
 mod offline;
+#[path = "news/transcripts.rs"]
+mod transcripts;
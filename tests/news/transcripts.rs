@@ -0,0 +1,73 @@
+use httpmock::{Method::POST, MockServer};
+use serde_json::json;
+use yfinance_rs::Ticker;
+
+fn stream_item(id: &str, title: &str, url: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "content": {
+            "title": title,
+            "pubDate": "2024-08-02T12:00:00Z",
+            "provider": { "displayName": "Example Wire" },
+            "canonicalUrl": { "url": url }
+        }
+    })
+}
+
+#[tokio::test]
+async fn transcripts_are_filtered_from_all_news() {
+    let server = MockServer::start();
+    let body = json!({
+        "data": { "tickerStream": { "stream": [
+            stream_item("1", "Apple (AAPL) Q3 2024 Earnings Call Transcript", "https://example.com/news/apple-q3"),
+            stream_item("2", "Apple beats estimates on services growth", "https://example.com/news/apple-beats"),
+            stream_item("3", "Apple Q3 call: what management said", "https://example.com/earnings-call-transcripts/aapl-q3-2024"),
+        ]}}
+    });
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/xhr/ncp")
+            .query_param("queryRef", "newsAll")
+            .json_body(json!({ "serviceConfig": { "snippetCount": 50, "s": ["AAPL"] } }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body.to_string());
+    });
+
+    let links = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .earnings_call_transcripts()
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(
+        links,
+        [
+            "https://example.com/news/apple-q3",
+            "https://example.com/earnings-call-transcripts/aapl-q3-2024",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn transcripts_empty_when_none_published() {
+    let server = MockServer::start();
+    let body = json!({
+        "data": { "tickerStream": { "stream": [
+            stream_item("1", "Apple beats estimates", "https://example.com/news/apple-beats"),
+        ]}}
+    });
+    server.mock(|when, then| {
+        when.method(POST).path("/xhr/ncp");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body.to_string());
+    });
+
+    let links = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .earnings_call_transcripts()
+        .await
+        .unwrap();
+    assert!(links.is_empty());
+}
@@ -1,9 +1,48 @@
 mod common;
 
+#[path = "quotes/cache_capacity.rs"]
+mod quotes_cache_capacity;
+#[path = "quotes/diff.rs"]
+mod quotes_diff;
+#[path = "quotes/display_name.rs"]
+mod quotes_display_name;
+#[path = "quotes/extra_param.rs"]
+mod quotes_extra_param;
+#[path = "quotes/extras.rs"]
+mod quotes_extras;
+#[path = "quotes/fast_info.rs"]
+mod quotes_fast_info;
+#[cfg(feature = "fault-injection")]
+#[path = "quotes/fault_injection.rs"]
+mod quotes_fault_injection;
+#[path = "quotes/futures.rs"]
+mod quotes_futures;
+#[path = "quotes/group_by_exchange.rs"]
+mod quotes_group_by_exchange;
+#[path = "quotes/metrics.rs"]
+mod quotes_metrics;
+#[path = "quotes/minor_units.rs"]
+mod quotes_minor_units;
 #[path = "quotes/offline.rs"]
 mod quotes_offline;
+#[path = "quotes/parse_error.rs"]
+mod quotes_parse_error;
+#[path = "quotes/partial.rs"]
+mod quotes_partial;
+#[path = "quotes/post.rs"]
+mod quotes_post;
+#[path = "quotes/preset.rs"]
+mod quotes_preset;
+#[path = "quotes/ranking.rs"]
+mod quotes_ranking;
+#[path = "quotes/region.rs"]
+mod quotes_region;
 #[path = "quotes/retry_synthetic.rs"]
 mod quotes_retry_synth;
+#[path = "quotes/spark.rs"]
+mod quotes_spark;
+#[path = "quotes/validation.rs"]
+mod quotes_validation;
 
 #[path = "quotes/live.rs"]
 mod live;
@@ -0,0 +1,118 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use std::time::Duration;
+use url::Url;
+use yfinance_rs::{Ticker, YfClient};
+
+#[tokio::test]
+async fn cache_capacity_evicts_least_recently_used_entry() {
+    let server = MockServer::start();
+    let mocks: Vec<_> = ["AAA", "BBB", "CCC"]
+        .into_iter()
+        .map(|sym| {
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/v7/finance/quote")
+                    .query_param("symbols", sym);
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(format!(
+                        r#"{{"quoteResponse":{{"result":[{{"symbol":"{sym}","regularMarketPrice":1.0,"currency":"USD"}}],"error":null}}}}"#
+                    ));
+            })
+        })
+        .collect();
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .cache_ttl(Duration::from_secs(60))
+        .cache_capacity(2)
+        .build()
+        .unwrap();
+    let quote = |sym: &'static str| {
+        let client = client.clone();
+        async move { Ticker::new(&client, sym).quote().await.unwrap() }
+    };
+
+    quote("AAA").await;
+    quote("BBB").await;
+    // Inserting a third entry evicts the oldest one.
+    quote("CCC").await;
+    quote("AAA").await;
+    assert_eq!(mocks[0].hits(), 2, "AAA should have been evicted");
+
+    // AAA and CCC are cached now; reading CCC makes AAA the least recently used.
+    quote("CCC").await;
+    quote("BBB").await;
+    quote("CCC").await;
+    assert_eq!(mocks[2].hits(), 1, "CCC should still be cached");
+    quote("AAA").await;
+    assert_eq!(mocks[0].hits(), 3, "AAA should have been evicted by BBB");
+    assert_eq!(mocks[1].hits(), 2);
+}
+
+#[tokio::test]
+async fn cache_capacity_zero_disables_the_cache() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAA","regularMarketPrice":1.0,"currency":"USD"}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .cache_ttl(Duration::from_secs(60))
+        .cache_capacity(0)
+        .build()
+        .unwrap();
+    assert!(!client.cache_enabled());
+
+    Ticker::new(&client, "AAA").quote().await.unwrap();
+    Ticker::new(&client, "AAA").quote().await.unwrap();
+    mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn cache_capacity_counts_only_live_entries_after_invalidation() {
+    let server = MockServer::start();
+    let mocks: Vec<_> = ["AAA", "BBB", "CCC"]
+        .into_iter()
+        .map(|sym| {
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/v7/finance/quote")
+                    .query_param("symbols", sym);
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(format!(
+                        r#"{{"quoteResponse":{{"result":[{{"symbol":"{sym}","regularMarketPrice":1.0,"currency":"USD"}}],"error":null}}}}"#
+                    ));
+            })
+        })
+        .collect();
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .cache_ttl(Duration::from_secs(60))
+        .cache_capacity(2)
+        .build()
+        .unwrap();
+    let quote = |sym: &'static str| {
+        let client = client.clone();
+        async move { Ticker::new(&client, sym).quote().await.unwrap() }
+    };
+
+    quote("AAA").await;
+    quote("BBB").await;
+    client.clear_cache().await;
+    quote("AAA").await;
+    quote("CCC").await;
+    // Both fit: the cleared entries no longer take up room.
+    quote("AAA").await;
+    quote("CCC").await;
+    assert_eq!(mocks[0].hits(), 2);
+    assert_eq!(mocks[1].hits(), 1);
+    assert_eq!(mocks[2].hits(), 1);
+}
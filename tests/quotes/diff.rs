@@ -0,0 +1,49 @@
+use paft::domain::MarketState;
+use paft::money::{Currency, IsoCurrency};
+use yfinance_rs::core::conversions::f64_to_money_with_currency;
+use yfinance_rs::{Quote, QuoteExt};
+
+fn quote(price: Option<f64>, currency: IsoCurrency, state: MarketState) -> Quote {
+    Quote {
+        symbol: "AAPL".into(),
+        shortname: Some("Apple Inc.".into()),
+        price: price.map(|p| f64_to_money_with_currency(p, Currency::Iso(currency))),
+        previous_close: Some(f64_to_money_with_currency(
+            190.0,
+            Currency::Iso(IsoCurrency::USD),
+        )),
+        exchange: None,
+        market_state: Some(state),
+    }
+}
+
+#[test]
+fn diff_reports_only_market_state_change() {
+    let before = quote(Some(195.5), IsoCurrency::USD, MarketState::Pre);
+    let after = quote(Some(195.5), IsoCurrency::USD, MarketState::Regular);
+
+    assert!(before.same_price_as(&after));
+
+    let diff = before.diff(&after);
+    assert!(!diff.is_empty());
+    assert_eq!(diff.price, None);
+    assert_eq!(diff.previous_close, None);
+    assert_eq!(
+        diff.market_state,
+        Some((Some(MarketState::Pre), Some(MarketState::Regular)))
+    );
+
+    assert!(after.diff(&after.clone()).is_empty());
+}
+
+#[test]
+fn same_price_is_currency_aware_and_handles_missing() {
+    let usd = quote(Some(100.0), IsoCurrency::USD, MarketState::Regular);
+    let eur = quote(Some(100.0), IsoCurrency::EUR, MarketState::Regular);
+    let none = quote(None, IsoCurrency::USD, MarketState::Regular);
+
+    assert!(!usd.same_price_as(&eur));
+    assert!(!usd.same_price_as(&none));
+    assert!(none.same_price_as(&none.clone()));
+    assert!(usd.diff(&none).price.is_some());
+}
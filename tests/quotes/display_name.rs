@@ -0,0 +1,96 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{QuotesBuilder, YfClient};
+
+#[tokio::test]
+async fn display_name_prefers_long_name_over_short_name() {
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL,XYZ");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+              "quoteResponse": {
+                "result": [
+                  { "symbol":"AAPL", "shortName":"Apple Inc.", "longName":"Apple Inc. Common Stock", "regularMarketPrice": 190.5, "currency":"USD" },
+                  { "symbol":"XYZ", "shortName":"XYZ Corp", "regularMarketPrice": 10.0, "currency":"USD" }
+                ],
+                "error": null
+              }
+            }"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let details = QuotesBuilder::new(client)
+        .symbols(["AAPL", "XYZ"])
+        .fetch_details()
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(
+        details[0].long_name.as_deref(),
+        Some("Apple Inc. Common Stock")
+    );
+    assert_eq!(details[0].quote.shortname.as_deref(), Some("Apple Inc."));
+    assert_eq!(details[0].display_name(), Some("Apple Inc. Common Stock"));
+
+    assert_eq!(details[1].long_name, None);
+    assert_eq!(details[1].display_name(), Some("XYZ Corp"));
+}
+
+#[tokio::test]
+async fn display_name_prefers_display_name_then_long_then_short() {
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL,MSFT,XYZ");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+              "quoteResponse": {
+                "result": [
+                  { "symbol":"AAPL", "displayName":"Apple", "shortName":"Apple Inc.", "longName":"Apple Inc. Common Stock", "typeDisp":"Equity", "regularMarketPrice": 190.5, "currency":"USD" },
+                  { "symbol":"MSFT", "displayName":" ", "shortName":"Microsoft", "longName":"Microsoft Corporation", "regularMarketPrice": 410.0, "currency":"USD" },
+                  { "symbol":"XYZ", "shortName":"XYZ Corp", "regularMarketPrice": 10.0, "currency":"USD" }
+                ],
+                "error": null
+              }
+            }"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let details = QuotesBuilder::new(client)
+        .symbols(["AAPL", "MSFT", "XYZ"])
+        .fetch_details()
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(details[0].display_name.as_deref(), Some("Apple"));
+    assert_eq!(details[0].type_disp.as_deref(), Some("Equity"));
+    assert_eq!(details[0].display_name(), Some("Apple"));
+
+    assert_eq!(details[1].display_name(), Some("Microsoft Corporation"));
+    assert_eq!(details[1].type_disp, None);
+
+    assert_eq!(details[2].display_name(), Some("XYZ Corp"));
+}
@@ -0,0 +1,44 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{QuotesBuilder, YfClient};
+
+// Each key must appear exactly once, so an extra param cannot shadow the crate's own.
+fn single_valued(req: &httpmock::prelude::HttpMockRequest) -> bool {
+    let params = req.query_params.clone().unwrap_or_default();
+    params
+        .iter()
+        .all(|(k, _)| params.iter().filter(|(other, _)| other == k).count() == 1)
+}
+
+#[tokio::test]
+async fn extra_param_is_appended_to_quote_url() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL")
+            .query_param("formatted", "false")
+            .matches(single_valued);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":200.0,"currency":"USD"}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let quotes = QuotesBuilder::new(client)
+        .symbols(["AAPL"])
+        .extra_param("formatted", "false")
+        .extra_param("symbols", "MSFT")
+        .fetch()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(quotes[0].symbol, "AAPL");
+}
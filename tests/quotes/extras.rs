@@ -0,0 +1,81 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::{QuotesBuilder, YfClient, YfError};
+
+const BODY: &str = r#"{"quoteResponse":{"result":[{
+  "symbol":"AAPL","shortName":"Apple Inc.","regularMarketPrice":200.0,"currency":"USD",
+  "quoteType":"EQUITY","epsTrailingTwelveMonths":6.42,"averageAnalystRating":"2.0 - Buy"
+}],"error":null}}"#;
+
+fn client(server: &MockServer) -> YfClient {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(BODY);
+    });
+    crate::common::client_for(server)
+}
+
+#[tokio::test]
+async fn extras_keep_fields_not_modeled() {
+    let server = MockServer::start();
+    let details = QuotesBuilder::new(client(&server))
+        .symbols(["AAPL"])
+        .extras(true)
+        .fetch_details()
+        .await
+        .unwrap();
+
+    let d = &details[0];
+    assert_eq!(d.quote.symbol, "AAPL");
+    assert_eq!(d.quote_type.as_deref(), Some("EQUITY"));
+
+    let extras = d.extras.as_ref().expect("extras requested");
+    assert_eq!(extras["epsTrailingTwelveMonths"], serde_json::json!(6.42));
+    assert_eq!(
+        extras["averageAnalystRating"],
+        serde_json::json!("2.0 - Buy")
+    );
+    assert!(!extras.contains_key("regularMarketPrice"));
+    assert!(!extras.contains_key("symbol"));
+}
+
+#[tokio::test]
+async fn extras_are_off_by_default() {
+    let server = MockServer::start();
+    let details = QuotesBuilder::new(client(&server))
+        .symbols(["AAPL"])
+        .fetch_details()
+        .await
+        .unwrap();
+
+    assert!(details[0].extras.is_none());
+}
+
+#[tokio::test]
+async fn extras_parse_errors_name_the_symbol() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"MSFT","regularMarketPrice":"not-a-number","currency":"USD"}],"error":null}}"#);
+    });
+    let client = crate::common::client_for(&server);
+
+    let err = QuotesBuilder::new(client)
+        .symbols(["MSFT"])
+        .extras(true)
+        .fetch_details()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(&err, YfError::Parse { endpoint, symbol, .. }
+            if endpoint == "quote_v7" && symbol == "MSFT"),
+        "{err:?}"
+    );
+}
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::YfClient;
+use yfinance_rs::quote::QuotesBuilder;
+
+#[tokio::test]
+async fn fetch_fast_info_drops_stale_symbols() {
+    let server = MockServer::start();
+    let now = chrono::Utc::now().timestamp();
+    let body = format!(
+        r#"{{"quoteResponse":{{"result":[
+          {{"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD","marketState":"REGULAR","regularMarketTime":{live}}},
+          {{"symbol":"HALT","regularMarketPrice":12.0,"currency":"USD","marketState":"REGULAR","regularMarketTime":{stale}}}
+        ],"error":null}}}}"#,
+        live = now - 30,
+        stale = now - 2 * 60 * 60,
+    );
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL,HALT")
+            .query_param_exists("fields");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let (kept, dropped) = QuotesBuilder::new(client)
+        .symbols(["AAPL", "HALT"])
+        .max_age(Duration::from_secs(10 * 60))
+        .fetch_fast_info()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].symbol, "AAPL");
+    assert_eq!(kept[0].last_price, 190.0);
+    assert_eq!(dropped, vec!["HALT".to_string()]);
+}
+
+#[derive(Debug)]
+struct FixedClock(chrono::DateTime<chrono::Utc>);
+
+impl yfinance_rs::Clock for FixedClock {
+    fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        self.0
+    }
+}
+
+#[tokio::test]
+async fn fetch_fast_info_reports_missing_symbols_and_uses_client_clock() {
+    let server = MockServer::start();
+    // Timestamps far in the past: only fresh relative to the injected clock.
+    let now = 1_704_205_800;
+    let body = format!(
+        r#"{{"quoteResponse":{{"result":[
+          {{"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD","regularMarketTime":{live}}},
+          {{"regularMarketPrice":1.0,"currency":"USD","regularMarketTime":{live}}}
+        ],"error":null}}}}"#,
+        live = now - 30,
+    );
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .clock(FixedClock(
+            chrono::DateTime::from_timestamp(now, 0).unwrap(),
+        ))
+        .build()
+        .unwrap();
+
+    let (kept, dropped) = QuotesBuilder::new(client)
+        .symbols(["AAPL", "GONE"])
+        .max_age(Duration::from_secs(10 * 60))
+        .fetch_fast_info()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].symbol, "AAPL");
+    assert_eq!(dropped, vec!["GONE".to_string()]);
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+use url::Url;
+use yfinance_rs::{FaultConfig, QuotesBuilder, YfClient, YfError};
+
+#[tokio::test]
+async fn injected_429_surfaces_as_rate_limited() {
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse("http://127.0.0.1:9/v7/finance/quote").unwrap())
+        .retry_enabled(false)
+        .fault_injection(FaultConfig::new().status("/v7/finance/quote", 429))
+        .build()
+        .unwrap();
+
+    let err = QuotesBuilder::new(client)
+        .symbols(["AAPL"])
+        .fetch()
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, YfError::RateLimited { .. }),
+        "expected RateLimited, got {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn injected_fault_is_limited_by_times() {
+    let server = httpmock::MockServer::start();
+    let ok = server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path("/v7/finance/quote");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL"}],"error":null}}"#);
+    });
+
+    let retry = yfinance_rs::RetryConfig {
+        backoff: yfinance_rs::core::client::Backoff::Fixed(Duration::from_millis(1)),
+        ..Default::default()
+    };
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .retry_config(retry)
+        .fault_injection(FaultConfig::new().status("/v7/finance/quote", 503).times(2))
+        .build()
+        .unwrap();
+
+    let quotes = QuotesBuilder::new(client)
+        .symbols(["AAPL"])
+        .fetch()
+        .await
+        .unwrap();
+
+    ok.assert_hits(1);
+    assert_eq!(quotes[0].symbol, "AAPL");
+}
@@ -0,0 +1,64 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{QuotesBuilder, YfClient};
+
+#[tokio::test]
+async fn futures_fields_are_parsed_for_future_and_absent_for_equity() {
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "ES=F,AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+              "quoteResponse": {
+                "result": [
+                  {
+                    "symbol":"ES=F", "quoteType":"FUTURE", "currency":"USD",
+                    "regularMarketPrice": 5850.25,
+                    "contractSymbol": false,
+                    "underlyingSymbol":"ESZ24.CME",
+                    "expireDate": 1734652800,
+                    "openInterest": 2091234
+                  },
+                  { "symbol":"AAPL", "quoteType":"EQUITY", "currency":"USD", "regularMarketPrice": 190.5 }
+                ],
+                "error": null
+              }
+            }"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let details = QuotesBuilder::new(client)
+        .symbols(["ES=F", "AAPL"])
+        .fetch_details()
+        .await
+        .unwrap();
+    mock.assert();
+
+    let es = &details[0];
+    assert_eq!(es.quote.symbol, "ES=F");
+    assert_eq!(es.quote_type.as_deref(), Some("FUTURE"));
+    let fut = es.futures.as_ref().expect("futures details for ES=F");
+    assert_eq!(fut.expire_date.unwrap().timestamp(), 1_734_652_800);
+    assert_eq!(fut.open_interest, Some(2_091_234));
+    assert_eq!(fut.underlying_symbol.as_deref(), Some("ESZ24.CME"));
+    assert_eq!(
+        fut.contract_symbol, None,
+        "boolean contractSymbol is ignored"
+    );
+
+    let aapl = &details[1];
+    assert_eq!(aapl.quote.symbol, "AAPL");
+    assert!(aapl.futures.is_none());
+}
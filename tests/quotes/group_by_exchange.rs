@@ -0,0 +1,80 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{QuotesBuilder, YfClient};
+
+#[tokio::test]
+async fn grouped_quotes_issue_one_request_per_exchange() {
+    let server = MockServer::start();
+
+    let us = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL,MSFT");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteResponse":{"result":[
+                  {"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD"},
+                  {"symbol":"MSFT","regularMarketPrice":410.0,"currency":"USD"}
+                ],"error":null}}"#,
+            );
+    });
+    let london = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "VOD.L");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"VOD.L","regularMarketPrice":72.5,"currency":"GBp"}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let quotes = QuotesBuilder::new(client)
+        .symbols(["AAPL", "VOD.L", "MSFT"])
+        .group_by_exchange(true)
+        .fetch()
+        .await
+        .unwrap();
+
+    us.assert_hits(1);
+    london.assert_hits(1);
+
+    let symbols: Vec<&str> = quotes.iter().map(|q| q.symbol.as_str()).collect();
+    assert_eq!(symbols, ["AAPL", "MSFT", "VOD.L"]);
+}
+
+#[tokio::test]
+async fn ungrouped_quotes_use_a_single_request() {
+    let server = MockServer::start();
+    let mixed = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL,VOD.L");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteResponse":{"result":[
+                  {"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD"},
+                  {"symbol":"VOD.L","regularMarketPrice":72.5,"currency":"GBp"}
+                ],"error":null}}"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let quotes = QuotesBuilder::new(client)
+        .symbols(["AAPL", "VOD.L"])
+        .fetch()
+        .await
+        .unwrap();
+    mixed.assert_hits(1);
+    assert_eq!(quotes.len(), 2);
+}
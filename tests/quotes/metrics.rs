@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{ClientMetrics, QuotesBuilder, YfClient};
+
+#[tokio::test]
+async fn metrics_count_requests_cache_and_rate_limits() {
+    let server = MockServer::start();
+    let ok = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD"}],"error":null}}"#);
+    });
+    let limited = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "MSFT");
+        then.status(429);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .cache_ttl(Duration::from_secs(60))
+        .retry_enabled(false)
+        .build()
+        .unwrap();
+    assert_eq!(client.metrics(), ClientMetrics::default());
+
+    QuotesBuilder::new(client.clone())
+        .symbols(["AAPL"])
+        .fetch()
+        .await
+        .unwrap();
+    let m = client.metrics();
+    assert_eq!(m.requests, 1);
+    assert_eq!(m.cache_misses, 1);
+    assert_eq!(m.cache_hits, 0);
+
+    // A clone shares the counters; the second call is served from the cache.
+    let clone = client.clone();
+    QuotesBuilder::new(clone.clone())
+        .symbols(["AAPL"])
+        .fetch()
+        .await
+        .unwrap();
+    ok.assert_hits(1);
+    assert_eq!(client.metrics().requests, 1);
+    assert_eq!(client.metrics().cache_hits, 1);
+
+    let err = QuotesBuilder::new(client.clone())
+        .symbols(["MSFT"])
+        .fetch()
+        .await;
+    assert!(err.is_err());
+    limited.assert();
+    let m = clone.metrics();
+    assert_eq!(m.requests, 2);
+    assert_eq!(m.rate_limited, 1);
+
+    client.metrics_reset();
+    assert_eq!(clone.metrics(), ClientMetrics::default());
+}
@@ -0,0 +1,53 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{QuotesBuilder, YfClient};
+
+const BODY: &str = r#"{"quoteResponse":{"result":[{
+  "symbol":"VOD.L","regularMarketPrice":7250.0,"regularMarketPreviousClose":7100.0,
+  "currency":"GBp","fullExchangeName":"LSE"
+}],"error":null}}"#;
+
+fn client(server: &MockServer, normalize: bool) -> YfClient {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "VOD.L");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(BODY);
+    });
+    crate::common::builder_for(server)
+        .normalize_minor_units(normalize)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn pence_are_restated_in_pounds_when_enabled() {
+    let server = MockServer::start();
+    let quotes = QuotesBuilder::new(client(&server, true))
+        .symbols(["VOD.L"])
+        .fetch()
+        .await
+        .unwrap();
+
+    let price = quotes[0].price.as_ref().unwrap();
+    assert!((money_to_f64(price) - 72.5).abs() < 1e-9);
+    assert_eq!(price.currency().to_string(), "GBP");
+    let prev = quotes[0].previous_close.as_ref().unwrap();
+    assert!((money_to_f64(prev) - 71.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn pence_are_kept_by_default() {
+    let server = MockServer::start();
+    let quotes = QuotesBuilder::new(client(&server, false))
+        .symbols(["VOD.L"])
+        .fetch()
+        .await
+        .unwrap();
+
+    let price = quotes[0].price.as_ref().unwrap();
+    assert!((money_to_f64(price) - 7250.0).abs() < 1e-9);
+}
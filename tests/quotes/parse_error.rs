@@ -0,0 +1,35 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use std::error::Error as _;
+use url::Url;
+use yfinance_rs::{Ticker, YfClient, YfError};
+
+#[tokio::test]
+async fn malformed_quote_body_reports_endpoint_and_symbol() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":"n/a"}]}}"#);
+    });
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let err = Ticker::new(&client, "AAPL").quote().await.unwrap_err();
+
+    assert!(err.source().is_some(), "serde error should be preserved");
+    match err {
+        YfError::Parse {
+            endpoint, symbol, ..
+        } => {
+            assert_eq!(endpoint, "quote_v7");
+            assert_eq!(symbol, "AAPL");
+        }
+        other => panic!("expected Parse, got {other:?}"),
+    }
+}
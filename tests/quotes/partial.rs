@@ -0,0 +1,56 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{QuotesBuilder, YfClient, YfError};
+
+#[tokio::test]
+async fn fetch_partial_reports_bad_nodes_and_keeps_the_rest() {
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL,MSFT,GOOG,NVDA");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+              "quoteResponse": {
+                "result": [
+                  { "symbol":"AAPL", "regularMarketPrice": 190.5, "currency":"USD" },
+                  { "symbol":"MSFT", "regularMarketPrice": "not-a-number", "currency":"USD" },
+                  { "symbol":"NVDA", "regularMarketPrice": 120.0, "currency":"USD" }
+                ],
+                "error": null
+              }
+            }"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let (quotes, failures) = QuotesBuilder::new(client)
+        .symbols(["AAPL", "MSFT", "GOOG", "NVDA"])
+        .fetch_partial()
+        .await
+        .unwrap();
+    mock.assert();
+
+    let syms: Vec<&str> = quotes.iter().map(|q| q.symbol.as_str()).collect();
+    assert_eq!(syms, ["AAPL", "NVDA"]);
+
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].0, "MSFT");
+    assert!(
+        matches!(&failures[0].1, YfError::Parse { endpoint, symbol, .. }
+            if endpoint == "quote_v7" && symbol == "MSFT"),
+        "{:?}",
+        failures[0].1
+    );
+    assert_eq!(failures[1].0, "GOOG");
+    assert!(matches!(failures[1].1, YfError::MissingData(_)));
+}
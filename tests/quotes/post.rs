@@ -0,0 +1,65 @@
+use httpmock::Method::{GET, POST};
+use httpmock::MockServer;
+use yfinance_rs::quote::QuotesBuilder;
+
+const BODY: &str = r#"{"quoteResponse":{"result":[
+  {"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD"},
+  {"symbol":"MSFT","regularMarketPrice":410.0,"currency":"USD"}
+],"error":null}}"#;
+
+#[tokio::test]
+async fn use_post_sends_symbols_in_the_form_body() {
+    let server = MockServer::start();
+    let post = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v7/finance/quote")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .x_www_form_urlencoded_tuple("symbols", "AAPL,MSFT")
+            .matches(|req| {
+                !req.query_params
+                    .as_ref()
+                    .is_some_and(|q| q.iter().any(|(k, _)| k == "symbols"))
+            });
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(BODY);
+    });
+    let get = server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(200).body(BODY);
+    });
+
+    let quotes = QuotesBuilder::new(crate::common::client_for(&server))
+        .symbols(["AAPL", "MSFT"])
+        .use_post(true)
+        .fetch()
+        .await
+        .unwrap();
+
+    post.assert();
+    get.assert_hits(0);
+    assert_eq!(quotes.len(), 2);
+    assert_eq!(quotes[1].symbol, "MSFT");
+}
+
+#[tokio::test]
+async fn quotes_use_get_by_default() {
+    let server = MockServer::start();
+    let get = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL,MSFT");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(BODY);
+    });
+
+    let quotes = QuotesBuilder::new(crate::common::client_for(&server))
+        .symbols(["AAPL", "MSFT"])
+        .fetch()
+        .await
+        .unwrap();
+
+    get.assert();
+    assert_eq!(quotes.len(), 2);
+}
@@ -0,0 +1,59 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{QuoteFieldPreset, QuotesBuilder, YfClient};
+
+#[test]
+fn trading_preset_expands_to_price_volume_and_book_fields() {
+    assert_eq!(
+        QuoteFieldPreset::Trading.fields(),
+        [
+            "regularMarketPrice",
+            "regularMarketPreviousClose",
+            "currency",
+            "marketState",
+            "regularMarketTime",
+            "regularMarketOpen",
+            "regularMarketDayHigh",
+            "regularMarketDayLow",
+            "regularMarketVolume",
+            "bid",
+            "bidSize",
+            "ask",
+            "askSize",
+            "preMarketPrice",
+            "postMarketPrice",
+        ]
+    );
+    assert!(QuoteFieldPreset::Full.fields().is_empty());
+}
+
+#[tokio::test]
+async fn preset_is_sent_as_fields_param() {
+    let server = MockServer::start();
+    let fields = QuoteFieldPreset::Trading.fields().join(",");
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL")
+            .query_param("fields", &fields);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":190.0,"bid":189.9,"ask":190.1,"currency":"USD"}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let raw = QuotesBuilder::new(client)
+        .symbols(["AAPL"])
+        .preset(QuoteFieldPreset::Trading)
+        .fetch_raw()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(raw[0]["bid"], 189.9);
+}
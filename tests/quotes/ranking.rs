@@ -0,0 +1,50 @@
+use paft::money::{Currency, IsoCurrency};
+use yfinance_rs::core::conversions::f64_to_money_with_currency;
+use yfinance_rs::{Quote, QuoteExt, bottom_n, rank_by_change_percent, top_n};
+
+fn quote(symbol: &str, price: f64, previous_close: f64, currency: IsoCurrency) -> Quote {
+    Quote {
+        symbol: symbol.into(),
+        shortname: None,
+        price: Some(f64_to_money_with_currency(price, Currency::Iso(currency))),
+        previous_close: Some(f64_to_money_with_currency(
+            previous_close,
+            Currency::Iso(IsoCurrency::USD),
+        )),
+        exchange: None,
+        market_state: None,
+    }
+}
+
+fn symbols(quotes: &[&Quote]) -> Vec<String> {
+    quotes.iter().map(|q| q.symbol.to_string()).collect()
+}
+
+#[test]
+fn ranks_three_quotes_by_change_percent() {
+    let quotes = vec![
+        quote("FLAT", 100.0, 100.0, IsoCurrency::USD),
+        quote("DOWN", 95.0, 100.0, IsoCurrency::USD),
+        quote("UP", 110.0, 100.0, IsoCurrency::USD),
+    ];
+
+    assert_eq!(quotes[2].change_percent(), Some(10.0));
+    assert_eq!(
+        symbols(&rank_by_change_percent(&quotes)),
+        ["UP", "FLAT", "DOWN"]
+    );
+    assert_eq!(symbols(&top_n(&quotes, 1)), ["UP"]);
+    assert_eq!(symbols(&bottom_n(&quotes, 2)), ["DOWN", "FLAT"]);
+    assert_eq!(top_n(&quotes, 10).len(), 3);
+}
+
+#[test]
+fn mismatched_currency_is_left_out() {
+    let quotes = vec![
+        quote("USD", 101.0, 100.0, IsoCurrency::USD),
+        quote("EUR", 150.0, 100.0, IsoCurrency::EUR),
+    ];
+
+    assert_eq!(quotes[1].change_percent(), None);
+    assert_eq!(symbols(&rank_by_change_percent(&quotes)), ["USD"]);
+}
@@ -0,0 +1,72 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+
+#[tokio::test]
+async fn client_default_region_is_sent_on_quote_calls() {
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "BMW")
+            .query_param("region", "DE");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{
+              "quoteResponse": {
+                "result": [
+                  { "symbol":"BMW", "regularMarketPrice": 90.5, "currency":"EUR", "fullExchangeName":"XETRA" }
+                ],
+                "error": null
+              }
+            }"#);
+    });
+
+    let client = yfinance_rs::YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .default_region("DE")
+        .build()
+        .unwrap();
+
+    let quotes = yfinance_rs::QuotesBuilder::new(client)
+        .symbols(["BMW"])
+        .fetch()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(quotes.len(), 1);
+    assert_eq!(quotes[0].symbol, "BMW");
+}
+
+#[tokio::test]
+async fn per_call_region_overrides_client_default() {
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "BMW")
+            .query_param("region", "US");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"BMW"}],"error":null}}"#);
+    });
+
+    let client = yfinance_rs::YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .default_region("DE")
+        .build()
+        .unwrap();
+
+    let quotes = yfinance_rs::QuotesBuilder::new(client)
+        .symbols(["BMW"])
+        .region("US")
+        .fetch()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(quotes.len(), 1);
+}
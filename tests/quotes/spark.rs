@@ -0,0 +1,77 @@
+use httpmock::{Method::GET, MockServer};
+use yfinance_rs::core::spark::fetch_spark;
+use yfinance_rs::{Interval, Range};
+
+#[tokio::test]
+async fn spark_returns_series_for_each_symbol() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/spark")
+            .query_param("symbols", "AAPL,MSFT")
+            .query_param("range", "1d")
+            .query_param("interval", "5m");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"spark":{"result":[
+                  {"symbol":"AAPL","response":[{
+                    "timestamp":[1704205800,1704206100,1704206400],
+                    "indicators":{"quote":[{"close":[190.1,null,190.4]}]}
+                  }]},
+                  {"symbol":"MSFT","response":[{
+                    "timestamp":[1704205800,1704206100],
+                    "indicators":{"quote":[{"close":[370.0,371.5]}]}
+                  }]}
+                ],"error":null}}"#,
+            );
+    });
+
+    let out = fetch_spark(
+        &crate::common::client_for(&server),
+        &["AAPL", "MSFT"],
+        Range::D1,
+        Interval::I5m,
+    )
+    .await
+    .unwrap();
+    mock.assert();
+
+    assert_eq!(out.len(), 2);
+    assert_eq!(
+        out["AAPL"],
+        vec![(1_704_205_800, 190.1), (1_704_206_400, 190.4)]
+    );
+    assert_eq!(
+        out["MSFT"],
+        vec![(1_704_205_800, 370.0), (1_704_206_100, 371.5)]
+    );
+}
+
+#[tokio::test]
+async fn spark_accepts_flat_symbol_keyed_payload() {
+    let server = MockServer::start();
+    let _mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/spark");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+                  "AAPL":{"symbol":"AAPL","timestamp":[1704205800],"close":[190.1]},
+                  "MSFT":{"symbol":"MSFT","timestamp":[1704205800],"close":[370.0]}
+                }"#,
+            );
+    });
+
+    let out = fetch_spark(
+        &crate::common::client_for(&server),
+        &["AAPL", "MSFT"],
+        Range::D1,
+        Interval::I5m,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(out["AAPL"], vec![(1_704_205_800, 190.1)]);
+    assert_eq!(out["MSFT"], vec![(1_704_205_800, 370.0)]);
+}
@@ -0,0 +1,40 @@
+use httpmock::MockServer;
+use yfinance_rs::YfError;
+use yfinance_rs::quote::QuotesBuilder;
+
+#[tokio::test]
+async fn empty_symbol_is_rejected_before_any_request() {
+    let server = MockServer::start();
+    let any = server.mock(|when, then| {
+        when.path("/v7/finance/quote");
+        then.status(200).body("{}");
+    });
+
+    let err = QuotesBuilder::new(crate::common::client_for(&server))
+        .symbols(["AAPL", "  ", "MSFT"])
+        .fetch()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, YfError::InvalidParams(_)), "{err:?}");
+    any.assert_hits(0);
+}
+
+#[tokio::test]
+async fn max_symbols_rejects_oversized_lists_before_any_request() {
+    let server = MockServer::start();
+    let any = server.mock(|when, then| {
+        when.path("/v7/finance/quote");
+        then.status(200).body("{}");
+    });
+
+    let err = QuotesBuilder::new(crate::common::client_for(&server))
+        .symbols(["AAPL", "MSFT", "GOOG"])
+        .max_symbols(2)
+        .fetch_raw()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, YfError::InvalidParams(_)), "{err:?}");
+    any.assert_hits(0);
+}
@@ -0,0 +1,22 @@
+use yfinance_rs::{QuoteSummaryModule, YfClient};
+
+#[test]
+fn all_modules_are_listed() {
+    let all = QuoteSummaryModule::all();
+    assert_eq!(all.len(), 26);
+    assert!(all.contains(&QuoteSummaryModule::AssetProfile));
+    assert_eq!(QuoteSummaryModule::AssetProfile.as_str(), "assetProfile");
+    for module in [
+        "components",
+        "earningsHistory",
+        "balanceSheetHistory",
+        "balanceSheetHistoryQuarterly",
+        "cashflowStatementHistory",
+        "cashflowStatementHistoryQuarterly",
+    ] {
+        assert!(all.iter().any(|m| m.as_str() == module), "{module} missing");
+    }
+
+    let client = YfClient::default();
+    assert_eq!(client.supported_modules(), all);
+}
@@ -2,5 +2,7 @@ mod common;
 
 #[path = "search/live.rs"]
 mod search_live;
+#[path = "search/many.rs"]
+mod search_many;
 #[path = "search/offline.rs"]
 mod search_offline;
@@ -0,0 +1,57 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{SearchBuilder, YfClient, YfError};
+
+fn body(symbol: &str, name: &str) -> String {
+    format!(
+        r#"{{"count":1,"quotes":[{{"symbol":"{symbol}","shortname":"{name}","quoteType":"EQUITY"}}]}}"#
+    )
+}
+
+#[tokio::test]
+async fn fetch_many_resolves_each_query() {
+    let server = MockServer::start();
+    let apple = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/finance/search")
+            .query_param("q", "apple");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body("AAPL", "Apple Inc."));
+    });
+    let microsoft = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/finance/search")
+            .query_param("q", "microsoft");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body("MSFT", "Microsoft Corporation"));
+    });
+    let broken = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/finance/search")
+            .query_param("q", "broken");
+        then.status(500);
+    });
+
+    let client = YfClient::builder().retry_enabled(false).build().unwrap();
+    let (found, failed) = SearchBuilder::new(&client, "")
+        .search_base(Url::parse(&format!("{}/v1/finance/search", server.base_url())).unwrap())
+        .fetch_many(["apple", "microsoft", "broken", "apple"])
+        .await;
+
+    apple.assert_hits(1);
+    microsoft.assert_hits(1);
+    broken.assert_hits(1);
+    assert_eq!(found.len(), 2, "failed query is left out: {found:?}");
+    assert_eq!(found["apple"][0].symbol, "AAPL");
+    assert_eq!(found["microsoft"][0].symbol, "MSFT");
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].0, "broken");
+    assert!(
+        matches!(failed[0].1, YfError::ServerError { status: 500, .. }),
+        "{:?}",
+        failed[0].1
+    );
+}
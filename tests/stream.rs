@@ -1,8 +1,16 @@
 mod common;
 
+#[path = "stream/backpressure.rs"]
+mod stream_backpressure;
+#[path = "stream/heartbeat.rs"]
+mod stream_heartbeat;
 #[path = "stream/live.rs"]
 mod stream_live;
+#[path = "stream/min_change.rs"]
+mod stream_min_change;
 #[path = "stream/offline.rs"]
 mod stream_offline;
+#[path = "stream/subscribe.rs"]
+mod stream_subscribe;
 #[path = "stream/websocket_decoder.rs"]
 mod websocket_decoder;
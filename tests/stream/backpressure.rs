@@ -0,0 +1,115 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use tokio::time::{Duration, sleep, timeout};
+use yfinance_rs::{Overflow, StreamBuilder, StreamMethod, YfClient};
+
+fn mock_quote(server: &MockServer, price: f64) -> Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(format!(
+                r#"{{"quoteResponse":{{"result":[{{"symbol":"AAPL","regularMarketPrice":{price},"currency":"USD"}}],"error":null}}}}"#
+            ));
+    })
+}
+
+fn builder(client: &YfClient, overflow: Overflow) -> StreamBuilder {
+    StreamBuilder::new(client)
+        .symbols(["AAPL"])
+        .method(StreamMethod::Polling)
+        .interval(Duration::from_millis(10))
+        .diff_only(false)
+        .buffer(2)
+        .overflow(overflow)
+}
+
+#[tokio::test]
+async fn blocking_stream_stops_polling_when_buffer_is_full() {
+    let server = MockServer::start();
+    let mock = mock_quote(&server, 100.0);
+    let (handle, mut rx) = builder(&crate::common::client_for(&server), Overflow::Block)
+        .start()
+        .unwrap();
+
+    // Nobody reads for a while: two updates fill the buffer and the third send waits.
+    sleep(Duration::from_millis(300)).await;
+    assert!(mock.hits() <= 3, "polled {} times", mock.hits());
+
+    for _ in 0..2 {
+        timeout(Duration::from_secs(1), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+    }
+    handle.abort();
+}
+
+#[tokio::test]
+async fn blocking_stream_stops_while_the_consumer_is_not_reading() {
+    let server = MockServer::start();
+    let mock = mock_quote(&server, 100.0);
+    let (handle, _rx) = builder(&crate::common::client_for(&server), Overflow::Block)
+        .start()
+        .unwrap();
+
+    // Let the buffer fill so the stream is waiting on the consumer.
+    timeout(Duration::from_secs(3), async {
+        while mock.hits() < 3 {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    timeout(Duration::from_secs(1), handle.stop())
+        .await
+        .expect("stop() hung while the stream was blocked on a full buffer");
+}
+
+#[tokio::test]
+async fn drop_oldest_stream_keeps_polling_and_discards_stale_updates() {
+    let server = MockServer::start();
+    let mut mock = mock_quote(&server, 100.0);
+    let (handle, mut rx) = builder(&crate::common::client_for(&server), Overflow::DropOldest)
+        .start()
+        .unwrap();
+
+    // The stream keeps polling while nobody reads, discarding old updates.
+    sleep(Duration::from_millis(300)).await;
+    assert!(mock.hits() > 3, "polled {} times", mock.hits());
+
+    mock.delete();
+    let mock = mock_quote(&server, 200.0);
+    timeout(Duration::from_secs(3), async {
+        while mock.hits() < 2 {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    // The update next in line is kept; the backlog behind it now holds the newest price.
+    let mut prices = Vec::new();
+    for _ in 0..2 {
+        let update = timeout(Duration::from_secs(1), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        prices.push(update.last_price);
+    }
+    assert_eq!(prices, [Some(100.0), Some(200.0)]);
+    handle.abort();
+}
+
+#[tokio::test]
+async fn zero_buffer_is_rejected() {
+    let server = MockServer::start();
+    let err = builder(&crate::common::client_for(&server), Overflow::Block)
+        .buffer(0)
+        .start()
+        .err()
+        .expect("buffer 0 must fail");
+    assert!(matches!(err, yfinance_rs::YfError::InvalidParams(_)));
+}
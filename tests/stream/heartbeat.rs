@@ -0,0 +1,90 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures_util::StreamExt;
+use tokio::net::TcpListener;
+use tokio::time::{Duration, timeout};
+use url::Url;
+use yfinance_rs::{StreamBuilder, StreamMethod, YfClient};
+
+// Accepts WebSocket connections, reads the subscribe frame, then goes silent: it never
+// reads again, so pings from the client go unanswered.
+async fn silent_server() -> (Url, Arc<AtomicUsize>, tokio::sync::mpsc::Receiver<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("ws://{}/", listener.local_addr().unwrap())).unwrap();
+    let connections = Arc::new(AtomicUsize::new(0));
+    let (conn_tx, conn_rx) = tokio::sync::mpsc::channel(8);
+
+    let counter = connections.clone();
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((tcp, _)) = listener.accept().await {
+            let Ok(mut ws) = tokio_tungstenite::accept_async(tcp).await else {
+                continue;
+            };
+            let _subscribe = ws.next().await;
+            counter.fetch_add(1, Ordering::SeqCst);
+            let _ = conn_tx.send(()).await;
+            held.push(ws);
+        }
+    });
+
+    (url, connections, conn_rx)
+}
+
+#[tokio::test]
+async fn heartbeat_timeout_reconnects_a_silent_websocket() {
+    let (url, connections, mut conn_rx) = silent_server().await;
+    let client = YfClient::builder().base_stream(url).build().unwrap();
+
+    let (handle, _rx) = StreamBuilder::new(&client)
+        .symbols(["AAPL"])
+        .method(StreamMethod::Websocket)
+        .heartbeat_timeout(Duration::from_millis(200))
+        .start()
+        .unwrap();
+
+    for _ in 0..2 {
+        timeout(Duration::from_secs(3), conn_rx.recv())
+            .await
+            .expect("timed out waiting for a websocket connection")
+            .unwrap();
+    }
+    handle.stop().await;
+
+    assert!(connections.load(Ordering::SeqCst) >= 2);
+}
+
+#[tokio::test]
+async fn without_heartbeat_timeout_a_silent_websocket_is_kept() {
+    let (url, connections, mut conn_rx) = silent_server().await;
+    let client = YfClient::builder().base_stream(url).build().unwrap();
+
+    let (handle, _rx) = StreamBuilder::new(&client)
+        .symbols(["AAPL"])
+        .method(StreamMethod::Websocket)
+        .start()
+        .unwrap();
+
+    timeout(Duration::from_secs(3), conn_rx.recv())
+        .await
+        .expect("timed out waiting for a websocket connection")
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    handle.stop().await;
+
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn zero_heartbeat_timeout_is_rejected() {
+    let client = YfClient::builder().build().unwrap();
+    let result = StreamBuilder::new(&client)
+        .symbols(["AAPL"])
+        .heartbeat_timeout(Duration::ZERO)
+        .start();
+    assert!(matches!(
+        result,
+        Err(yfinance_rs::YfError::InvalidParams(_))
+    ));
+}
@@ -0,0 +1,104 @@
+use httpmock::{Method::GET, Mock, MockServer};
+use tokio::time::{Duration, sleep, timeout};
+use url::Url;
+use yfinance_rs::{ChangeThreshold, Ticker, YfClient};
+
+fn mock_price(server: &MockServer, price: f64) -> Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(format!(
+                r#"{{"quoteResponse":{{"result":[{{"symbol":"AAPL","regularMarketPrice":{price},"currency":"USD"}}],"error":null}}}}"#
+            ));
+    })
+}
+
+async fn wait_for_hits(mock: &Mock<'_>, n: usize) {
+    timeout(Duration::from_secs(3), async {
+        while mock.hits() < n {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("stream stopped polling");
+}
+
+#[tokio::test]
+async fn small_moves_are_suppressed_and_large_moves_emitted() {
+    let server = MockServer::start();
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let mut mock = mock_price(&server, 100.0);
+    let (handle, mut rx) = Ticker::new(&client, "AAPL")
+        .quotes_stream_changes(ChangeThreshold::Percent(1.0), Duration::from_millis(20))
+        .unwrap();
+
+    let first = timeout(Duration::from_secs(3), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.last_price, Some(100.0));
+
+    // A 0.1% move stays below the 1% threshold.
+    mock.delete();
+    mock = mock_price(&server, 100.1);
+    wait_for_hits(&mock, 3).await;
+    assert!(rx.try_recv().is_err(), "0.1% move should be suppressed");
+
+    // A 2% move from the last emitted price goes through.
+    mock.delete();
+    let _mock = mock_price(&server, 102.0);
+    let next = timeout(Duration::from_secs(3), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    handle.abort();
+
+    assert_eq!(next.last_price, Some(102.0));
+}
+
+#[test]
+fn percent_threshold_from_zero_is_exceeded_by_any_move() {
+    let t = ChangeThreshold::Percent(1.0);
+    assert!(t.is_exceeded(0.0, 0.01));
+    assert!(t.is_exceeded(0.0, -0.01));
+    assert!(!t.is_exceeded(0.0, 0.0));
+    assert!(!t.is_exceeded(100.0, 100.5));
+    assert!(t.is_exceeded(100.0, 101.5));
+}
+
+#[tokio::test]
+async fn percent_stream_resumes_after_a_zero_price() {
+    let server = MockServer::start();
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let mut mock = mock_price(&server, 0.0);
+    let (handle, mut rx) = Ticker::new(&client, "AAPL")
+        .quotes_stream_changes(ChangeThreshold::Percent(1.0), Duration::from_millis(20))
+        .unwrap();
+
+    let first = timeout(Duration::from_secs(3), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.last_price, Some(0.0));
+
+    mock.delete();
+    let _mock = mock_price(&server, 5.0);
+    let next = timeout(Duration::from_secs(3), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    handle.abort();
+
+    assert_eq!(next.last_price, Some(5.0));
+}
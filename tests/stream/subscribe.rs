@@ -0,0 +1,89 @@
+use futures_util::StreamExt;
+use tokio::net::TcpListener;
+use tokio::time::{Duration, timeout};
+use tokio_tungstenite::tungstenite::Message;
+use url::Url;
+use yfinance_rs::{StreamBuilder, StreamMethod, YfClient};
+
+// Accepts a single WebSocket connection and forwards every text frame the client sends.
+async fn recording_server() -> (Url, tokio::sync::mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("ws://{}/", listener.local_addr().unwrap())).unwrap();
+    let (frame_tx, frame_rx) = tokio::sync::mpsc::channel(8);
+
+    tokio::spawn(async move {
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+        while let Some(Ok(msg)) = ws.next().await {
+            if let Message::Text(text) = msg
+                && frame_tx.send(text.to_string()).await.is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    (url, frame_rx)
+}
+
+async fn next_frame(rx: &mut tokio::sync::mpsc::Receiver<String>) -> serde_json::Value {
+    let text = timeout(Duration::from_secs(3), rx.recv())
+        .await
+        .expect("timed out waiting for a websocket frame")
+        .unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+#[tokio::test]
+async fn websocket_subscribe_and_unsubscribe_are_sent_to_the_server() {
+    let (url, mut frames) = recording_server().await;
+    let client = YfClient::builder().base_stream(url).build().unwrap();
+
+    let (handle, _rx) = StreamBuilder::new(&client)
+        .symbols(["AAPL"])
+        .method(StreamMethod::Websocket)
+        .start()
+        .unwrap();
+
+    assert_eq!(
+        next_frame(&mut frames).await,
+        serde_json::json!({ "subscribe": ["AAPL"] })
+    );
+
+    assert!(handle.subscribe(["MSFT", "TSLA"]));
+    assert_eq!(
+        next_frame(&mut frames).await,
+        serde_json::json!({ "subscribe": ["MSFT", "TSLA"] })
+    );
+
+    assert!(handle.unsubscribe(["AAPL"]));
+    assert_eq!(
+        next_frame(&mut frames).await,
+        serde_json::json!({ "unsubscribe": ["AAPL"] })
+    );
+
+    handle.stop().await;
+}
+
+#[tokio::test]
+async fn subscribe_after_the_stream_ended_returns_false() {
+    // Nothing listens on this port, so the websocket-only stream ends straight away.
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = Url::parse(&format!("ws://{}/", listener.local_addr().unwrap())).unwrap();
+    drop(listener);
+    let client = YfClient::builder().base_stream(url).build().unwrap();
+
+    let (handle, mut rx) = StreamBuilder::new(&client)
+        .symbols(["AAPL"])
+        .method(StreamMethod::Websocket)
+        .start()
+        .unwrap();
+
+    assert!(
+        timeout(Duration::from_secs(3), rx.recv())
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(!handle.subscribe(["MSFT"]));
+}
@@ -2,29 +2,73 @@ mod common;
 
 #[path = "ticker/actions.rs"]
 mod actions;
+#[path = "ticker/alerts.rs"]
+mod alerts;
+#[path = "ticker/beta.rs"]
+mod beta;
 #[path = "ticker/capital_gains.rs"]
 mod capital_gains;
+#[path = "ticker/concurrency_limit.rs"]
+mod concurrency_limit;
+#[path = "ticker/constituents.rs"]
+mod constituents;
+#[path = "ticker/dividend_yield.rs"]
+mod dividend_yield;
+#[path = "ticker/dividends_fx.rs"]
+mod dividends_fx;
 #[path = "ticker/fast_info.rs"]
 mod fast_info;
+#[path = "ticker/full_quote.rs"]
+mod full_quote;
 #[path = "ticker/history_convenience.rs"]
 mod history_convenience;
+#[path = "ticker/history_intraday.rs"]
+mod history_intraday;
 #[path = "ticker/info_live.rs"]
 mod info_live;
 #[path = "ticker/info_offline.rs"]
 mod info_offline;
+#[path = "ticker/info_raw.rs"]
+mod info_raw;
 #[path = "ticker/isin_live.rs"]
 mod isin_live;
 #[path = "ticker/isin_offline.rs"]
 mod isin_offline;
+#[path = "ticker/iv_surface.rs"]
+mod iv_surface;
+#[path = "ticker/key_statistics.rs"]
+mod key_statistics;
 #[path = "ticker/live.rs"]
 mod live;
+#[path = "ticker/market_cap.rs"]
+mod market_cap;
+#[path = "ticker/new_on_exchange.rs"]
+mod new_on_exchange;
+#[path = "ticker/null_price_retry.rs"]
+mod null_price_retry;
 #[path = "ticker/offline.rs"]
 mod offline;
+#[path = "ticker/option_chain_strike_range.rs"]
+mod option_chain_strike_range;
 #[path = "ticker/options.rs"]
 mod options;
 #[path = "ticker/options_expiry_from_url_fallback.rs"]
 mod options_expiry_from_url_fallback;
+#[path = "ticker/previous_close.rs"]
+mod previous_close;
+#[path = "ticker/price_preference.rs"]
+mod price_preference;
+#[path = "ticker/price_validation.rs"]
+mod price_validation;
 #[path = "ticker/quote.rs"]
 mod quote;
+#[path = "ticker/quote_chart_fallback.rs"]
+mod quote_chart_fallback;
+#[path = "ticker/quote_coalesce.rs"]
+mod quote_coalesce;
 #[path = "ticker/shares.rs"]
 mod shares;
+#[path = "ticker/strict_symbol.rs"]
+mod strict_symbol;
+#[path = "ticker/summary_detail.rs"]
+mod summary_detail;
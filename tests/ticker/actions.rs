@@ -67,3 +67,37 @@ async fn ticker_actions_dividends_splits() {
     let splits = t.splits(Some(Range::Max)).await.unwrap();
     assert_eq!(splits, vec![(2000, 2, 1)]);
 }
+
+#[tokio::test]
+async fn adjusted_dividends_match_yahoos_split_adjusted_amounts() {
+    let server = MockServer::start();
+    let body = r#"{"chart":{"result":[{
+        "timestamp":[1000,2000,3000],
+        "indicators":{"quote":[{
+          "open":[100.0,50.0,50.0],"high":[100.0,50.0,50.0],"low":[100.0,50.0,50.0],
+          "close":[100.0,50.0,50.0],"volume":[10,10,10]
+        }]},
+        "events":{
+          "dividends":{"1000":{"date":1000,"amount":0.5},"3000":{"date":3000,"amount":0.6}},
+          "splits":{"2000":{"date":2000,"numerator":2,"denominator":1}}
+        }
+      }],"error":null}}"#;
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/TEST");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+    let t = Ticker::new(&client, "TEST");
+
+    // Yahoo sends the 1.0 dividend paid before the 2:1 split already halved.
+    let adjusted = t.adjusted_dividends(None).await.unwrap();
+    mock.assert();
+    assert_eq!(adjusted, vec![(1000, 0.5), (3000, 0.6)]);
+    assert_eq!(t.dividends(None).await.unwrap(), adjusted);
+}
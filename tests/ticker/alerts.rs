@@ -0,0 +1,61 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Alert, Ticker, YfClient};
+
+#[tokio::test]
+async fn check_alerts_returns_only_triggered_conditions() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":205.0,"currency":"USD"}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let alerts = [
+        Alert::Above(200.0),
+        Alert::Below(150.0),
+        Alert::CrossesAbove {
+            level: 200.0,
+            previous: 198.0,
+        },
+        // Already above the level last time: no new crossing.
+        Alert::CrossesAbove {
+            level: 200.0,
+            previous: 201.0,
+        },
+        Alert::CrossesBelow {
+            level: 210.0,
+            previous: 212.0,
+        },
+    ];
+
+    let fired = Ticker::new(&client, "AAPL")
+        .check_alerts(&alerts)
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(
+        fired,
+        vec![
+            Alert::Above(200.0),
+            Alert::CrossesAbove {
+                level: 200.0,
+                previous: 198.0,
+            },
+            Alert::CrossesBelow {
+                level: 210.0,
+                previous: 212.0,
+            },
+        ]
+    );
+}
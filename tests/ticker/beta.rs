@@ -0,0 +1,98 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::{BetaSource, Ticker};
+
+fn summary_mock<'a>(server: &'a MockServer, body: &str) -> httpmock::Mock<'a> {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", "summaryDetail,defaultKeyStatistics");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    })
+}
+
+fn chart_body(closes: &[f64]) -> String {
+    let ts: Vec<String> = (0..closes.len())
+        .map(|i| (1_704_067_200 + i * 86_400).to_string())
+        .collect();
+    let px: Vec<String> = closes.iter().map(f64::to_string).collect();
+    let (ts, px) = (ts.join(","), px.join(","));
+    format!(
+        r#"{{"chart":{{"result":[{{"meta":{{"currency":"USD"}},"timestamp":[{ts}],
+        "indicators":{{"quote":[{{"open":[{px}],"high":[{px}],"low":[{px}],"close":[{px}],
+        "volume":[]}}],"adjclose":[{{"adjclose":[{px}]}}]}}}}],"error":null}}}}"#
+    )
+}
+
+#[tokio::test]
+async fn beta_prefers_the_reported_value() {
+    let server = MockServer::start();
+    let summary = summary_mock(
+        &server,
+        r#"{"quoteSummary":{"result":[{
+          "summaryDetail":{"beta":{"raw":1.24,"fmt":"1.24"}},
+          "defaultKeyStatistics":{"beta":{"raw":1.3},"beta3Year":{"raw":0.9}}
+        }],"error":null}}"#,
+    );
+    let chart = server.mock(|when, then| {
+        when.method(GET).path_contains("/v8/finance/chart/");
+        then.status(500);
+    });
+
+    let beta = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .beta(Some("^GSPC"))
+        .await
+        .unwrap()
+        .unwrap();
+
+    summary.assert();
+    chart.assert_hits(0);
+    assert_eq!(beta.value, 1.24);
+    assert_eq!(beta.source, BetaSource::Reported);
+}
+
+#[tokio::test]
+async fn beta_is_computed_against_the_benchmark_when_not_reported() {
+    let server = MockServer::start();
+    summary_mock(
+        &server,
+        r#"{"quoteSummary":{"result":[{"summaryDetail":{},"defaultKeyStatistics":{}}],"error":null}}"#,
+    );
+
+    // The asset's daily returns are twice the benchmark's, so beta is 2 up to price rounding.
+    let moves = [0.01, -0.005, 0.02, -0.01, 0.004];
+    let (mut bench, mut asset) = (vec![100.0], vec![50.0]);
+    for i in 0..30 {
+        let r = moves[i % moves.len()];
+        bench.push(bench[i] * (1.0 + r));
+        asset.push(asset[i] * 2.0f64.mul_add(r, 1.0));
+    }
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chart_body(&asset));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/^GSPC");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chart_body(&bench));
+    });
+
+    let ticker = Ticker::new(&crate::common::client_for(&server), "AAPL");
+    let beta = ticker.beta(Some("^GSPC")).await.unwrap().unwrap();
+
+    assert!((beta.value - 2.0).abs() < 1e-2, "beta = {}", beta.value);
+    assert_eq!(
+        beta.source,
+        BetaSource::Computed {
+            benchmark: "^GSPC".into(),
+            periods: 30
+        }
+    );
+
+    assert!(ticker.beta(None).await.unwrap().is_none());
+}
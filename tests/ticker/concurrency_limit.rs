@@ -0,0 +1,134 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Interval, Range, Ticker, YfClient};
+
+const DELAY: Duration = Duration::from_millis(100);
+
+const QUOTE_BODY: &str = r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD"}],"error":null}}"#;
+const CHART_BODY: &str = r#"{"chart":{"result":[{"meta":{"currency":"USD","symbol":"AAPL"},"timestamp":[1704153600],"indicators":{"quote":[{"open":[1.0],"high":[1.0],"low":[1.0],"close":[1.0],"volume":[1]}]}}],"error":null}}"#;
+
+/// Request counters for [`serve_slowly`].
+#[derive(Default)]
+struct InFlight {
+    current: AtomicUsize,
+    max: AtomicUsize,
+    quotes: AtomicUsize,
+    charts: AtomicUsize,
+}
+
+/// Serves the v7 quote and v8 chart endpoints, holding every request for `DELAY` and
+/// recording how many were being handled at once.
+fn serve_slowly(listener: TcpListener, stats: Arc<InFlight>) {
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else { return };
+        let stats = stats.clone();
+        std::thread::spawn(move || {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+                    return;
+                }
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap_or(0) > 2 {
+                    header.clear();
+                }
+
+                let now = stats.current.fetch_add(1, Ordering::SeqCst) + 1;
+                stats.max.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(DELAY);
+                stats.current.fetch_sub(1, Ordering::SeqCst);
+
+                let body = if request_line.contains("/v7/finance/quote") {
+                    stats.quotes.fetch_add(1, Ordering::SeqCst);
+                    QUOTE_BODY
+                } else {
+                    stats.charts.fetch_add(1, Ordering::SeqCst);
+                    CHART_BODY
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                    body.len()
+                );
+                if stream.write_all(response.as_bytes()).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+#[tokio::test]
+async fn max_concurrent_requests_caps_mixed_operations_across_clones() {
+    let server = MockServer::start();
+
+    // History resolves the reporting currency through the profile endpoint; keep it offline.
+    let _auth = crate::common::mock_cookie_crumb(&server);
+    let _profile = server.mock(|when, then| {
+        when.method(GET).path("/v10/finance/quoteSummary/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(crate::common::fixture(
+                "profile_api_assetProfile-quoteType-fundProfile",
+                "AAPL",
+                "json",
+            ));
+    });
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let slow = format!("http://{}", listener.local_addr().unwrap());
+    let stats = Arc::new(InFlight::default());
+    {
+        let stats = stats.clone();
+        std::thread::spawn(move || serve_slowly(listener, stats));
+    }
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{slow}/v7/finance/quote")).unwrap())
+        .base_chart(Url::parse(&format!("{slow}/v8/finance/chart/")).unwrap())
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        .cookie_url(Url::parse(&format!("{}/consent", server.base_url())).unwrap())
+        .crumb_url(Url::parse(&format!("{}/v1/test/getcrumb", server.base_url())).unwrap())
+        .max_concurrent_requests(2)
+        .build()
+        .unwrap();
+    let clone = client.clone();
+
+    let a = Ticker::new(&client, "AAPL");
+    let b = Ticker::new(&clone, "AAPL");
+    // Identical concurrent quotes share one request, so each quote uses its own symbol.
+    let c = Ticker::new(&clone, "MSFT");
+    let d = Ticker::new(&client, "GOOG");
+
+    let (q1, q2, q3, h1, h2, h3) = tokio::join!(
+        a.quote(),
+        c.quote(),
+        d.quote(),
+        a.history(Some(Range::D5), Some(Interval::D1), false),
+        b.history(Some(Range::D5), Some(Interval::D1), false),
+        b.history(Some(Range::D5), Some(Interval::D1), false),
+    );
+
+    for q in [q1, q2, q3] {
+        q.unwrap();
+    }
+    for h in [h1, h2, h3] {
+        assert_eq!(h.unwrap().len(), 1);
+    }
+    assert_eq!(stats.quotes.load(Ordering::SeqCst), 3);
+    assert_eq!(stats.charts.load(Ordering::SeqCst), 3);
+    assert_eq!(
+        stats.max.load(Ordering::SeqCst),
+        2,
+        "six requests should run two at a time"
+    );
+}
@@ -0,0 +1,107 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::{Ticker, YfError};
+
+#[tokio::test]
+async fn constituents_resolves_components_then_batches_quotes() {
+    let server = MockServer::start();
+    let components = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/^TINY")
+            .query_param("modules", "components");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteSummary":{"result":[{"components":{"components":["AAPL","MSFT"],"maxAge":1}}],"error":null}}"#);
+    });
+    let quotes = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL,MSFT");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteResponse":{"result":[
+                  {"symbol":"AAPL","regularMarketPrice":190.0,"currency":"USD"},
+                  {"symbol":"MSFT","regularMarketPrice":410.0,"currency":"USD"}
+                ],"error":null}}"#,
+            );
+    });
+
+    let out = Ticker::new(&crate::common::client_for(&server), "^TINY")
+        .constituents()
+        .await
+        .unwrap();
+    components.assert();
+    quotes.assert();
+
+    let symbols: Vec<&str> = out.iter().map(|q| q.symbol.as_str()).collect();
+    assert_eq!(symbols, ["AAPL", "MSFT"]);
+}
+
+#[tokio::test]
+async fn constituents_without_components_is_not_found() {
+    let server = MockServer::start();
+    let _components = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", "components");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteSummary":{"result":null,"error":{"code":"Not Found","description":"No fundamentals data found for symbol: AAPL"}}}"#);
+    });
+
+    let err = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .constituents()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, YfError::NotFound { .. }), "got {err:?}");
+}
+
+#[tokio::test]
+async fn constituents_of_a_large_index_are_fetched_in_chunks() {
+    let server = MockServer::start();
+    let members: Vec<String> = (0..150).map(|i| format!("S{i}")).collect();
+    let list = serde_json::to_string(&members).unwrap();
+    let _components = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/^BIG")
+            .query_param("modules", "components");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(format!(
+                r#"{{"quoteSummary":{{"result":[{{"components":{{"components":{list}}}}}],"error":null}}}}"#
+            ));
+    });
+    let chunk_mock = |chunk: &[String]| {
+        let symbols = chunk.join(",");
+        let result: Vec<String> = chunk
+            .iter()
+            .map(|s| format!(r#"{{"symbol":"{s}","regularMarketPrice":1.0,"currency":"USD"}}"#))
+            .collect();
+        let body = format!(
+            r#"{{"quoteResponse":{{"result":[{}],"error":null}}}}"#,
+            result.join(",")
+        );
+        server.mock(move |when, then| {
+            when.method(GET)
+                .path("/v7/finance/quote")
+                .query_param("symbols", symbols.as_str());
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(body);
+        })
+    };
+    let first = chunk_mock(&members[..100]);
+    let second = chunk_mock(&members[100..]);
+
+    let out = Ticker::new(&crate::common::client_for(&server), "^BIG")
+        .constituents()
+        .await
+        .unwrap();
+
+    first.assert();
+    second.assert();
+    let symbols: Vec<&str> = out.iter().map(|q| q.symbol.as_str()).collect();
+    assert_eq!(symbols, members);
+}
@@ -0,0 +1,158 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::core::Range;
+use yfinance_rs::{Ticker, YfClient};
+
+const T0: i64 = 1_600_000_000;
+const DAY: i64 = 86_400;
+
+fn body() -> String {
+    let days = [0, 100, 200, 300, 366, 400, 500, 600, 700];
+    let closes = [100.0, 100.0, 100.0, 100.0, 100.0, 100.0, 100.0, 100.0, 80.0];
+    chart_body("USD", &days, &closes, "")
+}
+
+fn chart_body(currency: &str, days: &[i64], closes: &[f64], splits: &str) -> String {
+    let ts: Vec<String> = days.iter().map(|d| (T0 + d * DAY).to_string()).collect();
+    let closes: Vec<String> = closes.iter().map(ToString::to_string).collect();
+    let dividends: Vec<String> = [50, 140, 230, 320, 410, 500, 590, 680]
+        .iter()
+        .map(|d| {
+            let t = T0 + d * DAY;
+            format!(r#""{t}":{{"date":{t},"amount":0.5}}"#)
+        })
+        .collect();
+    let closes = closes.join(",");
+    format!(
+        r#"{{"chart":{{"result":[{{
+          "meta":{{"currency":"{currency}"}},
+          "timestamp":[{}],
+          "indicators":{{
+            "quote":[{{"open":[{closes}],"high":[{closes}],"low":[{closes}],"close":[{closes}],"volume":[{}]}}],
+            "adjclose":[{{"adjclose":[{closes}]}}]
+          }},
+          "events":{{"dividends":{{{}}},"splits":{{{splits}}}}}
+        }}],"error":null}}}}"#,
+        ts.join(","),
+        vec!["1"; days.len()].join(","),
+        dividends.join(",")
+    )
+}
+
+#[tokio::test]
+async fn dividend_yield_history_sums_trailing_year() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/DIV")
+            .query_param("range", "2y")
+            .query_param("events", "div|split|capitalGains");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body());
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let points = Ticker::new(&client, "DIV")
+        .dividend_yield_history(Some(Range::Y2))
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(points.len(), 9);
+    // No complete trailing year before day 365.
+    assert!(
+        points[..4]
+            .iter()
+            .all(|p| p.ttm_dividends.is_none() && p.dividend_yield.is_none())
+    );
+
+    let yields: Vec<(i64, f64, f64)> = points[4..]
+        .iter()
+        .map(|p| {
+            (
+                (p.ts.timestamp() - T0) / DAY,
+                p.ttm_dividends.unwrap(),
+                p.dividend_yield.unwrap(),
+            )
+        })
+        .collect();
+    let expected = [
+        (366, 2.0, 0.02),
+        (400, 2.0, 0.02),
+        (500, 2.5, 0.025),
+        (600, 2.0, 0.02),
+        (700, 2.0, 0.025),
+    ];
+    for ((day, ttm, y), (e_day, e_ttm, e_y)) in yields.into_iter().zip(expected) {
+        assert_eq!(day, e_day);
+        assert!((ttm - e_ttm).abs() < 1e-9, "day {day}: ttm {ttm}");
+        assert!((y - e_y).abs() < 1e-9, "day {day}: yield {y}");
+    }
+}
+
+#[tokio::test]
+async fn dividend_yield_history_restates_dividends_across_a_split() {
+    let server = MockServer::start();
+    // A 2:1 split on day 450 halves the unadjusted close; Yahoo's dividends stay split-adjusted
+    // (0.5 per post-split share, i.e. 1.0 per share before the split).
+    let split_ts = T0 + 450 * DAY;
+    let splits = format!(
+        r#""{split_ts}":{{"date":{split_ts},"numerator":2,"denominator":1,"splitRatio":"2:1"}}"#
+    );
+    let days = [0, 100, 200, 300, 366, 400, 500];
+    let closes = [200.0, 200.0, 200.0, 200.0, 200.0, 200.0, 100.0];
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/SPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chart_body("USD", &days, &closes, &splits));
+    });
+
+    let points = Ticker::new(&crate::common::client_for(&server), "SPL")
+        .dividend_yield_history(Some(Range::Y2))
+        .await
+        .unwrap();
+    mock.assert();
+
+    let at = |day: i64| {
+        points
+            .iter()
+            .find(|p| p.ts.timestamp() == T0 + day * DAY)
+            .unwrap()
+    };
+    // Before the split: four 1.0 payments against a 200 close.
+    assert!((at(400).ttm_dividends.unwrap() - 4.0).abs() < 1e-9);
+    assert!((at(400).dividend_yield.unwrap() - 0.02).abs() < 1e-9);
+    // After the split: 0.5 per share against a 100 close gives the same yield.
+    assert!((at(500).ttm_dividends.unwrap() - 2.5).abs() < 1e-9);
+    assert!((at(500).dividend_yield.unwrap() - 0.025).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn dividend_yield_history_skips_yield_when_currencies_differ() {
+    let server = MockServer::start();
+    let days = [0, 366];
+    let closes = [1000.0, 1000.0];
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/PENCE.L");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chart_body("GBp", &days, &closes, ""));
+    });
+
+    let points = Ticker::new(&crate::common::client_for(&server), "PENCE.L")
+        .dividend_yield_history(Some(Range::Y2))
+        .await
+        .unwrap();
+    mock.assert();
+
+    let last = points.last().unwrap();
+    assert!(last.ttm_dividends.is_some());
+    assert!(last.dividend_yield.is_none());
+}
@@ -0,0 +1,141 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use paft::money::{Currency, IsoCurrency};
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{RateBasis, Ticker, YfClient};
+
+fn mock_dividends(server: &MockServer) -> httpmock::Mock<'_> {
+    // One USD dividend of 0.24 on 2024-01-02.
+    let body = r#"{
+      "chart":{"result":[{
+        "meta":{"currency":"USD","symbol":"TEST"},
+        "timestamp":[1704153600],
+        "indicators":{"quote":[{
+          "open":[100.0],"high":[101.0],"low":[99.0],"close":[100.0],"volume":[10]
+        }]},
+        "events":{"dividends":{"1704153600":{"date":1704153600,"amount":0.24}}}
+      }],"error":null}
+    }"#;
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/TEST");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    })
+}
+
+fn fx_body(ts: &[i64], closes: &[f64]) -> String {
+    format!(
+        r#"{{"chart":{{"result":[{{
+          "meta":{{"currency":"EUR","symbol":"USDEUR=X"}},
+          "timestamp":{ts:?},
+          "indicators":{{"quote":[{{"open":{closes:?},"high":{closes:?},"low":{closes:?},"close":{closes:?},"volume":{zeros:?}}}]}}
+        }}],"error":null}}}}"#,
+        zeros = vec![0; ts.len()]
+    )
+}
+
+#[tokio::test]
+async fn dividends_convert_usd_to_eur_at_event_date() {
+    let server = MockServer::start();
+    let divs = mock_dividends(&server);
+    let fx = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/USDEUR=X")
+            .query_param_exists("period1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(fx_body(&[1_704_067_200, 1_704_153_600], &[0.90, 0.92]));
+    });
+
+    let out = Ticker::new(&crate::common::client_for(&server), "TEST")
+        .dividends_builder()
+        .convert_to("EUR", RateBasis::EventDate)
+        .fetch()
+        .await
+        .unwrap();
+    divs.assert();
+    fx.assert();
+
+    assert_eq!(out.len(), 1);
+    let d = &out[0];
+    assert_eq!(d.amount.currency(), &Currency::Iso(IsoCurrency::EUR));
+    // 0.24 USD * 0.92 = 0.2208 EUR, rounded to cents
+    assert!(
+        (money_to_f64(&d.amount) - 0.22).abs() < 1e-9,
+        "{}",
+        d.amount
+    );
+
+    let conv = d.conversion.as_ref().expect("conversion recorded");
+    assert_eq!(conv.basis, RateBasis::EventDate);
+    assert!((conv.rate - 0.92).abs() < 1e-12);
+    assert!((money_to_f64(&conv.original) - 0.24).abs() < 1e-12);
+    assert_eq!(conv.original.currency(), &Currency::Iso(IsoCurrency::USD));
+}
+
+#[tokio::test]
+async fn dividends_convert_with_latest_rate() {
+    let server = MockServer::start();
+    let _divs = mock_dividends(&server);
+    let fx = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/USDEUR=X")
+            .query_param("range", "5d");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(fx_body(&[1_727_654_400, 1_727_740_800], &[0.89, 0.95]));
+    });
+
+    let out = Ticker::new(&crate::common::client_for(&server), "TEST")
+        .dividends_builder()
+        .convert_to("EUR", RateBasis::Latest)
+        .fetch()
+        .await
+        .unwrap();
+    fx.assert();
+
+    let d = &out[0];
+    // 0.24 * 0.95 = 0.228 -> 0.23
+    assert!((money_to_f64(&d.amount) - 0.23).abs() < 1e-9);
+    assert_eq!(d.conversion.as_ref().unwrap().basis, RateBasis::Latest);
+}
+
+#[tokio::test]
+async fn dividends_fail_when_no_rate_is_available() {
+    let server = MockServer::start();
+    let divs = mock_dividends(&server);
+    // The event-date history has no rate on or before the dividend, and the latest rate
+    // request fails, so there is nothing to convert with.
+    let history = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/USDEUR=X")
+            .query_param_exists("period1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(fx_body(&[1_704_240_000], &[0.93]));
+    });
+    let latest = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/USDEUR=X")
+            .query_param("range", "5d");
+        then.status(404);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .retry_enabled(false)
+        .build()
+        .unwrap();
+    let res = Ticker::new(&client, "TEST")
+        .dividends_builder()
+        .convert_to("EUR", RateBasis::EventDate)
+        .fetch()
+        .await;
+
+    divs.assert();
+    history.assert();
+    latest.assert();
+    assert!(res.is_err(), "expected an error, got {res:?}");
+}
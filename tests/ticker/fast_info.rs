@@ -1,7 +1,8 @@
 use httpmock::Method::GET;
 use httpmock::MockServer;
+use std::time::Duration;
 use url::Url;
-use yfinance_rs::{Ticker, YfClient};
+use yfinance_rs::{Freshness, Ticker, YfClient};
 
 #[tokio::test]
 async fn fast_info_uses_previous_close_when_price_missing() {
@@ -43,3 +44,225 @@ async fn fast_info_uses_previous_close_when_price_missing() {
     assert_eq!(fi.previous_close, Some(199.5));
     assert_eq!(fi.exchange.as_deref(), Some("NASDAQ"));
 }
+
+#[tokio::test]
+async fn fast_info_requests_minimal_field_set() {
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "MSFT")
+            .query_param(
+                "fields",
+                "regularMarketPrice,regularMarketPreviousClose,preMarketPreviousClose,postMarketPreviousClose,currency,marketState,fullExchangeName,regularMarketTime",
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"MSFT","regularMarketPrice":410.0,"currency":"USD","marketState":"REGULAR"}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let fi = Ticker::new(&client, "MSFT").fast_info().await.unwrap();
+    mock.assert();
+
+    assert!((fi.last_price - 410.0).abs() < 1e-9);
+    assert_eq!(fi.currency.as_deref(), Some("USD"));
+}
+
+#[tokio::test]
+async fn fast_info_post_market_uses_regular_session_close() {
+    let server = MockServer::start();
+
+    // After hours: regularMarketPrice is today's regular close, regularMarketPreviousClose is
+    // yesterday's. The post-market change must be measured against today's close.
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteResponse":{"result":[{
+              "symbol":"AAPL","currency":"USD","marketState":"POST",
+              "regularMarketPrice":200.0,"regularMarketPreviousClose":195.0
+            }],"error":null}}"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let fi = Ticker::new(&client, "AAPL").fast_info().await.unwrap();
+    mock.assert();
+
+    assert_eq!(fi.previous_close, Some(200.0));
+}
+
+#[tokio::test]
+async fn fast_info_prefers_explicit_post_market_previous_close() {
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteResponse":{"result":[{
+              "symbol":"AAPL","currency":"USD","marketState":"POST",
+              "regularMarketPrice":200.0,"regularMarketPreviousClose":195.0,
+              "postMarketPreviousClose":199.75
+            }],"error":null}}"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let fi = Ticker::new(&client, "AAPL").fast_info().await.unwrap();
+    mock.assert();
+
+    assert_eq!(fi.previous_close, Some(199.75));
+    assert!((fi.last_price - 200.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn fast_info_parses_regular_market_time_for_staleness() {
+    let server = MockServer::start();
+    let now = chrono::Utc::now().timestamp();
+
+    let _mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(format!(
+                r#"{{"quoteResponse":{{"result":[{{"symbol":"AAPL","regularMarketPrice":200.0,"currency":"USD","regularMarketTime":{now}}}],"error":null}}}}"#
+            ));
+    });
+    let _old = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "OLD");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"OLD","regularMarketPrice":1.0,"currency":"USD","regularMarketTime":1704205800}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let fresh = Ticker::new(&client, "AAPL").fast_info().await.unwrap();
+    assert_eq!(fresh.regular_market_time.map(|t| t.timestamp()), Some(now));
+    assert!(!fresh.is_stale(Duration::from_secs(60)));
+
+    let old = Ticker::new(&client, "OLD").quote_details().await.unwrap();
+    assert_eq!(
+        old.regular_market_time.map(|t| t.timestamp()),
+        Some(1_704_205_800)
+    );
+    assert!(old.is_stale(Duration::from_secs(3600)));
+}
+
+#[tokio::test]
+async fn fast_info_freshness_flags_old_price_during_regular_session_as_stale() {
+    let server = MockServer::start();
+    let now = chrono::Utc::now().timestamp();
+
+    let body = |symbol: &str, state: &str, time: i64| {
+        format!(
+            r#"{{"quoteResponse":{{"result":[{{"symbol":"{symbol}","regularMarketPrice":10.0,"currency":"USD","marketState":"{state}","regularMarketTime":{time}}}],"error":null}}}}"#
+        )
+    };
+    for (symbol, state, time) in [
+        ("OLD", "REGULAR", 1_704_205_800),
+        ("LIVE", "REGULAR", now),
+        ("LAG", "REGULAR", now - 15 * 60),
+        ("SHUT", "CLOSED", 1_704_205_800),
+    ] {
+        let body = body(symbol, state, time);
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/v7/finance/quote")
+                .query_param("symbols", symbol);
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(body);
+        });
+    }
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let freshness = |symbol: &'static str| {
+        let client = client.clone();
+        async move {
+            Ticker::new(&client, symbol)
+                .fast_info()
+                .await
+                .unwrap()
+                .freshness()
+        }
+    };
+
+    assert_eq!(freshness("OLD").await, Freshness::Stale);
+    assert_eq!(freshness("LIVE").await, Freshness::Live);
+    assert_eq!(freshness("LAG").await, Freshness::Delayed);
+    assert_eq!(freshness("SHUT").await, Freshness::Closed);
+}
+
+#[tokio::test]
+async fn fast_info_provenance_names_fallback_sources() {
+    let server = MockServer::start();
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":null,"regularMarketPreviousClose":199.5,"currency":"USD","marketCapFigureExchange":"NasdaqGS"}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let plain = Ticker::new(&client, "AAPL").fast_info().await.unwrap();
+    assert_eq!(plain.provenance, None);
+
+    let fi = Ticker::new(&client, "AAPL")
+        .provenance(true)
+        .fast_info()
+        .await
+        .unwrap();
+    let provenance = fi.provenance.expect("provenance requested");
+    assert_eq!(
+        provenance.get("last_price").map(String::as_str),
+        Some("regularMarketPreviousClose")
+    );
+    assert_eq!(
+        provenance.get("previous_close").map(String::as_str),
+        Some("regularMarketPreviousClose")
+    );
+    assert_eq!(
+        provenance.get("exchange").map(String::as_str),
+        Some("marketCapFigureExchange")
+    );
+}
@@ -0,0 +1,52 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use paft::domain::MarketState;
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Ticker, YfClient};
+
+#[tokio::test]
+async fn full_quote_carries_all_three_sessions() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteResponse":{"result":[{
+                  "symbol":"AAPL","currency":"USD","marketState":"POST",
+                  "regularMarketPrice":200.0,"regularMarketChange":2.0,
+                  "regularMarketChangePercent":1.0101,"regularMarketTime":1704229200,
+                  "preMarketPrice":197.5,"preMarketChange":-0.5,
+                  "preMarketChangePercent":-0.2525,"preMarketTime":1704205800,
+                  "postMarketPrice":201.0,"postMarketChange":1.0,
+                  "postMarketChangePercent":0.5,"postMarketTime":1704240000
+                }],"error":null}}"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let q = Ticker::new(&client, "AAPL").full_quote().await.unwrap();
+    mock.assert();
+
+    assert_eq!(q.symbol, "AAPL");
+    assert_eq!(q.market_state, Some(MarketState::Post));
+
+    for (session, price, change, pct, ts) in [
+        (&q.regular, 200.0, 2.0, 1.0101, 1_704_229_200),
+        (&q.pre_market, 197.5, -0.5, -0.2525, 1_704_205_800),
+        (&q.post_market, 201.0, 1.0, 0.5, 1_704_240_000),
+    ] {
+        let s = session.as_ref().expect("session present");
+        assert!((money_to_f64(&s.price) - price).abs() < 1e-9);
+        assert!((money_to_f64(s.change.as_ref().unwrap()) - change).abs() < 1e-9);
+        assert_eq!(s.change_percent, Some(pct));
+        assert_eq!(s.time.map(|t| t.timestamp()), Some(ts));
+    }
+}
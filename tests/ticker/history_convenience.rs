@@ -52,3 +52,81 @@ async fn ticker_history_convenience_builds_expected_query() {
     assert_eq!(bars.len(), 1);
     assert!((money_to_f64(&bars[0].close) - 100.5).abs() < 1e-9);
 }
+
+#[tokio::test]
+async fn ticker_download_matches_history_builder_request() {
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/AAPL")
+            .query_param("range", "1mo")
+            .query_param("interval", "1d")
+            .query_param("includePrePost", "false")
+            .query_param("events", "div|split|capitalGains");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(minimal_ok_body());
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .no_cache()
+        .build()
+        .unwrap();
+
+    let ticker = yfinance_rs::Ticker::new(&client, "AAPL");
+    let downloaded = ticker.download("1mo", "1d").await.unwrap();
+    let built = ticker
+        .history_builder()
+        .range(Range::M1)
+        .interval(Interval::D1)
+        .fetch()
+        .await
+        .unwrap();
+
+    mock.assert_hits(2);
+    assert_eq!(downloaded, built);
+
+    let err = ticker.download("1 month", "1d").await.unwrap_err();
+    assert!(matches!(err, yfinance_rs::YfError::InvalidParams(_)));
+}
+
+#[tokio::test]
+async fn ticker_history_uses_client_default_history_when_unspecified() {
+    let server = MockServer::start();
+
+    let defaults = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/AAPL")
+            .query_param("range", "1mo")
+            .query_param("interval", "1wk");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(minimal_ok_body());
+    });
+    let explicit = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/AAPL")
+            .query_param("range", "1y")
+            .query_param("interval", "1wk");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(minimal_ok_body());
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .default_history(Range::M1, Interval::W1)
+        .build()
+        .unwrap();
+    assert_eq!(client.default_history(), Some((Range::M1, Interval::W1)));
+
+    let ticker = yfinance_rs::Ticker::new(&client, "AAPL");
+    ticker.history(None, None, false).await.unwrap();
+    defaults.assert();
+
+    // An explicit range wins; the interval still comes from the default.
+    ticker.history(Some(Range::Y1), None, false).await.unwrap();
+    explicit.assert();
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Clock, Interval, Ticker, YfClient, YfError};
+
+#[derive(Debug)]
+struct FixedClock(DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+#[tokio::test]
+async fn one_minute_interval_requests_the_last_seven_days() {
+    let server = MockServer::start();
+    let now = Utc.with_ymd_and_hms(2024, 1, 12, 21, 0, 0).unwrap();
+    let start = now - Duration::days(7);
+
+    let body = r#"{"chart":{"result":[{"meta":{"currency":"USD"},"timestamp":[1705006800],"indicators":{"quote":[{"open":[1.0],"high":[1.0],"low":[1.0],"close":[1.0],"volume":[10]}]}}],"error":null}}"#;
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/AAPL")
+            .query_param("interval", "1m")
+            .query_param("period1", start.timestamp().to_string())
+            .query_param("period2", now.timestamp().to_string());
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .clock(FixedClock(now))
+        .build()
+        .unwrap();
+
+    let out = Ticker::new(&client, "AAPL")
+        .history_intraday(Interval::I1m)
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(out.interval, Interval::I1m);
+    assert_eq!(out.start, start);
+    assert_eq!(out.end, now);
+    assert_eq!(out.candles.len(), 1);
+}
+
+#[tokio::test]
+async fn daily_interval_is_rejected() {
+    let client = YfClient::default();
+    let err = Ticker::new(&client, "AAPL")
+        .history_intraday(Interval::D1)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, YfError::InvalidParams(_)), "got {err:?}");
+}
@@ -0,0 +1,57 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{QuoteSummaryModule, Ticker, YfClient};
+
+#[tokio::test]
+async fn info_raw_namespaces_quote_and_quote_summary() {
+    let server = MockServer::start();
+
+    let quote = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":190.5,"currency":"USD"}],"error":null}}"#);
+    });
+
+    let modules = QuoteSummaryModule::all()
+        .iter()
+        .map(|m| m.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+    let summary = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", &modules);
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteSummary":{"result":[{
+              "assetProfile":{"sector":"Technology","regularMarketPrice":"not the quote"},
+              "calendarEvents":{"earnings":{}}
+            }],"error":null}}"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let raw = Ticker::new(&client, "AAPL").info_raw().await.unwrap();
+    quote.assert();
+    summary.assert();
+
+    assert_eq!(raw["quote"]["regularMarketPrice"], 190.5);
+    assert_eq!(raw["quoteSummary"]["assetProfile"]["sector"], "Technology");
+    assert_eq!(
+        raw["quoteSummary"]["assetProfile"]["regularMarketPrice"],
+        "not the quote"
+    );
+}
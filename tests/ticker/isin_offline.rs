@@ -38,3 +38,39 @@ async fn offline_isin_happy_path() {
         "ISIN not parsed from fixture. Did you run `just test-record ticker` first?"
     );
 }
+
+#[tokio::test]
+async fn offline_isin_rejects_bad_check_digit() {
+    let server = MockServer::start();
+
+    // Same shape as a real hit, but the check digit is wrong (valid is US0378331005).
+    let isin_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/ajax/SearchController_Suggest")
+            .query_param("query", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"[{"Value":"AAPL|US0378331006|AAPL||AAPL","Symbol":"AAPL"}]"#);
+    });
+
+    let client = YfClient::builder()
+        .base_insider_search(
+            Url::parse(&format!(
+                "{}/ajax/SearchController_Suggest",
+                server.base_url()
+            ))
+            .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let isin = Ticker::new(&client, "AAPL").isin().await.unwrap();
+    isin_mock.assert();
+    assert_eq!(isin, None);
+}
+
+#[tokio::test]
+async fn offline_isin_is_none_for_indices() {
+    let client = YfClient::builder().build().unwrap();
+    assert_eq!(Ticker::new(&client, "^GSPC").isin().await.unwrap(), None);
+}
@@ -0,0 +1,110 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Ticker, YfClient};
+
+const D1: i64 = 1_767_225_600;
+const D2: i64 = 1_769_904_000;
+
+fn contract(strike: f64, iv: Option<f64>) -> String {
+    let iv = iv.map_or_else(|| "null".to_string(), |v| v.to_string());
+    format!(r#"{{"contractSymbol":"X","strike":{strike},"impliedVolatility":{iv}}}"#)
+}
+
+fn chain(date: i64, calls: &[String], puts: &[String]) -> String {
+    format!(
+        r#"{{"optionChain":{{"result":[{{"expirationDates":[{D1},{D2}],"quote":{{"currency":"USD","regularMarketPrice":100.0}},"options":[{{"expirationDate":{date},"calls":[{}],"puts":[{}]}}]}}],"error":null}}}}"#,
+        calls.join(","),
+        puts.join(",")
+    )
+}
+
+#[tokio::test]
+async fn iv_surface_builds_grid_across_expirations() {
+    let server = MockServer::start();
+
+    // The near expiration lists strikes 90/100/110; the far one lacks 90 and has no IV at 110.
+    let near = chain(
+        D1,
+        &[
+            contract(90.0, Some(0.30)),
+            contract(100.0, Some(0.25)),
+            contract(110.0, Some(0.28)),
+        ],
+        &[contract(90.0, Some(0.32)), contract(100.0, Some(0.26))],
+    );
+    let far = chain(
+        D2,
+        &[contract(100.0, Some(0.22)), contract(110.0, None)],
+        &[contract(110.0, Some(0.24))],
+    );
+
+    let root = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/options/AAPL")
+            .matches(|req| {
+                req.query_params
+                    .as_ref()
+                    .is_none_or(|q| q.iter().all(|(k, _)| k != "date"))
+            });
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chain(D1, &[], &[]));
+    });
+    let near_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/options/AAPL")
+            .query_param("date", D1.to_string());
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(near);
+    });
+    let far_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/options/AAPL")
+            .query_param("date", D2.to_string());
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(far);
+    });
+
+    let client = YfClient::builder()
+        .base_options_v7(Url::parse(&format!("{}/v7/finance/options/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let surface = Ticker::new(&client, "AAPL")
+        .options_implied_volatility_surface()
+        .await
+        .unwrap();
+    root.assert();
+    near_mock.assert();
+    far_mock.assert();
+
+    assert_eq!(surface.underlying_price, Some(100.0));
+    assert_eq!(
+        surface
+            .expirations
+            .iter()
+            .map(chrono::DateTime::timestamp)
+            .collect::<Vec<_>>(),
+        vec![D1, D2]
+    );
+    assert_eq!(surface.strikes, vec![90.0, 100.0, 110.0]);
+    assert_eq!(surface.moneyness, vec![Some(0.9), Some(1.0), Some(1.1)]);
+
+    assert_eq!(
+        surface.call_iv,
+        vec![
+            vec![Some(0.30), Some(0.25), Some(0.28)],
+            vec![None, Some(0.22), None],
+        ]
+    );
+    assert_eq!(
+        surface.put_iv,
+        vec![
+            vec![Some(0.32), Some(0.26), None],
+            vec![None, None, Some(0.24)],
+        ]
+    );
+}
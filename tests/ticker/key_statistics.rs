@@ -0,0 +1,78 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::{Ticker, YfError};
+
+#[tokio::test]
+async fn key_statistics_parses_shares_and_valuation() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", "defaultKeyStatistics");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteSummary":{"result":[{"defaultKeyStatistics":{
+                  "sharesOutstanding":{"raw":15204100096,"fmt":"15.2B"},
+                  "floatShares":{"raw":15179300000,"fmt":"15.18B"},
+                  "sharesShort":{"raw":94000000,"fmt":"94M"},
+                  "shortRatio":{"raw":1.7,"fmt":"1.70"},
+                  "heldPercentInstitutions":{"raw":0.6162,"fmt":"61.62%"},
+                  "enterpriseValue":{"raw":3510000000000,"fmt":"3.51T"},
+                  "forwardPE":{"raw":28.4,"fmt":"28.40"},
+                  "pegRatio":{},
+                  "trailingEps":{"raw":6.08,"fmt":"6.08"},
+                  "52WeekChange":{"raw":0.1339,"fmt":"13.39%"},
+                  "SandP52WeekChange":{"raw":0.1702,"fmt":"17.02%"},
+                  "lastSplitFactor":"4:1",
+                  "lastSplitDate":{"raw":1598832000,"fmt":"2020-08-31"},
+                  "mostRecentQuarter":{"raw":1719532800,"fmt":"2024-06-28"}
+                }}],"error":null}}"#,
+            );
+    });
+
+    let ks = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .key_statistics()
+        .await
+        .unwrap();
+    mock.assert();
+
+    assert_eq!(ks.shares_outstanding, Some(15_204_100_096));
+    assert_eq!(ks.float_shares, Some(15_179_300_000));
+    assert_eq!(ks.shares_short, Some(94_000_000));
+    assert_eq!(ks.short_ratio, Some(1.7));
+    assert_eq!(ks.held_percent_institutions, Some(0.6162));
+    assert_eq!(ks.held_percent_insiders, None);
+    assert_eq!(ks.enterprise_value, Some(3.51e12));
+    assert_eq!(ks.forward_pe, Some(28.4));
+    assert_eq!(ks.peg_ratio, None);
+    assert_eq!(ks.trailing_eps, Some(6.08));
+    assert_eq!(ks.fifty_two_week_change, Some(0.1339));
+    assert_eq!(ks.sp500_fifty_two_week_change, Some(0.1702));
+    assert_eq!(ks.last_split_factor.as_deref(), Some("4:1"));
+    assert_eq!(
+        ks.last_split_date.map(|d| d.timestamp()),
+        Some(1_598_832_000)
+    );
+    assert_eq!(
+        ks.most_recent_quarter.map(|d| d.timestamp()),
+        Some(1_719_532_800)
+    );
+}
+
+#[tokio::test]
+async fn key_statistics_missing_module_is_an_error() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v10/finance/quoteSummary/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteSummary":{"result":[{}],"error":null}}"#);
+    });
+
+    let err = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .key_statistics()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, YfError::MissingData(_)), "got {err:?}");
+}
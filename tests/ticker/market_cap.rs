@@ -0,0 +1,171 @@
+use httpmock::{Method::GET, MockServer};
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Range, Ticker, YfClient};
+
+#[tokio::test]
+async fn historical_market_cap_steps_between_share_reports() {
+    let server = MockServer::start();
+
+    // 2024-01-01 .. 2024-01-04, daily closes 10..13
+    let chart = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/AAPL")
+            .query_param("range", "1mo");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+              "chart":{"result":[{
+                "meta":{"currency":"USD","timezone":"UTC","gmtoffset":0},
+                "timestamp":[1704067200,1704153600,1704240000,1704326400],
+                "indicators":{"quote":[{
+                  "open":[10.0,11.0,12.0,13.0],
+                  "high":[10.0,11.0,12.0,13.0],
+                  "low":[10.0,11.0,12.0,13.0],
+                  "close":[10.0,11.0,12.0,13.0],
+                  "volume":[100,100,100,100]
+                }]}
+              }],"error":null}
+            }"#,
+            );
+    });
+
+    // Shares reported on 2024-01-01 (1000) and 2024-01-03 (2000)
+    let shares = server.mock(|when, then| {
+        when.method(GET)
+            .path("/ws/fundamentals-timeseries/v1/finance/timeseries/AAPL")
+            .query_param("type", "quarterlyBasicAverageShares");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"timeseries":{"result":[{
+                  "meta":{"symbol":["AAPL"],"type":["quarterlyBasicAverageShares"]},
+                  "timestamp":[1704067200,1704240000],
+                  "quarterlyBasicAverageShares":[
+                    {"reportedValue":{"raw":1000}},
+                    {"reportedValue":{"raw":2000}}
+                  ]
+                }]}}"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .base_timeseries(
+            Url::parse(&format!(
+                "{}/ws/fundamentals-timeseries/v1/finance/timeseries/",
+                server.base_url()
+            ))
+            .unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let points = Ticker::new(&client, "AAPL")
+        .historical_market_cap(Some(Range::M1))
+        .await
+        .unwrap();
+
+    chart.assert();
+    shares.assert();
+
+    let caps: Vec<(u64, f64)> = points
+        .iter()
+        .map(|p| (p.shares, money_to_f64(&p.market_cap)))
+        .collect();
+    assert_eq!(
+        caps,
+        vec![
+            (1000, 10_000.0),
+            (1000, 11_000.0),
+            (2000, 24_000.0),
+            (2000, 26_000.0)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn historical_market_cap_scales_shares_across_a_split() {
+    let server = MockServer::start();
+
+    // 2024-01-01 .. 2024-01-04 with a 2:1 split effective 2024-01-02.
+    let chart = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+              "chart":{"result":[{
+                "meta":{"currency":"USD","timezone":"UTC","gmtoffset":0},
+                "timestamp":[1704067200,1704153600,1704240000,1704326400],
+                "indicators":{"quote":[{
+                  "open":[10.0,5.0,6.0,7.0],
+                  "high":[10.0,5.0,6.0,7.0],
+                  "low":[10.0,5.0,6.0,7.0],
+                  "close":[10.0,5.0,6.0,7.0],
+                  "volume":[100,100,100,100]
+                }]},
+                "events":{"splits":{"1704153600":{"date":1704153600,"numerator":2,"denominator":1}}}
+              }],"error":null}
+            }"#,
+            );
+    });
+
+    // 1000 shares reported before the split, 2100 after it (on 2024-01-04).
+    let shares = server.mock(|when, then| {
+        when.method(GET)
+            .path("/ws/fundamentals-timeseries/v1/finance/timeseries/AAPL")
+            .query_param("type", "quarterlyBasicAverageShares");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"timeseries":{"result":[{
+                  "meta":{"symbol":["AAPL"],"type":["quarterlyBasicAverageShares"]},
+                  "timestamp":[1704067200,1704326400],
+                  "quarterlyBasicAverageShares":[
+                    {"reportedValue":{"raw":1000}},
+                    {"reportedValue":{"raw":2100}}
+                  ]
+                }]}}"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .base_timeseries(
+            Url::parse(&format!(
+                "{}/ws/fundamentals-timeseries/v1/finance/timeseries/",
+                server.base_url()
+            ))
+            .unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let points = Ticker::new(&client, "AAPL")
+        .historical_market_cap(Some(Range::M1))
+        .await
+        .unwrap();
+
+    chart.assert();
+    shares.assert();
+
+    let caps: Vec<(u64, f64)> = points
+        .iter()
+        .map(|p| (p.shares, money_to_f64(&p.market_cap)))
+        .collect();
+    assert_eq!(
+        caps,
+        vec![
+            (1000, 10_000.0),
+            // Post-split prices pair with the pre-split report doubled.
+            (2000, 10_000.0),
+            (2000, 12_000.0),
+            // The new report already counts post-split shares.
+            (2100, 14_700.0)
+        ]
+    );
+}
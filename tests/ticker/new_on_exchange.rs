@@ -0,0 +1,46 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{Ticker, YfClient, YfError};
+
+#[tokio::test]
+async fn new_on_exchange_requests_suffixed_symbol() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "RY.TO");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"RY.TO","regularMarketPrice":165.2,"currency":"CAD"}],"error":null}}"#);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let quote = Ticker::new_on_exchange(&client, "RY", "TO")
+        .unwrap()
+        .quote()
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(quote.symbol, "RY.TO");
+}
+
+#[test]
+fn new_on_exchange_validates_the_suffix() {
+    let client = YfClient::default();
+
+    assert!(Ticker::new_on_exchange(&client, "VOD", ".l").is_ok());
+    assert!(matches!(
+        Ticker::new_on_exchange(&client, "RY", "XX"),
+        Err(YfError::InvalidParams(_))
+    ));
+    assert!(matches!(
+        Ticker::new_on_exchange(&client, "RY.TO", "TO"),
+        Err(YfError::InvalidParams(_))
+    ));
+}
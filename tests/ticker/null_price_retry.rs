@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::core::client::{Backoff, RetryConfig};
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Ticker, YfClient};
+
+const NULL_PRICES: &str = r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":null,"regularMarketPreviousClose":null,"currency":"USD"}],"error":null}}"#;
+const PRICED: &str = r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":190.5,"regularMarketPreviousClose":189.0,"currency":"USD"}],"error":null}}"#;
+
+fn client_for(server: &MockServer) -> YfClient {
+    crate::common::builder_for(server)
+        .retry_config(RetryConfig {
+            backoff: Backoff::Fixed(Duration::from_millis(300)),
+            ..RetryConfig::default()
+        })
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn null_price_is_retried_once_when_enabled() {
+    let server = MockServer::start_async().await;
+    let first = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/v7/finance/quote")
+                .query_param("symbols", "AAPL");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(NULL_PRICES);
+        })
+        .await;
+
+    let ticker = Ticker::new(&client_for(&server), "AAPL").retry_null_price(true);
+
+    // Swap in a priced response once the first (all-null) response has been served.
+    let swap = async {
+        while first.hits_async().await == 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        first.delete_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/v7/finance/quote")
+                    .query_param("symbols", "AAPL");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .body(PRICED);
+            })
+            .await
+    };
+
+    let (quote, second) = tokio::join!(ticker.quote(), swap);
+    let quote = quote.unwrap();
+
+    second.assert_hits_async(1).await;
+    assert_eq!(quote.price.as_ref().map(money_to_f64), Some(190.5));
+}
+
+#[tokio::test]
+async fn null_price_is_returned_as_is_by_default() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/v7/finance/quote")
+                .query_param("symbols", "AAPL");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(NULL_PRICES);
+        })
+        .await;
+
+    let quote = Ticker::new(&client_for(&server), "AAPL")
+        .quote()
+        .await
+        .unwrap();
+
+    mock.assert_hits_async(1).await;
+    assert!(quote.price.is_none());
+}
@@ -0,0 +1,58 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Ticker, YfError};
+
+const DATE: i64 = 1_767_225_600;
+
+fn chain_body() -> String {
+    let side: Vec<String> = [90.0, 95.0, 100.0, 105.0, 110.0]
+        .iter()
+        .map(|k| format!(r#"{{"contractSymbol":"AAPL{k}","strike":{k}}}"#))
+        .collect();
+    let side = side.join(",");
+    format!(
+        r#"{{"optionChain":{{"result":[{{"expirationDates":[{DATE}],"quote":{{"currency":"USD","regularMarketPrice":100.0}},"options":[{{"expirationDate":{DATE},"calls":[{side}],"puts":[{side}]}}]}}],"error":null}}}}"#
+    )
+}
+
+#[tokio::test]
+async fn strike_range_keeps_contracts_within_inclusive_bounds() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/options/AAPL")
+            .query_param("date", DATE.to_string());
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(chain_body());
+    });
+
+    let chain = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .option_chain_builder()
+        .expiration(DATE)
+        .strike_range(95.0, 105.0)
+        .fetch()
+        .await
+        .unwrap();
+    mock.assert();
+
+    let strikes = |v: &[yfinance_rs::ticker::OptionContract]| -> Vec<f64> {
+        v.iter().map(|c| money_to_f64(&c.strike)).collect()
+    };
+    assert_eq!(strikes(&chain.calls), vec![95.0, 100.0, 105.0]);
+    assert_eq!(strikes(&chain.puts), vec![95.0, 100.0, 105.0]);
+}
+
+#[tokio::test]
+async fn strike_range_rejects_inverted_bounds() {
+    let server = MockServer::start();
+
+    let err = Ticker::new(&crate::common::client_for(&server), "AAPL")
+        .option_chain_builder()
+        .strike_range(110.0, 90.0)
+        .fetch()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, YfError::InvalidParams(_)), "got {err:?}");
+}
@@ -0,0 +1,145 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use paft::money::{Currency, IsoCurrency};
+use url::Url;
+use yfinance_rs::core::conversions::*;
+use yfinance_rs::{Ticker, YfClient};
+
+#[tokio::test]
+async fn previous_close_falls_back_to_chart_previous_close() {
+    let server = MockServer::start();
+
+    let quote = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteResponse":{"result":[{"symbol":"AAPL","currency":"USD"}],"error":null}}"#,
+            );
+    });
+
+    let chart = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v8/finance/chart/AAPL")
+            .query_param("range", "1d");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{
+              "chart":{"result":[{
+                "meta":{"currency":"USD","chartPreviousClose":187.25},
+                "timestamp":[],
+                "indicators":{"quote":[{}]}
+              }],"error":null}
+            }"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let pc = Ticker::new(&client, "AAPL").previous_close().await.unwrap();
+
+    quote.assert();
+    chart.assert();
+    assert_eq!(
+        pc,
+        f64_to_money_with_currency(187.25, Currency::Iso(IsoCurrency::USD))
+    );
+}
+
+#[tokio::test]
+async fn previous_close_prefers_quote_field() {
+    let server = MockServer::start();
+
+    let quote = server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","currency":"USD","regularMarketPreviousClose":190.0}],"error":null}}"#);
+    });
+    let chart = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(500);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let pc = Ticker::new(&client, "AAPL").previous_close().await.unwrap();
+
+    quote.assert();
+    chart.assert_hits(0);
+    assert_eq!(
+        pc,
+        f64_to_money_with_currency(190.0, Currency::Iso(IsoCurrency::USD))
+    );
+}
+
+#[tokio::test]
+async fn previous_close_uses_chart_when_quote_is_unavailable() {
+    for status in [429, 503] {
+        let server = MockServer::start();
+
+        let quote = server.mock(|when, then| {
+            when.method(GET).path("/v7/finance/quote");
+            then.status(status);
+        });
+        let chart = server.mock(|when, then| {
+            when.method(GET).path("/v8/finance/chart/AAPL");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"chart":{"result":[{"meta":{"currency":"USD","chartPreviousClose":187.25},"timestamp":[],"indicators":{"quote":[{}]}}],"error":null}}"#);
+        });
+
+        let client = YfClient::builder()
+            .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+            .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+            .retry_enabled(false)
+            .build()
+            .unwrap();
+
+        let pc = Ticker::new(&client, "AAPL").previous_close().await.unwrap();
+
+        quote.assert_hits(1);
+        chart.assert_hits(1);
+        assert_eq!(
+            pc,
+            f64_to_money_with_currency(187.25, Currency::Iso(IsoCurrency::USD)),
+            "status {status}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn previous_close_returns_other_quote_errors() {
+    let server = MockServer::start();
+
+    let quote = server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(404);
+    });
+    let chart = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .base_chart(Url::parse(&format!("{}/v8/finance/chart/", server.base_url())).unwrap())
+        .retry_enabled(false)
+        .build()
+        .unwrap();
+
+    assert!(Ticker::new(&client, "AAPL").previous_close().await.is_err());
+    quote.assert_hits(1);
+    chart.assert_hits(0);
+}
@@ -0,0 +1,44 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{PricePreference, Ticker, YfClient};
+
+const POST_BODY: &str = r#"{"quoteResponse":{"result":[{
+  "symbol":"AAPL","currency":"USD","marketState":"POST",
+  "regularMarketPrice":200.0,"postMarketPrice":203.5,"preMarketPrice":198.0
+}],"error":null}}"#;
+
+#[tokio::test]
+async fn extended_preference_uses_post_market_price_after_hours() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(POST_BODY);
+    });
+
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+    let ticker = Ticker::new(&client, "AAPL");
+
+    let extended = ticker
+        .quote_with_price_preference(PricePreference::Extended)
+        .await
+        .unwrap();
+    let regular = ticker
+        .quote_with_price_preference(PricePreference::Regular)
+        .await
+        .unwrap();
+    let default = ticker.quote().await.unwrap();
+    mock.assert_hits(3);
+
+    assert!((money_to_f64(extended.price.as_ref().unwrap()) - 203.5).abs() < 1e-9);
+    assert!((money_to_f64(regular.price.as_ref().unwrap()) - 200.0).abs() < 1e-9);
+    assert_eq!(default.price, regular.price);
+}
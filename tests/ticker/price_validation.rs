@@ -0,0 +1,42 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::{Ticker, YfClient, YfError};
+
+const NEGATIVE: &str = r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":-190.5,"regularMarketPreviousClose":189.0,"currency":"USD"}],"error":null}}"#;
+
+fn setup(server: &MockServer) -> YfClient {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(NEGATIVE);
+    });
+    crate::common::client_for(server)
+}
+
+#[tokio::test]
+async fn negative_price_is_rejected_when_validation_enabled() {
+    let server = MockServer::start();
+    let client = setup(&server);
+
+    let err = Ticker::new(&client, "AAPL")
+        .validate_prices(true)
+        .quote()
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, YfError::MissingData(msg) if msg.contains("regularMarketPrice")),
+        "got {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn negative_price_passes_through_by_default() {
+    let server = MockServer::start();
+    let client = setup(&server);
+
+    let q = Ticker::new(&client, "AAPL").quote().await.unwrap();
+    assert!(q.price.is_some());
+}
@@ -0,0 +1,153 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::core::conversions::{money_to_currency_str, money_to_f64};
+use yfinance_rs::{Ticker, YfClient, YfError};
+
+const CHART_META: &str = r#"{"chart":{"result":[{"meta":{"currency":"USD","symbol":"AAPL","exchangeName":"NMS","fullExchangeName":"NasdaqGS","regularMarketPrice":201.25,"chartPreviousClose":199.5},"timestamp":[1704205800],"indicators":{"quote":[{"open":[1.0],"high":[1.0],"low":[1.0],"close":[1.0],"volume":[10]}],"adjclose":[{"adjclose":[1.0]}]}}],"error":null}}"#;
+
+fn setup(server: &MockServer) -> (YfClient, httpmock::Mock<'_>, httpmock::Mock<'_>) {
+    let v7 = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(429);
+    });
+    let chart = server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CHART_META);
+    });
+    let client = crate::common::builder_for(server)
+        .retry_enabled(false)
+        .build()
+        .unwrap();
+    (client, v7, chart)
+}
+
+#[tokio::test]
+async fn rate_limited_quote_falls_back_to_chart_meta() {
+    let server = MockServer::start();
+    let (client, v7, chart) = setup(&server);
+
+    let q = Ticker::new(&client, "AAPL")
+        .chart_fallback(true)
+        .quote()
+        .await
+        .unwrap();
+    v7.assert();
+    chart.assert();
+
+    assert_eq!(q.symbol, "AAPL");
+    let price = q.price.expect("price from chart meta");
+    assert!((money_to_f64(&price) - 201.25).abs() < 1e-9);
+    assert_eq!(money_to_currency_str(&price).as_deref(), Some("USD"));
+    let prev = q.previous_close.expect("previous close from chart meta");
+    assert!((money_to_f64(&prev) - 199.5).abs() < 1e-9);
+    assert!(q.exchange.is_some());
+}
+
+#[tokio::test]
+async fn rate_limited_quote_without_fallback_is_an_error() {
+    let server = MockServer::start();
+    let (client, v7, chart) = setup(&server);
+
+    let err = Ticker::new(&client, "AAPL").quote().await.unwrap_err();
+    v7.assert();
+    assert_eq!(chart.hits(), 0);
+    assert!(matches!(err, YfError::RateLimited { .. }), "got {err:?}");
+}
+
+fn fallback_server(server: &MockServer, meta: &str) -> YfClient {
+    server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(429);
+    });
+    let body = format!(
+        r#"{{"chart":{{"result":[{{"meta":{meta},"timestamp":[1704205800],"indicators":{{"quote":[{{"open":[1.0],"high":[1.0],"low":[1.0],"close":[1.0],"volume":[10]}}]}}}}],"error":null}}}}"#
+    );
+    server.mock(|when, then| {
+        when.method(GET).path("/v8/finance/chart/AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(body);
+    });
+    crate::common::builder_for(server)
+        .retry_enabled(false)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn chart_fallback_applies_price_validation() {
+    let server = MockServer::start();
+    let client = fallback_server(
+        &server,
+        r#"{"currency":"USD","symbol":"AAPL","regularMarketPrice":-1.0}"#,
+    );
+
+    let err = Ticker::new(&client, "AAPL")
+        .chart_fallback(true)
+        .validate_prices(true)
+        .quote()
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, YfError::MissingData(msg) if msg.contains("regularMarketPrice")),
+        "got {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn chart_fallback_applies_strict_symbol() {
+    let server = MockServer::start();
+    let client = fallback_server(
+        &server,
+        r#"{"currency":"USD","symbol":"AAPL.NEW","regularMarketPrice":10.0}"#,
+    );
+    let err = Ticker::new(&client, "AAPL")
+        .chart_fallback(true)
+        .strict_symbol(true)
+        .quote()
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, YfError::SymbolMismatch { returned, .. } if returned == "AAPL.NEW"),
+        "got {err:?}"
+    );
+
+    let server = MockServer::start();
+    let client = fallback_server(&server, r#"{"currency":"USD","regularMarketPrice":10.0}"#);
+    let err = Ticker::new(&client, "AAPL")
+        .chart_fallback(true)
+        .strict_symbol(true)
+        .quote()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, YfError::SymbolMismatch { .. }), "got {err:?}");
+
+    // Without strict_symbol the requested symbol stands in for the missing one.
+    let q = Ticker::new(&client, "AAPL")
+        .chart_fallback(true)
+        .quote()
+        .await
+        .unwrap();
+    assert_eq!(q.symbol, "AAPL");
+}
+
+#[tokio::test]
+async fn chart_fallback_prefers_previous_close_like_ticker_previous_close() {
+    let server = MockServer::start();
+    let client = fallback_server(
+        &server,
+        r#"{"currency":"USD","symbol":"AAPL","regularMarketPrice":201.25,"previousClose":200.0,"chartPreviousClose":150.0}"#,
+    );
+    let ticker = Ticker::new(&client, "AAPL").chart_fallback(true);
+
+    let q = ticker.quote().await.unwrap();
+    let prev = q.previous_close.expect("previous close from chart meta");
+    assert!((money_to_f64(&prev) - 200.0).abs() < 1e-9);
+
+    let pc = ticker.previous_close().await.unwrap();
+    assert!((money_to_f64(&pc) - 200.0).abs() < 1e-9);
+}
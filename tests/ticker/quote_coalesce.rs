@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::{CacheMode, Ticker, YfClient, YfError};
+
+#[tokio::test]
+async fn concurrent_identical_quotes_share_one_request() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(Duration::from_millis(200))
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":200.0,"currency":"USD"}],"error":null}}"#);
+    });
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let tickers: Vec<_> = (0..5)
+        .map(|_| Ticker::new(&client, "AAPL").cache_mode(CacheMode::Bypass))
+        .collect();
+    let quotes = futures::future::try_join_all(tickers.iter().map(Ticker::quote))
+        .await
+        .unwrap();
+
+    mock.assert_hits(1);
+    assert_eq!(quotes.len(), 5);
+    assert!(quotes.iter().all(|q| q.symbol == "AAPL"));
+
+    // Once the shared request has finished, a new call goes to the network again.
+    Ticker::new(&client, "AAPL")
+        .cache_mode(CacheMode::Bypass)
+        .quote()
+        .await
+        .unwrap();
+    mock.assert_hits(2);
+}
+
+#[tokio::test]
+async fn waiting_callers_share_the_leaders_error() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(429).delay(Duration::from_millis(200));
+    });
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .retry_enabled(false)
+        .build()
+        .unwrap();
+
+    let tickers: Vec<_> = (0..5)
+        .map(|_| Ticker::new(&client, "AAPL").cache_mode(CacheMode::Bypass))
+        .collect();
+    let results = futures::future::join_all(tickers.iter().map(Ticker::quote)).await;
+
+    mock.assert_hits(1);
+    assert!(
+        results
+            .iter()
+            .all(|r| matches!(r, Err(YfError::RateLimited { .. })))
+    );
+}
+
+#[tokio::test]
+async fn different_cache_modes_do_not_share_a_request() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "AAPL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(Duration::from_millis(200))
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"AAPL","regularMarketPrice":200.0,"currency":"USD"}],"error":null}}"#);
+    });
+    let client = YfClient::builder()
+        .base_quote_v7(Url::parse(&format!("{}/v7/finance/quote", server.base_url())).unwrap())
+        .build()
+        .unwrap();
+
+    let bypass = Ticker::new(&client, "AAPL").cache_mode(CacheMode::Bypass);
+    let refresh = Ticker::new(&client, "AAPL").cache_mode(CacheMode::Refresh);
+    let (a, b) = tokio::join!(bypass.quote(), refresh.quote());
+    a.unwrap();
+    b.unwrap();
+
+    mock.assert_hits(2);
+}
@@ -0,0 +1,88 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use yfinance_rs::{Ticker, YfError};
+
+fn renamed_server() -> MockServer {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v7/finance/quote")
+            .query_param("symbols", "FB");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"META","regularMarketPrice":500.0,"currency":"USD"}],"error":null}}"#);
+    });
+    server
+}
+
+#[tokio::test]
+async fn strict_symbol_rejects_a_different_returned_symbol() {
+    let server = renamed_server();
+
+    let err = Ticker::new(&crate::common::client_for(&server), "FB")
+        .strict_symbol(true)
+        .quote()
+        .await
+        .unwrap_err();
+
+    match err {
+        YfError::SymbolMismatch {
+            requested,
+            returned,
+        } => {
+            assert_eq!(requested, "FB");
+            assert_eq!(returned, "META");
+        }
+        other => panic!("expected SymbolMismatch, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn symbol_mismatch_is_accepted_by_default() {
+    let server = renamed_server();
+
+    let quote = Ticker::new(&crate::common::client_for(&server), "FB")
+        .quote()
+        .await
+        .unwrap();
+    assert_eq!(quote.symbol, "META");
+}
+
+#[tokio::test]
+async fn strict_symbol_compares_case_insensitively() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"symbol":"BRK-B","regularMarketPrice":400.0,"currency":"USD"}],"error":null}}"#);
+    });
+
+    let quote = Ticker::new(&crate::common::client_for(&server), "brk-b")
+        .strict_symbol(true)
+        .quote()
+        .await
+        .unwrap();
+    assert_eq!(quote.symbol, "BRK-B");
+}
+
+#[tokio::test]
+async fn strict_symbol_rejects_a_missing_symbol() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v7/finance/quote");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{"quoteResponse":{"result":[{"regularMarketPrice":500.0,"currency":"USD"}],"error":null}}"#);
+    });
+
+    let err = Ticker::new(&crate::common::client_for(&server), "FB")
+        .strict_symbol(true)
+        .quote()
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, YfError::SymbolMismatch { requested, returned } if requested == "FB" && returned.is_empty()),
+        "got {err:?}"
+    );
+}
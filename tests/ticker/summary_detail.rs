@@ -0,0 +1,60 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use url::Url;
+use yfinance_rs::core::conversions::money_to_f64;
+use yfinance_rs::{Ticker, YfClient};
+
+#[tokio::test]
+async fn summary_detail_parses_ratios_and_prices() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v10/finance/quoteSummary/AAPL")
+            .query_param("modules", "summaryDetail");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(
+                r#"{"quoteSummary":{"result":[{"summaryDetail":{
+                  "currency":"USD",
+                  "previousClose":{"raw":189.5,"fmt":"189.50"},
+                  "fiftyTwoWeekLow":{"raw":164.08,"fmt":"164.08"},
+                  "fiftyTwoWeekHigh":{"raw":237.23,"fmt":"237.23"},
+                  "marketCap":{"raw":2950000000000,"fmt":"2.95T"},
+                  "dividendYield":{"raw":0.0044,"fmt":"0.44%"},
+                  "payoutRatio":{"raw":0.1493,"fmt":"14.93%"},
+                  "beta":{"raw":1.24,"fmt":"1.24"},
+                  "trailingPE":{"raw":31.2,"fmt":"31.20"},
+                  "volume":{"raw":48000000,"fmt":"48M"},
+                  "exDividendDate":{"raw":1715299200,"fmt":"2024-05-10"}
+                }}],"error":null}}"#,
+            );
+    });
+
+    let client = YfClient::builder()
+        .base_quote_api(
+            Url::parse(&format!("{}/v10/finance/quoteSummary/", server.base_url())).unwrap(),
+        )
+        ._preauth("cookie", "crumb")
+        .build()
+        .unwrap();
+
+    let sd = Ticker::new(&client, "AAPL").summary_detail().await.unwrap();
+    mock.assert();
+
+    assert_eq!(sd.beta, Some(1.24));
+    assert_eq!(sd.payout_ratio, Some(0.1493));
+    assert_eq!(sd.dividend_yield, Some(0.0044));
+    assert_eq!(sd.trailing_pe, Some(31.2));
+    assert_eq!(sd.forward_pe, None);
+    assert_eq!(sd.volume, Some(48_000_000));
+    assert_eq!(sd.currency.as_deref(), Some("USD"));
+
+    let low = sd.fifty_two_week_low.expect("52-week low");
+    assert!((money_to_f64(&low) - 164.08).abs() < 1e-9);
+    assert_eq!(low.currency().to_string(), "USD");
+    assert!(sd.market_cap.is_some());
+    assert_eq!(
+        sd.ex_dividend_date.map(|d| d.timestamp()),
+        Some(1_715_299_200)
+    );
+}
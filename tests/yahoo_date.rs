@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use yfinance_rs::core::YahooDate;
+
+#[derive(Deserialize)]
+struct Row {
+    #[serde(default)]
+    date: YahooDate,
+}
+
+fn parse(json: &str) -> YahooDate {
+    serde_json::from_str::<Row>(json).unwrap().date
+}
+
+#[test]
+fn bare_unix_seconds() {
+    let d = parse(r#"{"date":1715299200}"#);
+    assert_eq!(d.timestamp(), Some(1_715_299_200));
+    assert_eq!(d.date(), NaiveDate::from_ymd_opt(2024, 5, 10));
+}
+
+#[test]
+fn raw_fmt_object() {
+    let d = parse(r#"{"date":{"raw":1715299200,"fmt":"2024-05-10"}}"#);
+    assert_eq!(d.timestamp(), Some(1_715_299_200));
+    assert_eq!(d.date(), NaiveDate::from_ymd_opt(2024, 5, 10));
+}
+
+#[test]
+fn out_of_range_and_missing_values_are_none() {
+    assert_eq!(parse(r#"{"date":9223372036854775807}"#).get(), None);
+    assert_eq!(parse(r#"{"date":{"fmt":"2024-05-10"}}"#).get(), None);
+    assert_eq!(parse(r#"{"date":null}"#).get(), None);
+    assert_eq!(parse("{}").get(), None);
+}
@@ -0,0 +1,42 @@
+use serde::Deserialize;
+use yfinance_rs::core::YahooNumber;
+
+#[derive(Deserialize)]
+struct Row {
+    #[serde(default)]
+    value: YahooNumber,
+}
+
+fn parse(json: &str) -> Option<f64> {
+    serde_json::from_str::<Row>(json).unwrap().value.get()
+}
+
+#[test]
+fn object_form_uses_raw() {
+    assert_eq!(
+        parse(r#"{"value":{"raw":0.1493,"fmt":"14.93%","longFmt":"14.93%"}}"#),
+        Some(0.1493)
+    );
+}
+
+#[test]
+fn object_form_falls_back_to_fmt() {
+    assert_eq!(parse(r#"{"value":{"fmt":"1,234.50"}}"#), Some(1234.5));
+    assert_eq!(parse(r#"{"value":{"fmt":"2.5B"}}"#), Some(2.5e9));
+    let pct = parse(r#"{"value":{"fmt":"14.93%"}}"#).unwrap();
+    assert!((pct - 0.1493).abs() < 1e-12);
+}
+
+#[test]
+fn bare_number_and_string() {
+    assert_eq!(parse(r#"{"value":1.24}"#), Some(1.24));
+    assert_eq!(parse(r#"{"value":"31.2"}"#), Some(31.2));
+}
+
+#[test]
+fn missing_null_and_empty_values_are_none() {
+    assert_eq!(parse("{}"), None);
+    assert_eq!(parse(r#"{"value":null}"#), None);
+    assert_eq!(parse(r#"{"value":{}}"#), None);
+    assert_eq!(parse(r#"{"value":{"fmt":"N/A"}}"#), None);
+}